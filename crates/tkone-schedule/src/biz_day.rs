@@ -6,12 +6,19 @@
 //! custom cut-off calendars, etc.
 //!
 //! The built-in [`WeekendSkipper`] treats every Monday–Friday as a business
-//! day, regardless of public holidays.
+//! day, regardless of public holidays. [`CutOff`] wraps any processor so that
+//! occurrences after a daily cut-off time count as the next business day.
+//! For weekends other than Saturday–Sunday and for public holidays, use
+//! [`crate::calendar::HolidayCalendar`].
+//!
+//! [`crate::biz_day::BizDayProcessor::session`] reports a date's trading
+//! [`crate::biz_day::Session`], so that schedules can follow early closes
+//! such as a Christmas Eve half-day.
 
 use std::fmt::Debug;
 
 use crate::{prelude::*, utils::DateLikeUtils};
-use chrono::{Datelike, Duration, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
 
 /// Trait for pluggable business day logic.
 ///
@@ -93,10 +100,44 @@ pub trait BizDayProcessor: Debug + Clone + Send + Sync + 'static {
 
     /// Retreats `dtm` by exactly `num` business days.
     fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime>;
+
+    /// The trading session on `date`.
+    ///
+    /// Defaults to [`Session::Full`] on a business day and [`Session::Closed`]
+    /// otherwise; override it to report opening hours and early closes.
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        Ok(if self.is_biz_day(&date.and_time(NaiveTime::MIN))? {
+            Session::Full
+        } else {
+            Session::Closed
+        })
+    }
+}
+
+/// The trading session of a single date, as reported by
+/// [`BizDayProcessor::session`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Session {
+    /// Not a business day.
+    Closed,
+    /// A business day without known opening hours.
+    Full,
+    /// A business day open from `open` until `close`.
+    Hours { open: NaiveTime, close: NaiveTime },
+}
+
+impl Session {
+    /// The closing time, when the session has one.
+    pub fn close(&self) -> Option<NaiveTime> {
+        match self {
+            Session::Hours { close, .. } => Some(*close),
+            _ => None,
+        }
+    }
 }
 
 /// Direction used when searching for a nearby business day.
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Clone)]
 pub enum Direction {
     /// Nearest business day; tie-breaking is implementation-defined.
     /// This is the default.
@@ -216,3 +257,108 @@ impl BizDayProcessor for WeekendSkipper {
         }
     }
 }
+
+/// A [`BizDayProcessor`] that applies a daily cut-off time on top of another
+/// processor.
+///
+/// A datetime later than the cut-off is too late to settle on the business
+/// day it falls on, so it counts as the next business day: it is not a
+/// business day itself, and adjusting it in any direction lands on the next
+/// business day. Counting with [`add`](BizDayProcessor::add) and
+/// [`sub`](BizDayProcessor::sub) starts from the day it counts as too. On a
+/// day the inner processor does not treat as a business day the time does not
+/// matter, and a datetime at or before the cut-off is handled by the inner
+/// processor unchanged.
+///
+/// An adjusted datetime keeps the time of day it was given, so a late time
+/// moved to the next business day is after the cut-off there as well:
+/// [`is_biz_day`](BizDayProcessor::is_biz_day) is `false` for it. Compare the
+/// dates of adjusted datetimes rather than adjusting them again.
+///
+/// The cut-off compares against the wall-clock time of the naive datetimes
+/// the schedule produces. Datetime specs check each occurrence's time of day
+/// in the spec's zone, so with a `~NB` / `~PB` / `~B` / `~nN` / `~nP`
+/// adjustment only the late slots of a date move. Date specs carry the time
+/// of day of the datetime their iterator starts from.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::biz_day::{BizDayProcessor, CutOff, Direction, WeekendSkipper};
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// let bdp = CutOff::new(WeekendSkipper::new(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+/// let fri = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+///
+/// assert!(bdp.is_biz_day(&fri.and_hms_opt(17, 0, 0).unwrap()).unwrap());
+/// assert!(!bdp.is_biz_day(&fri.and_hms_opt(17, 30, 0).unwrap()).unwrap());
+///
+/// // Friday after the cut-off settles on Monday
+/// let settled = bdp.find_biz_day(&fri.and_hms_opt(17, 30, 0).unwrap(), Direction::Next).unwrap();
+/// assert_eq!(settled.date(), NaiveDate::from_ymd_opt(2025, 1, 6).unwrap());
+///
+/// // On Saturday the time does not matter: it settles on Monday either way
+/// let sat = NaiveDate::from_ymd_opt(2025, 1, 4).unwrap();
+/// for time in [sat.and_hms_opt(9, 0, 0).unwrap(), sat.and_hms_opt(17, 30, 0).unwrap()] {
+///     let settled = bdp.find_biz_day(&time, Direction::Next).unwrap();
+///     assert_eq!(settled.date(), NaiveDate::from_ymd_opt(2025, 1, 6).unwrap());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CutOff<BDP: BizDayProcessor> {
+    inner: BDP,
+    cut_off: NaiveTime,
+}
+
+impl<BDP: BizDayProcessor> CutOff<BDP> {
+    /// Wraps `inner` so that times after `cut_off` count as the next business day.
+    pub fn new(inner: BDP, cut_off: NaiveTime) -> Self {
+        Self { inner, cut_off }
+    }
+
+    /// Whether `dtm` is after the cut-off on a business day of the inner
+    /// processor.
+    fn is_late(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        Ok(dtm.time() > self.cut_off && self.inner.is_biz_day(dtm)?)
+    }
+
+    /// The business day a late `dtm` counts as: the next one.
+    fn late_day(&self, dtm: &NaiveDateTime) -> Result<NaiveDateTime> {
+        self.inner.add(dtm, 1)
+    }
+}
+
+impl<BDP: BizDayProcessor> BizDayProcessor for CutOff<BDP> {
+    fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        Ok(self.inner.is_biz_day(dtm)? && dtm.time() <= self.cut_off)
+    }
+
+    fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        if self.is_late(dtm)? {
+            self.late_day(dtm)
+        } else {
+            self.inner.find_biz_day(dtm, direction)
+        }
+    }
+
+    fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        if self.is_late(dtm)? {
+            self.inner.add(&self.late_day(dtm)?, num)
+        } else {
+            self.inner.add(dtm, num)
+        }
+    }
+
+    fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        if self.is_late(dtm)? {
+            self.inner.sub(&self.late_day(dtm)?, num)
+        } else {
+            self.inner.sub(dtm, num)
+        }
+    }
+
+    /// The inner processor's session; the cut-off does not move the close.
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        self.inner.session(date)
+    }
+}
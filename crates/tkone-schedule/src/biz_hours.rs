@@ -0,0 +1,217 @@
+//! Business-hours windows used to filter intraday schedules.
+//!
+//! [`BusinessHours`] holds, for each weekday, the time windows during which
+//! occurrences may fire, plus optional breaks (e.g. lunch) that apply to every
+//! day. Apply it to a schedule with
+//! [`time::SpecIteratorBuilder::with_business_hours`](crate::time::SpecIteratorBuilder::with_business_hours)
+//! or
+//! [`datetime::SpecIteratorBuilder::with_business_hours`](crate::datetime::SpecIteratorBuilder::with_business_hours);
+//! occurrences outside the windows are dropped.
+
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Weekday};
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{all_consuming, map_opt, map_res, opt},
+    error::Error as NomError,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult, Parser,
+};
+
+use crate::prelude::*;
+
+/// How long a filtered schedule may keep producing only closed-hours
+/// occurrences before iteration fails with [`Error::IteratorNotConverged`].
+pub(crate) const MAX_CLOSED_DAYS: i64 = 366;
+
+/// Per-weekday opening windows, optionally interrupted by daily breaks.
+///
+/// Windows are written `HH:MM-HH:MM` (seconds optional), several separated
+/// by commas: `"08:30-12:00,13:00-17:30"`. Both ends of a window are
+/// included, so `08:30-17:30` lets a 17:30 occurrence through. A window must
+/// end after it starts; to cover midnight, split the window across both days.
+///
+/// A break removes the time strictly between its ends from every day's
+/// windows, so `08:30-17:30` with break `12:00-13:00` is open at 12:00 and
+/// 13:00 but not at 12:30.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::biz_hours::BusinessHours;
+/// use chrono::{Datelike, NaiveDate, Weekday};
+///
+/// let hours = BusinessHours::weekdays("08:30-17:30")
+///     .unwrap()
+///     .with_break("12:00-13:00")
+///     .unwrap()
+///     .with_day(Weekday::Sat, "09:00-12:00")
+///     .unwrap();
+///
+/// let fri = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+/// assert!(hours.contains(&fri.and_hms_opt(9, 0, 0).unwrap()));
+/// assert!(!hours.contains(&fri.and_hms_opt(12, 30, 0).unwrap()));
+/// assert!(hours.contains(&fri.and_hms_opt(17, 30, 0).unwrap()));
+///
+/// // Friday evening → Saturday 09:00
+/// let open = hours.next_open(&fri.and_hms_opt(18, 0, 0).unwrap()).unwrap();
+/// assert_eq!(open.weekday(), Weekday::Sat);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessHours {
+    /// Windows per weekday, indexed from Monday; sorted and non-overlapping.
+    days: [Vec<(NaiveTime, NaiveTime)>; 7],
+    breaks: Vec<(NaiveTime, NaiveTime)>,
+}
+
+impl BusinessHours {
+    /// Hours that are closed every day; add windows with [`Self::with_day`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The same `windows` Monday to Friday, closed at the weekend.
+    pub fn weekdays(windows: &str) -> Result<Self> {
+        let windows = parse_windows(windows)?;
+        let mut hours = Self::new();
+        for day in &mut hours.days[..5] {
+            *day = windows.clone();
+        }
+        Ok(hours)
+    }
+
+    /// Replace the windows of `weekday`.
+    pub fn with_day(mut self, weekday: Weekday, windows: &str) -> Result<Self> {
+        self.days[weekday.num_days_from_monday() as usize] = parse_windows(windows)?;
+        Ok(self)
+    }
+
+    /// Close `weekday` entirely.
+    pub fn with_closed_day(mut self, weekday: Weekday) -> Self {
+        self.days[weekday.num_days_from_monday() as usize].clear();
+        self
+    }
+
+    /// Add a break, such as lunch, that applies to every day.
+    pub fn with_break(mut self, window: &str) -> Result<Self> {
+        self.breaks.extend(parse_windows(window)?);
+        Ok(self)
+    }
+
+    /// The windows in effect on `weekday`, with breaks already removed.
+    pub fn windows(&self, weekday: Weekday) -> Vec<(NaiveTime, NaiveTime)> {
+        let mut windows = self.days[weekday.num_days_from_monday() as usize].clone();
+        for &(break_start, break_end) in &self.breaks {
+            windows = windows
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    if break_end <= start || break_start >= end {
+                        return vec![(start, end)];
+                    }
+                    let before = (break_start > start).then_some((start, break_start));
+                    let after = (break_end < end).then_some((break_end, end));
+                    before.into_iter().chain(after).collect()
+                })
+                .collect();
+        }
+        windows
+    }
+
+    /// Whether `dtm` falls inside one of its weekday's windows.
+    pub fn contains(&self, dtm: &NaiveDateTime) -> bool {
+        let time = dtm.time();
+        self.windows(dtm.weekday())
+            .iter()
+            .any(|&(start, end)| start <= time && time <= end)
+    }
+
+    /// The earliest datetime at or after `dtm` that falls inside a window, or
+    /// `None` when no day of the week has any window.
+    pub fn next_open(&self, dtm: &NaiveDateTime) -> Option<NaiveDateTime> {
+        (0..=7).find_map(|offset| {
+            let date = dtm.date() + Duration::days(offset);
+            self.windows(date.weekday())
+                .into_iter()
+                .find_map(|(start, end)| {
+                    let open = date.and_time(start);
+                    if offset > 0 || open >= *dtm {
+                        Some(open)
+                    } else {
+                        (date.and_time(end) >= *dtm).then_some(*dtm)
+                    }
+                })
+        })
+    }
+
+    /// Pulls items from `next` until one falls inside a window.
+    ///
+    /// Ends with `None` when the schedule ends or the hours are never open,
+    /// and fails with [`Error::IteratorNotConverged`] when more than a year of
+    /// occurrences falls outside every window.
+    pub(crate) fn first_open<T>(
+        &self,
+        mut next: impl FnMut() -> Result<Option<T>>,
+        dtm_of: impl Fn(&T) -> NaiveDateTime,
+    ) -> Result<Option<T>> {
+        let mut closed_since = None;
+        while let Some(item) = next()? {
+            let dtm = dtm_of(&item);
+            if self.contains(&dtm) {
+                return Ok(Some(item));
+            }
+            if self.next_open(&dtm).is_none() {
+                return Ok(None);
+            }
+            let since = *closed_since.get_or_insert(dtm);
+            if dtm - since > Duration::days(MAX_CLOSED_DAYS) {
+                return Err(Error::IteratorNotConverged);
+            }
+        }
+        Ok(None)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parser (nom)
+// ---------------------------------------------------------------------------
+
+type Res<'a, T> = IResult<&'a str, T, NomError<&'a str>>;
+
+fn parse_time(input: &str) -> Res<'_, NaiveTime> {
+    let number = || map_res(digit1, str::parse::<u32>);
+    map_opt(
+        (
+            number(),
+            preceded(char(':'), number()),
+            opt(preceded(char(':'), number())),
+        ),
+        |(h, m, s)| NaiveTime::from_hms_opt(h, m, s.unwrap_or(0)),
+    )
+    .parse(input)
+}
+
+fn parse_window(input: &str) -> Res<'_, (NaiveTime, NaiveTime)> {
+    separated_pair(parse_time, char('-'), parse_time).parse(input)
+}
+
+/// Parses `HH:MM-HH:MM[,…]` into sorted windows, merging any that overlap.
+fn parse_windows(input: &str) -> Result<Vec<(NaiveTime, NaiveTime)>> {
+    let invalid = || Error::InvalidBusinessHours(input.to_string());
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let (_, mut windows) = all_consuming(separated_list1(char(','), parse_window))
+        .parse(&compact)
+        .map_err(|_| invalid())?;
+    if windows.iter().any(|(start, end)| start >= end) {
+        return Err(invalid());
+    }
+
+    windows.sort();
+    let mut merged: Vec<(NaiveTime, NaiveTime)> = Vec::with_capacity(windows.len());
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Ok(merged)
+}
@@ -0,0 +1,543 @@
+//! Calendars combining several business day processors.
+
+use std::fmt::Debug;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+use super::{nearest, MAX_CLOSED_DAYS};
+use crate::biz_day::{BizDayProcessor, Direction, Session};
+use crate::prelude::*;
+
+/// A [`BizDayProcessor`] whose business days are those of *every* member,
+/// as for a cross-border settlement that needs both markets open.
+///
+/// Adjustments and counting work over the combined definition, so `~NB`,
+/// `~B` and `nBD` skip the holidays of every member. To give the joint
+/// calendar a cut-off, wrap it in a [`CutOff`](crate::biz_day::CutOff)
+/// rather than wrapping the members. Members may be processors of different
+/// types, e.g. a [`HolidayCalendar`](super::HolidayCalendar) joined with a
+/// custom processor.
+///
+/// Its [`session`](BizDayProcessor::session) is the time every member is
+/// open: from the latest opening to the earliest close, or
+/// [`Session::Closed`] when the members' hours do not overlap.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::biz_day::BizDayProcessor;
+/// use tkone_schedule::calendar::{HolidayCalendar, JointCalendar};
+/// use chrono::NaiveDate;
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+/// let london = HolidayCalendar::new().with_holiday(date(8, 25));
+/// let new_york = HolidayCalendar::new().with_holiday(date(9, 1));
+/// let joint = JointCalendar::new(london).with(new_york);
+///
+/// // Friday 22 August + 5 business days skips both bank holidays
+/// let fri = date(8, 22).and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(joint.add(&fri, 5).unwrap().date(), date(9, 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JointCalendar {
+    members: Vec<Member>,
+}
+
+/// A [`BizDayProcessor`] whose business days are those of *any* member, as
+/// for a payment that can settle in whichever market is open.
+///
+/// Adjustments and counting work over the combined definition, so `~NB`,
+/// `~B` and `nBD` only skip days on which every member is closed. As with
+/// [`JointCalendar`], members may be of different types.
+///
+/// Its [`session`](BizDayProcessor::session) runs from the earliest opening
+/// of an open member to the latest close.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::biz_day::{BizDayProcessor, Direction};
+/// use tkone_schedule::calendar::{AnyCalendar, HolidayCalendar};
+/// use chrono::{NaiveDate, Weekday};
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let dubai = HolidayCalendar::new().with_weekend(&[Weekday::Sat, Weekday::Sun]).unwrap();
+/// let riyadh = HolidayCalendar::new().with_weekend(&[Weekday::Fri, Weekday::Sat]).unwrap();
+/// let either = AnyCalendar::new(dubai).with(riyadh);
+///
+/// // Sunday is open in Riyadh; only Saturday is closed in both
+/// assert!(either.is_biz_day(&date(6, 1)).unwrap());
+/// assert_eq!(either.find_biz_day(&date(5, 31), Direction::Next).unwrap(), date(6, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnyCalendar {
+    members: Vec<Member>,
+}
+
+impl JointCalendar {
+    /// A joint calendar with `first` as its only member.
+    pub fn new<BDP: BizDayProcessor>(first: BDP) -> Self {
+        Self {
+            members: vec![Member(Box::new(first))],
+        }
+    }
+
+    /// Add `member`, so its holidays close the joint calendar too.
+    pub fn with<BDP: BizDayProcessor>(mut self, member: BDP) -> Self {
+        self.members.push(Member(Box::new(member)));
+        self
+    }
+
+    /// The closest joint business day at or beyond `dtm` in `direction`.
+    ///
+    /// Settles on each member in turn until a whole pass leaves the date
+    /// where it was, at which point every member accepts it.
+    fn settle(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        let mut current = *dtm;
+        loop {
+            let mut settled = current;
+            for member in &self.members {
+                settled = settle(member, &settled, direction.clone())?;
+            }
+            if settled == current {
+                return Ok(current);
+            }
+            if (settled - *dtm).num_days().abs() > MAX_CLOSED_DAYS {
+                return Err(Error::IteratorNotConverged);
+            }
+            current = settled;
+        }
+    }
+}
+
+impl AnyCalendar {
+    /// A calendar with `first` as its only member.
+    pub fn new<BDP: BizDayProcessor>(first: BDP) -> Self {
+        Self {
+            members: vec![Member(Box::new(first))],
+        }
+    }
+
+    /// Add `member`, so its business days open the calendar too.
+    pub fn with<BDP: BizDayProcessor>(mut self, member: BDP) -> Self {
+        self.members.push(Member(Box::new(member)));
+        self
+    }
+
+    /// The closest business day of any member at or beyond `dtm` in
+    /// `direction`.
+    fn settle(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        let mut closest: Option<NaiveDateTime> = None;
+        for member in &self.members {
+            let settled = settle(member, dtm, direction.clone())?;
+            closest = Some(match (closest, &direction) {
+                (Some(closest), Direction::Prev) => closest.max(settled),
+                (Some(closest), _) => closest.min(settled),
+                (None, _) => settled,
+            });
+        }
+        Ok(closest.unwrap_or(*dtm))
+    }
+}
+
+impl BizDayProcessor for JointCalendar {
+    fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        for member in &self.members {
+            if !member.is_biz_day(dtm)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        find_biz_day(self, dtm, direction, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        step(dtm, num, Direction::Next, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        step(dtm, num, Direction::Prev, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        let mut joint = Session::Full;
+        for member in &self.members {
+            joint = match (joint, member.session(date)?) {
+                (_, Session::Closed) => return Ok(Session::Closed),
+                (Session::Full, session) | (session, Session::Full) => session,
+                (
+                    Session::Hours { open, close },
+                    Session::Hours {
+                        open: other_open,
+                        close: other_close,
+                    },
+                ) => Session::Hours {
+                    open: open.max(other_open),
+                    close: close.min(other_close),
+                },
+                (Session::Closed, _) => Session::Closed,
+            };
+        }
+        Ok(match joint {
+            Session::Hours { open, close } if close <= open => Session::Closed,
+            joint => joint,
+        })
+    }
+}
+
+impl BizDayProcessor for AnyCalendar {
+    fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        for member in &self.members {
+            if member.is_biz_day(dtm)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        find_biz_day(self, dtm, direction, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        step(dtm, num, Direction::Next, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        step(dtm, num, Direction::Prev, |dtm, direction| {
+            self.settle(dtm, direction)
+        })
+    }
+
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        let mut any = Session::Closed;
+        for member in &self.members {
+            any = match (any, member.session(date)?) {
+                (Session::Closed, session) | (session, Session::Closed) => session,
+                (Session::Full, _) | (_, Session::Full) => Session::Full,
+                (
+                    Session::Hours { open, close },
+                    Session::Hours {
+                        open: other_open,
+                        close: other_close,
+                    },
+                ) => Session::Hours {
+                    open: open.min(other_open),
+                    close: close.max(other_close),
+                },
+            };
+        }
+        Ok(any)
+    }
+}
+
+/// A member of a combined calendar, boxed so that members of different
+/// processor types can sit side by side.
+#[derive(Debug)]
+struct Member(Box<dyn DynBizDayProcessor>);
+
+/// The object-safe part of [`BizDayProcessor`], implemented for every
+/// processor under `dyn_` names, so that calls on a concrete processor stay
+/// unambiguous; `Clone` is replaced by [`clone_member`](Self::clone_member).
+trait DynBizDayProcessor: Debug + Send + Sync {
+    fn dyn_is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool>;
+    fn dyn_find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime>;
+    fn dyn_add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime>;
+    fn dyn_sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime>;
+    fn dyn_session(&self, date: NaiveDate) -> Result<Session>;
+    fn clone_member(&self) -> Member;
+}
+
+impl<BDP: BizDayProcessor> DynBizDayProcessor for BDP {
+    fn dyn_is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        BizDayProcessor::is_biz_day(self, dtm)
+    }
+
+    fn dyn_find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        BizDayProcessor::find_biz_day(self, dtm, direction)
+    }
+
+    fn dyn_add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        BizDayProcessor::add(self, dtm, num)
+    }
+
+    fn dyn_sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        BizDayProcessor::sub(self, dtm, num)
+    }
+
+    fn dyn_session(&self, date: NaiveDate) -> Result<Session> {
+        BizDayProcessor::session(self, date)
+    }
+
+    fn clone_member(&self) -> Member {
+        Member(Box::new(self.clone()))
+    }
+}
+
+impl Clone for Member {
+    fn clone(&self) -> Self {
+        self.0.clone_member()
+    }
+}
+
+impl BizDayProcessor for Member {
+    fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        self.0.dyn_is_biz_day(dtm)
+    }
+
+    fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        self.0.dyn_find_biz_day(dtm, direction)
+    }
+
+    fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        self.0.dyn_add(dtm, num)
+    }
+
+    fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        self.0.dyn_sub(dtm, num)
+    }
+
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        self.0.dyn_session(date)
+    }
+}
+
+/// `dtm` if `bdp` counts it as a business day, otherwise the business day
+/// `bdp` finds from it in `direction`.
+fn settle<BDP: BizDayProcessor>(
+    bdp: &BDP,
+    dtm: &NaiveDateTime,
+    direction: Direction,
+) -> Result<NaiveDateTime> {
+    if bdp.is_biz_day(dtm)? {
+        Ok(*dtm)
+    } else {
+        bdp.find_biz_day(dtm, direction)
+    }
+}
+
+/// [`BizDayProcessor::find_biz_day`] for a combined calendar whose closest
+/// business day in a direction is given by `settle`. `Nearest` follows the
+/// [`HolidayCalendar`](super::HolidayCalendar) conventions.
+fn find_biz_day(
+    bdp: &impl BizDayProcessor,
+    dtm: &NaiveDateTime,
+    direction: Direction,
+    settle: impl Fn(&NaiveDateTime, Direction) -> Result<NaiveDateTime>,
+) -> Result<NaiveDateTime> {
+    match direction {
+        Direction::Nearest if bdp.is_biz_day(dtm)? => Ok(*dtm),
+        Direction::Nearest => {
+            let prev = settle(dtm, Direction::Prev)?;
+            let next = settle(dtm, Direction::Next)?;
+            Ok(nearest(dtm, prev, next))
+        }
+        direction => settle(dtm, direction),
+    }
+}
+
+/// Moves `dtm` by `num` business days towards `direction`, settling a day at
+/// a time with `settle`.
+fn step(
+    dtm: &NaiveDateTime,
+    num: u32,
+    direction: Direction,
+    settle: impl Fn(&NaiveDateTime, Direction) -> Result<NaiveDateTime>,
+) -> Result<NaiveDateTime> {
+    let day = match direction {
+        Direction::Prev => Duration::days(-1),
+        _ => Duration::days(1),
+    };
+    let mut current = *dtm;
+    for _ in 0..num {
+        current = settle(&(current + day), direction.clone())?;
+    }
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, Weekday};
+    use fallible_iterator::FallibleIterator;
+
+    use super::*;
+    use crate::calendar::HolidayCalendar;
+    use crate::date::NaiveSpecIterator;
+
+    fn day(m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    /// Steps one day at a time, as a reference for the combined `add` / `sub`.
+    fn shift_by_days(bdp: &impl BizDayProcessor, dtm: NaiveDateTime, num: i64) -> NaiveDateTime {
+        let step = Duration::days(num.signum());
+        let (mut current, mut remaining) = (dtm, num.abs());
+        while remaining > 0 {
+            current += step;
+            if bdp.is_biz_day(&current).unwrap() {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    fn calendars() -> (HolidayCalendar, HolidayCalendar) {
+        let london = HolidayCalendar::new()
+            .with_holiday(day(5, 26).date())
+            .with_holiday(day(8, 25).date());
+        let riyadh = HolidayCalendar::new()
+            .with_weekend(&[Weekday::Fri, Weekday::Sat])
+            .unwrap()
+            .with_closure(day(6, 5).date()..day(6, 10).date());
+        (london, riyadh)
+    }
+
+    /// Checks `add` / `sub` against stepping one day at a time.
+    fn check_counting(bdp: &impl BizDayProcessor) {
+        for start in [day(5, 20), day(6, 6), day(8, 22)] {
+            for num in [0, 1, 2, 5, 13, 40] {
+                assert_eq!(
+                    bdp.add(&start, num as u32).unwrap(),
+                    shift_by_days(bdp, start, num)
+                );
+                assert_eq!(
+                    bdp.sub(&start, num as u32).unwrap(),
+                    shift_by_days(bdp, start, -num)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_joint_and_any_match_day_by_day() {
+        let (london, riyadh) = calendars();
+        check_counting(&JointCalendar::new(london.clone()).with(riyadh.clone()));
+        check_counting(&AnyCalendar::new(london).with(riyadh));
+    }
+
+    #[test]
+    fn test_members_of_different_types() {
+        use crate::biz_day::WeekendSkipper;
+
+        let (london, riyadh) = calendars();
+        // Riyadh's Fri–Sat weekend joined with a Sat–Sun one
+        let joint = JointCalendar::new(riyadh.clone()).with(WeekendSkipper::new());
+        assert!(!joint.is_biz_day(&day(6, 13)).unwrap());
+        assert!(!joint.is_biz_day(&day(6, 15)).unwrap());
+        assert!(joint.is_biz_day(&day(6, 16)).unwrap());
+        check_counting(&joint);
+
+        // Combined calendars nest as members of either kind
+        let any = AnyCalendar::new(joint.clone()).with(london);
+        assert!(any.is_biz_day(&day(6, 6)).unwrap());
+        assert!(!any.is_biz_day(&day(6, 14)).unwrap());
+        check_counting(&any);
+        check_counting(&JointCalendar::new(any).with(joint));
+    }
+
+    #[test]
+    fn test_find_biz_day() {
+        let (london, riyadh) = calendars();
+        let joint = JointCalendar::new(london.clone()).with(riyadh.clone());
+        let any = AnyCalendar::new(london).with(riyadh);
+
+        // Riyadh is closed 5–9 June and every Friday; London every weekend.
+        assert_eq!(
+            joint.find_biz_day(&day(6, 6), Direction::Next).unwrap(),
+            day(6, 10)
+        );
+        assert_eq!(
+            joint.find_biz_day(&day(6, 6), Direction::Prev).unwrap(),
+            day(6, 4)
+        );
+        assert_eq!(
+            joint.find_biz_day(&day(6, 8), Direction::Nearest).unwrap(),
+            day(6, 10)
+        );
+        assert_eq!(
+            joint.find_biz_day(&day(6, 13), Direction::Nearest).unwrap(),
+            day(6, 12)
+        );
+
+        assert_eq!(
+            any.find_biz_day(&day(6, 7), Direction::Next).unwrap(),
+            day(6, 9)
+        );
+        assert_eq!(
+            any.find_biz_day(&day(6, 7), Direction::Prev).unwrap(),
+            day(6, 6)
+        );
+        assert_eq!(
+            any.find_biz_day(&day(5, 31), Direction::Nearest).unwrap(),
+            day(5, 30)
+        );
+    }
+
+    #[test]
+    fn test_spec_adjustment_uses_combined_days() {
+        let (london, riyadh) = calendars();
+        let joint = JointCalendar::new(london).with(riyadh);
+        // Riyadh is closed on 6 June and Fridays, London on 22 June and 25
+        // August, so `~NB` only settles where both are open.
+        let dates: Vec<_> = NaiveSpecIterator::new_after("YY-[06,08]-[6,22]~NB", joint, day(6, 1))
+            .unwrap()
+            .take(4)
+            .map(|occ| Ok(*occ.observed()))
+            .collect()
+            .unwrap();
+        assert_eq!(dates, [day(6, 10), day(6, 23), day(8, 6), day(8, 26)]);
+    }
+
+    #[test]
+    fn test_sessions_combine_hours() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let hours = |open, close| Session::Hours { open, close };
+        let (london, riyadh) = calendars();
+        let london = london
+            .with_session(time(8, 0), time(16, 30))
+            .unwrap()
+            .with_early_close(day(12, 24).date(), time(12, 30));
+        let new_york = HolidayCalendar::new()
+            .with_session(time(14, 30), time(21, 0))
+            .unwrap();
+
+        let joint = JointCalendar::new(london.clone()).with(new_york.clone());
+        assert_eq!(
+            joint.session(day(6, 2).date()).unwrap(),
+            hours(time(14, 30), time(16, 30))
+        );
+        assert_eq!(joint.session(day(8, 25).date()).unwrap(), Session::Closed);
+        assert_eq!(joint.session(day(12, 24).date()).unwrap(), Session::Closed);
+
+        let any = AnyCalendar::new(london.clone()).with(new_york);
+        assert_eq!(
+            any.session(day(12, 24).date()).unwrap(),
+            hours(time(8, 0), time(21, 0))
+        );
+        assert_eq!(
+            any.session(day(8, 25).date()).unwrap(),
+            hours(time(14, 30), time(21, 0))
+        );
+
+        // Riyadh has no hours, so open days are open all day.
+        let any = AnyCalendar::new(london).with(riyadh);
+        assert_eq!(any.session(day(6, 1).date()).unwrap(), Session::Full);
+        assert_eq!(any.session(day(6, 7).date()).unwrap(), Session::Closed);
+    }
+}
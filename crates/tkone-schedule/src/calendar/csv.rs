@@ -0,0 +1,130 @@
+//! Loading holidays from `date,name` CSV data.
+
+use chrono::NaiveDate;
+
+use super::HolidayCalendar;
+use crate::prelude::*;
+
+impl HolidayCalendar {
+    /// Add the holidays listed in the CSV data `input`.
+    ///
+    /// Each row is `date,name`, or `start,end,name` for a closure of several
+    /// days where `end` is exclusive, the first day open again, as for
+    /// [`with_closure`](Self::with_closure) and an iCalendar `DTEND`. Dates
+    /// are written `YYYY-MM-DD`. The name may be quoted and is otherwise
+    /// ignored; blank lines, lines starting with `#` and a header row, whose
+    /// first field is not a date, are skipped.
+    ///
+    /// Fails with [`Error::InvalidHolidayCalendar`], naming the offending
+    /// line, for a row without a valid date, with an invalid end date or with
+    /// a range that does not end after it starts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tkone_schedule::calendar::HolidayCalendar;
+    /// use chrono::NaiveDate;
+    ///
+    /// let csv = "\
+    /// date,name
+    /// 2025-01-01,New Year's Day
+    /// 2025-04-18,\"Good Friday, observed\"
+    /// 2025-12-24,2025-12-27,Christmas closure
+    /// ";
+    /// let cal = HolidayCalendar::new().with_csv(csv).unwrap();
+    /// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    /// assert!(cal.is_holiday(date(4, 18)).unwrap());
+    /// assert!(cal.is_holiday(date(12, 26)).unwrap());
+    /// assert!(!cal.is_holiday(date(12, 27)).unwrap());
+    /// ```
+    pub fn with_csv(mut self, input: &str) -> Result<Self> {
+        let mut header_allowed = true;
+        for (idx, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fail =
+                |msg: String| Error::InvalidHolidayCalendar(format!("line {}: {msg}", idx + 1));
+
+            let (first, rest) = line.split_once(',').unwrap_or((line, ""));
+            if std::mem::take(&mut header_allowed) && !looks_like_date(first) {
+                continue;
+            }
+            let start = parse_date(first)
+                .ok_or_else(|| fail(format!("invalid date `{}`", unquote(first))))?;
+
+            let second = rest.split_once(',').map_or(rest, |(second, _)| second);
+            if !looks_like_date(second) {
+                self = self.with_holiday(start);
+                continue;
+            }
+            let end = parse_date(second)
+                .ok_or_else(|| fail(format!("invalid end date `{}`", unquote(second))))?;
+            if end <= start {
+                return Err(fail(format!("closure must end after {start}")));
+            }
+            self = self.with_closure(start..end);
+        }
+        Ok(self)
+    }
+}
+
+fn unquote(field: &str) -> &str {
+    field.trim().trim_matches('"')
+}
+
+/// Whether `field` is written like a date, `YYYY-…`, valid or not.
+fn looks_like_date(field: &str) -> bool {
+    let field = unquote(field).as_bytes();
+    field.len() > 4 && field[..4].iter().all(u8::is_ascii_digit) && field[4] == b'-'
+}
+
+fn parse_date(field: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(unquote(field), "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_rows_and_errors() {
+        let csv = "# NYSE 2025\n2025-07-04\n2025-11-27,Thanksgiving Day\n";
+        let cal = HolidayCalendar::new().with_csv(csv).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+        assert_eq!(
+            cal.holidays_between(date(1, 1), date(12, 31)).unwrap(),
+            [date(7, 4), date(11, 27)].into()
+        );
+
+        // The end of a closure is exclusive
+        let cal = HolidayCalendar::new()
+            .with_csv("start,end,name\n2025-12-24,2025-12-26,Christmas\n")
+            .unwrap();
+        assert_eq!(
+            cal.holidays_between(date(12, 1), date(12, 31)).unwrap(),
+            [date(12, 24), date(12, 25)].into()
+        );
+
+        let cases = [
+            ("2025-07-04,Independence Day\nJuly 4th,oops\n", "line 2"),
+            ("date,name\nholiday,name\n", "line 2"),
+            ("2025-12-26,2025-12-24,Backwards\n", "line 1"),
+            ("2025-12-26,2025-12-26,Empty\n", "line 1"),
+            // A mistyped first row is not taken for a header
+            ("2025-13-01,Holiday\n", "line 1"),
+            ("# comment\n\n2025-13-01,Holiday\n", "line 3"),
+            // Nor a mistyped end for the name
+            ("2025-07-04,2025-07-32,x\n", "invalid end date `2025-07-32`"),
+        ];
+        for (csv, expected) in cases {
+            match HolidayCalendar::new().with_csv(csv) {
+                Err(Error::InvalidHolidayCalendar(msg)) => {
+                    assert!(msg.contains(expected), "{msg}")
+                }
+                other => panic!("{csv:?} gave {other:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Loading holidays from iCalendar (RFC 5545) data.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use fallible_iterator::FallibleIterator;
+
+use super::{HolidayCalendar, HolidayRule, MAX_CLOSED_DAYS};
+use crate::date::{NaiveSpecIterator, Spec};
+use crate::prelude::*;
+
+impl HolidayCalendar {
+    /// Add the holidays of every `VEVENT` in the iCalendar data `input`.
+    ///
+    /// Events must be all-day (`DTSTART;VALUE=DATE:20251225`). `DTEND`
+    /// (exclusive, as for [`with_closure`](Self::with_closure)) or a `DURATION`
+    /// of whole days or weeks makes an event a closure of up to a year, and
+    /// `EXDATE` removes single occurrences. Recurring
+    /// holidays take a yearly `RRULE` built from `BYMONTH`, `BYMONTHDAY` and
+    /// `BYDAY` (for example `FREQ=YEARLY;BYMONTH=11;BYDAY=4TH`), optionally
+    /// bounded with `INTERVAL`, `COUNT` or `UNTIL`. Cancelled events are
+    /// skipped and other components, such as `VTIMEZONE` or `VALARM`, are
+    /// ignored.
+    ///
+    /// Fails with [`Error::InvalidHolidayCalendar`], naming the offending
+    /// line, for malformed data, timed events and recurrence rules outside
+    /// that subset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tkone_schedule::calendar::HolidayCalendar;
+    /// use chrono::NaiveDate;
+    ///
+    /// let ics = "\
+    /// BEGIN:VCALENDAR
+    /// BEGIN:VEVENT
+    /// SUMMARY:Thanksgiving Day
+    /// DTSTART;VALUE=DATE:20201126
+    /// RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=4TH
+    /// END:VEVENT
+    /// BEGIN:VEVENT
+    /// SUMMARY:Year-end closure
+    /// DTSTART;VALUE=DATE:20251229
+    /// DTEND;VALUE=DATE:20260102
+    /// END:VEVENT
+    /// END:VCALENDAR
+    /// ";
+    /// let cal = HolidayCalendar::new().with_ics(ics).unwrap();
+    /// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// assert!(cal.is_holiday(date(2025, 11, 27)).unwrap());
+    /// assert!(cal.is_holiday(date(2026, 1, 1)).unwrap());
+    /// assert!(!cal.is_holiday(date(2026, 1, 2)).unwrap());
+    /// ```
+    pub fn with_ics(mut self, input: &str) -> Result<Self> {
+        let mut event: Option<Event> = None;
+        // Components nested inside the current event, such as `VALARM`.
+        let mut nested: Vec<String> = Vec::new();
+
+        for (line_no, line) in unfold(input) {
+            let fail =
+                |msg: String| Error::InvalidHolidayCalendar(format!("line {line_no}: {msg}"));
+            let (name, params, value) =
+                split_property(&line).ok_or_else(|| fail("expected `NAME:VALUE`".to_string()))?;
+
+            match (name.as_str(), value.to_ascii_uppercase().as_str()) {
+                ("BEGIN", "VEVENT") if event.is_none() => {
+                    event = Some(Event::default());
+                    continue;
+                }
+                ("BEGIN", "VEVENT") => return Err(fail("nested `VEVENT`".to_string())),
+                ("BEGIN", _) if event.is_some() => {
+                    nested.push(value.to_ascii_uppercase());
+                    continue;
+                }
+                ("END", component) if nested.last().is_some_and(|open| open == component) => {
+                    nested.pop();
+                    continue;
+                }
+                ("END", "VEVENT") if nested.is_empty() => {
+                    let done = event
+                        .take()
+                        .ok_or_else(|| fail("`END:VEVENT` without `BEGIN:VEVENT`".to_string()))?;
+                    self = done.apply(self).map_err(fail)?;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let Some(event) = event.as_mut().filter(|_| nested.is_empty()) else {
+                continue;
+            };
+            let date = || parse_date(&params, &value).map_err(&fail);
+            match name.as_str() {
+                "DTSTART" => event.start = Some(date()?),
+                "DTEND" => event.end = Some(date()?),
+                "DURATION" => event.days = Some(parse_duration(&value).map_err(&fail)?),
+                "RRULE" => event.rrule = Some(value.clone()),
+                "EXDATE" => {
+                    for item in value.split(',') {
+                        event
+                            .except
+                            .insert(parse_date(&params, item).map_err(&fail)?);
+                    }
+                }
+                "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+                "RDATE" => return Err(fail("`RDATE` is not supported".to_string())),
+                _ => {}
+            }
+        }
+
+        if event.is_some() {
+            return Err(Error::InvalidHolidayCalendar(
+                "unterminated `VEVENT`".to_string(),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+/// The properties of one `VEVENT` that matter to a holiday calendar.
+#[derive(Debug, Default)]
+struct Event {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    days: Option<u32>,
+    rrule: Option<String>,
+    except: BTreeSet<NaiveDate>,
+    cancelled: bool,
+}
+
+impl Event {
+    /// Adds the event to `cal`.
+    fn apply(self, cal: HolidayCalendar) -> std::result::Result<HolidayCalendar, String> {
+        if self.cancelled {
+            return Ok(cal);
+        }
+        let start = self.start.ok_or("event has no `DTSTART`")?;
+        let span = match (self.end, self.days) {
+            (Some(_), Some(_)) => return Err("event has both `DTEND` and `DURATION`".to_string()),
+            (Some(end), None) if end <= start => {
+                return Err("`DTEND` is not after `DTSTART`".to_string())
+            }
+            (Some(end), None) => check_span((end - start).num_days())?,
+            (None, Some(days)) => days,
+            (None, None) => 1,
+        };
+
+        let Some(rrule) = self.rrule else {
+            let end = start
+                .checked_add_signed(Duration::days(span as i64))
+                .ok_or("event ends after the last supported date")?;
+            let closed = start.iter_days().take_while(|date| *date < end);
+            return Ok(cal.with_holidays(closed.filter(|date| !self.except.contains(date))));
+        };
+        let mut rule = parse_rrule(&rrule, start)?;
+        rule.span = span;
+        rule.except = self.except;
+        let mut cal = cal;
+        cal.rules.push(rule);
+        Ok(cal)
+    }
+}
+
+/// Joins folded lines, yielding each logical line with the number of the
+/// physical line it starts on.
+fn unfold(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (idx, raw) in input.lines().enumerate() {
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some((_, line))) => line.push_str(rest),
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push((idx + 1, raw.to_string())),
+        }
+    }
+    lines
+}
+
+/// Splits `NAME;PARAM=…:VALUE` into its upper-cased name, its parameters and
+/// its value.
+fn split_property(line: &str) -> Option<(String, Vec<String>, String)> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(idx, ch)| {
+        if ch == '"' {
+            quoted = !quoted;
+        }
+        (ch == ':' && !quoted).then_some(idx)
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    if name.is_empty() {
+        return None;
+    }
+    let params = parts.map(|param| param.to_ascii_uppercase()).collect();
+    Some((name, params, value.trim().to_string()))
+}
+
+/// Parses an all-day `DATE` value, rejecting `DATE-TIME`s.
+fn parse_date(params: &[String], value: &str) -> std::result::Result<NaiveDate, String> {
+    if params.iter().any(|param| param == "VALUE=DATE-TIME") || value.contains('T') {
+        return Err(format!(
+            "`{value}` is a timed event; only all-day events are supported"
+        ));
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| format!("invalid date `{value}`"))
+}
+
+/// Parses a `DURATION` of whole days (`P3D`) or weeks (`P1W`) into days.
+fn parse_duration(value: &str) -> std::result::Result<u32, String> {
+    let invalid = || format!("unsupported duration `{value}`; use whole days or weeks");
+    let body = value.strip_prefix('P').ok_or_else(invalid)?;
+    let (num, unit) = body.split_at(body.len().saturating_sub(1));
+    let num: u32 = num.parse().map_err(|_| invalid())?;
+    match unit {
+        "D" if num > 0 => check_span(num.into()),
+        "W" if num > 0 => check_span(i64::from(num) * 7),
+        _ => Err(invalid()),
+    }
+}
+
+/// Rejects a closure longer than a business day processor can skip.
+fn check_span(days: i64) -> std::result::Result<u32, String> {
+    if days > MAX_CLOSED_DAYS {
+        return Err(format!(
+            "a closure of {days} days is longer than {MAX_CLOSED_DAYS} days"
+        ));
+    }
+    Ok(days as u32)
+}
+
+/// Translates a yearly `RRULE` starting on `start` into a holiday rule.
+fn parse_rrule(rrule: &str, start: NaiveDate) -> std::result::Result<HolidayRule, String> {
+    let mut freq = None;
+    let (mut every, mut count, mut until) = (1, None, None);
+    let (mut months, mut month_days, mut week_days) = (None, None, None);
+
+    for part in rrule.split(';').filter(|part| !part.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| format!("invalid `RRULE` part `{part}`"))?;
+        let number = |value: &str| {
+            value
+                .parse::<u32>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("invalid `{key}` value `{value}`"))
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(value.to_ascii_uppercase()),
+            "INTERVAL" => every = number(value)?,
+            "COUNT" => count = Some(number(value)?),
+            "UNTIL" => until = Some(parse_date(&[], value.get(..8).unwrap_or(value))?),
+            "BYMONTH" => months = Some(by_month(value)?),
+            "BYMONTHDAY" => month_days = Some(by_month_day(value)?),
+            "BYDAY" => week_days = Some(value),
+            "WKST" => {}
+            other => return Err(format!("`RRULE` part `{other}` is not supported")),
+        }
+    }
+
+    if freq.as_deref() != Some("YEARLY") {
+        return Err("only `FREQ=YEARLY` recurring holidays are supported".to_string());
+    }
+    if count.is_some() && until.is_some() {
+        return Err("`RRULE` has both `COUNT` and `UNTIL`".to_string());
+    }
+    // Without a day rule the holiday keeps the day, and by default the
+    // month, of `DTSTART`.
+    let (months, days) = match (months, month_days, week_days) {
+        (_, Some(_), Some(_)) => {
+            return Err("`BYMONTHDAY` with `BYDAY` is not supported".to_string());
+        }
+        (months, None, None) => (
+            months.unwrap_or_else(|| format!("{:02}", start.month())),
+            start.day().to_string(),
+        ),
+        (months, Some(days), None) => (months.unwrap_or_else(|| "MM".to_string()), days),
+        (months, None, Some(days)) => {
+            let days = by_day(days, months.is_some())?;
+            (months.unwrap_or_else(|| "MM".to_string()), days)
+        }
+    };
+    let spec: Spec = format!("YY-{months}-{days}")
+        .parse()
+        .map_err(|err: Error| err.to_string())?;
+
+    let mut rule = HolidayRule::new(spec);
+    rule.since = start;
+    rule.every = every;
+    if let Some(until) = until {
+        rule.until = until;
+    }
+    if let Some(count) = count {
+        rule.until = nth_occurrence(&rule, count)?;
+    }
+    Ok(rule)
+}
+
+/// The nominal date of the `count`th occurrence of `rule`.
+fn nth_occurrence(rule: &HolidayRule, count: u32) -> std::result::Result<NaiveDate, String> {
+    let start = rule
+        .since
+        .pred_opt()
+        .unwrap_or(rule.since)
+        .and_time(NaiveTime::MIN);
+    let iter = NaiveSpecIterator::from_spec_after(rule.spec.clone(), HolidayCalendar::new(), start);
+    iter.map(|occurrence| Ok(occurrence.actual().date()))
+        .filter(|date| Ok(rule.includes(*date)))
+        .nth(count as usize - 1)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("`RRULE` has fewer than {count} occurrences"))
+}
+
+/// Translates a `BYMONTH` list into a date spec months component.
+fn by_month(value: &str) -> std::result::Result<String, String> {
+    let months = value
+        .split(',')
+        .map(|month| match month.parse::<u32>() {
+            Ok(month @ 1..=12) => Ok(format!("{month:02}")),
+            _ => Err(format!("invalid `BYMONTH` value `{month}`")),
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(list(months))
+}
+
+/// Translates a `BYMONTHDAY` list into a date spec days component. `-1`
+/// stands for the last day of the month.
+fn by_month_day(value: &str) -> std::result::Result<String, String> {
+    if value == "-1" {
+        return Ok("L".to_string());
+    }
+    let days = value
+        .split(',')
+        .map(|day| match day.parse::<u32>() {
+            Ok(day @ 1..=31) => Ok(day.to_string()),
+            _ => Err(format!("unsupported `BYMONTHDAY` value `{day}`")),
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(list(days))
+}
+
+/// Translates a `BYDAY` list such as `MO,FR`, `4TH` or `-1MO` into a date
+/// spec days component. Ordinals count within the month, so they need a
+/// `BYMONTH`, and all entries of a list must share the same one.
+fn by_day(value: &str, has_months: bool) -> std::result::Result<String, String> {
+    let mut ordinal = None;
+    let mut days = Vec::new();
+    for (idx, item) in value.split(',').enumerate() {
+        let invalid = || format!("unsupported `BYDAY` value `{item}`");
+        let split = item.len().checked_sub(2).ok_or_else(invalid)?;
+        let (nth, day) = item.split_at(split);
+        let day = match day.to_ascii_uppercase().as_str() {
+            "MO" => "MON",
+            "TU" => "TUE",
+            "WE" => "WED",
+            "TH" => "THU",
+            "FR" => "FRI",
+            "SA" => "SAT",
+            "SU" => "SUN",
+            _ => return Err(invalid()),
+        };
+        let nth = match nth.trim_start_matches('+') {
+            "" => None,
+            nth => match nth.parse::<i32>() {
+                Ok(n @ 1..=5) => Some(n.to_string()),
+                Ok(-1) => Some("L".to_string()),
+                Ok(n @ -5..=-2) => Some(format!("{}L", -n)),
+                _ => return Err(invalid()),
+            },
+        };
+        if idx > 0 && nth != ordinal {
+            return Err(format!(
+                "`BYDAY` entries in `{value}` must share one ordinal"
+            ));
+        }
+        ordinal = nth;
+        days.push(day.to_string());
+    }
+    match ordinal {
+        Some(_) if !has_months => Err("`BYDAY` with an ordinal needs `BYMONTH`".to_string()),
+        Some(nth) => Ok(format!("{}#{nth}", list(days))),
+        None => Ok(list(days)),
+    }
+}
+
+/// A single date spec value, or a bracketed list of several.
+fn list(items: Vec<String>) -> String {
+    match items.as_slice() {
+        [item] => item.clone(),
+        _ => format!("[{}]", items.join(",")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_rrule_translation() {
+        let cases = [
+            ("FREQ=YEARLY", "YY-07-4"),
+            ("FREQ=YEARLY;BYMONTH=12", "YY-12-4"),
+            ("FREQ=YEARLY;BYMONTHDAY=1", "YY-MM-1"),
+            ("FREQ=YEARLY;BYMONTH=7;BYMONTHDAY=4", "YY-07-4"),
+            ("FREQ=YEARLY;BYMONTH=1;BYDAY=3MO", "YY-01-MON#3"),
+            ("FREQ=YEARLY;BYDAY=-1MO;BYMONTH=5", "YY-05-MON#L"),
+            ("FREQ=YEARLY;BYMONTH=5,6;BYDAY=-2FR", "YY-[05,06]-FRI#2L"),
+            ("FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=-1", "YY-12-L"),
+        ];
+        for (rrule, spec) in cases {
+            let rule = parse_rrule(rrule, date(2020, 7, 4)).unwrap();
+            assert_eq!(rule.spec, spec.parse::<Spec>().unwrap(), "{rrule}");
+        }
+
+        for rrule in [
+            "FREQ=MONTHLY",
+            "FREQ=YEARLY;BYDAY=1MO",
+            "FREQ=YEARLY;BYMONTH=13",
+            "FREQ=YEARLY;BYMONTH=1;BYDAY=1MO,2TU",
+            "FREQ=YEARLY;BYSETPOS=1",
+            "FREQ=YEARLY;COUNT=2;UNTIL=20250101",
+        ] {
+            assert!(parse_rrule(rrule, date(2020, 7, 4)).is_err(), "{rrule}");
+        }
+    }
+
+    #[test]
+    fn test_recurring_bounds_and_exceptions() {
+        let ics = "BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20200101\r\n\
+            RRULE:FREQ=YEARLY;INTERVAL=2;COUNT=3\r\n\
+            EXDATE;VALUE=DATE:20220101\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20201224\r\n\
+            DURATION:P2D\r\n\
+            RRULE:FREQ=YEARLY;UNTIL=20221231T235959Z\r\n\
+            BEGIN:VALARM\r\n\
+            DURATION:PT15M\r\n\
+            END:VALARM\r\n\
+            END:VEVENT\r\n";
+        let cal = HolidayCalendar::new().with_ics(ics).unwrap();
+
+        let holidays = cal
+            .holidays_between(date(2019, 1, 1), date(2026, 12, 31))
+            .unwrap();
+        let expected = [
+            date(2020, 1, 1),
+            date(2020, 12, 24),
+            date(2020, 12, 25),
+            date(2021, 12, 24),
+            date(2021, 12, 25),
+            date(2022, 12, 24),
+            date(2022, 12, 25),
+            date(2024, 1, 1),
+        ];
+        assert_eq!(holidays, BTreeSet::from(expected));
+    }
+
+    #[test]
+    fn test_folding_and_errors() {
+        let folded =
+            "BEGIN:VEVENT\nSUMMARY:Long\n name\nDTSTART;VALUE=DATE:2025\n 0526\nEND:VEVENT\n";
+        let cal = HolidayCalendar::new().with_ics(folded).unwrap();
+        assert!(cal.is_holiday(date(2025, 5, 26)).unwrap());
+
+        let cases = [
+            (
+                "BEGIN:VEVENT\nDTSTART:20250526T090000Z\nEND:VEVENT\n",
+                "line 2",
+            ),
+            ("BEGIN:VEVENT\nSUMMARY:x\nEND:VEVENT\n", "line 3"),
+            (
+                "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250526\n",
+                "unterminated",
+            ),
+            ("END:VEVENT\n", "line 1"),
+            ("BEGIN:VEVENT\nnot a property\n", "line 2"),
+            (
+                "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250526\nDURATION:P999999999W\n",
+                "line 3",
+            ),
+            (
+                "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250526\nDURATION:P999999999D\n",
+                "line 3",
+            ),
+            (
+                "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250526\nDURATION:P367D\n",
+                "line 3",
+            ),
+            (
+                "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250526\nDTEND;VALUE=DATE:20990526\nEND:VEVENT\n",
+                "line 4",
+            ),
+        ];
+        for (ics, expected) in cases {
+            match HolidayCalendar::new().with_ics(ics) {
+                Err(Error::InvalidHolidayCalendar(msg)) => assert!(msg.contains(expected), "{msg}"),
+                other => panic!("{ics:?} gave {other:?}"),
+            }
+        }
+    }
+}
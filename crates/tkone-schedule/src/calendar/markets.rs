@@ -0,0 +1,490 @@
+//! Reference holiday calendars for major markets.
+//!
+//! | Function | Calendar |
+//! |----------|----------|
+//! | [`us_federal`] | US federal holidays (5 U.S.C. 6103) |
+//! | [`nyse`] | New York Stock Exchange closures, trading hours and early closes |
+//! | [`uk_england_wales`] | UK bank holidays in England and Wales |
+//! | [`target2`] | TARGET2 / T2 closing days for euro payments |
+//!
+//! Each is a [`HolidayCalendar`] built from [`HolidayRule`]s plus the one-off
+//! closures announced since 1999, so it can be extended or combined like any
+//! other. Rules follow current law back to the year each holiday was
+//! introduced; dates before 1999 are not checked against official lists.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tkone_schedule::biz_day::BizDayProcessor;
+//! use tkone_schedule::calendar::{markets, JointCalendar};
+//! use chrono::NaiveDate;
+//!
+//! // A business day in both New York and London
+//! let joint = JointCalendar::new(markets::nyse()).with(markets::uk_england_wales());
+//!
+//! // Friday 23 May 2025 + 1 skips the Spring and Memorial Day bank holidays
+//! let fri = NaiveDate::from_ymd_opt(2025, 5, 23).unwrap().and_hms_opt(0, 0, 0).unwrap();
+//! assert_eq!(joint.add(&fri, 1).unwrap().date(), NaiveDate::from_ymd_opt(2025, 5, 27).unwrap());
+//! ```
+
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+use super::{HolidayCalendar, HolidayRule, Observance};
+
+/// US federal holidays, observed on the Friday before or Monday after when
+/// they fall on a weekend.
+pub fn us_federal() -> HolidayCalendar {
+    HolidayCalendar::new()
+        // New Year's Day
+        .with_holiday_rule(HolidayRule::fixed(1, 1).observed(Observance::Nearest))
+        // Birthday of Martin Luther King, Jr.
+        .with_holiday_rule(HolidayRule::nth_weekday(1, Weekday::Mon, 3).since(1986))
+        // Washington's Birthday
+        .with_holiday_rule(HolidayRule::nth_weekday(2, Weekday::Mon, 3))
+        // Memorial Day
+        .with_holiday_rule(HolidayRule::nth_weekday(5, Weekday::Mon, -1))
+        // Juneteenth National Independence Day
+        .with_holiday_rule(
+            HolidayRule::fixed(6, 19)
+                .observed(Observance::Nearest)
+                .since(2021),
+        )
+        // Independence Day
+        .with_holiday_rule(HolidayRule::fixed(7, 4).observed(Observance::Nearest))
+        // Labor Day
+        .with_holiday_rule(HolidayRule::nth_weekday(9, Weekday::Mon, 1))
+        // Columbus Day
+        .with_holiday_rule(HolidayRule::nth_weekday(10, Weekday::Mon, 2))
+        // Veterans Day
+        .with_holiday_rule(HolidayRule::fixed(11, 11).observed(Observance::Nearest))
+        // Thanksgiving Day
+        .with_holiday_rule(HolidayRule::nth_weekday(11, Weekday::Thu, 4))
+        // Christmas Day
+        .with_holiday_rule(HolidayRule::fixed(12, 25).observed(Observance::Nearest))
+}
+
+/// New York Stock Exchange full-day closures, with its 09:30–16:00 session
+/// and 13:00 early closes, in New York time.
+///
+/// Weekend holidays move to the Friday before or Monday after, except New
+/// Year's Day, which is not made up when it falls on a Saturday. The market
+/// closes early on 3 July, the day after Thanksgiving and Christmas Eve when
+/// they are trading days.
+pub fn nyse() -> HolidayCalendar {
+    let early_close = time(13, 0);
+    HolidayCalendar::new()
+        .with_session(time(9, 30), time(16, 0))
+        .expect("NYSE session closes after it opens")
+        .with_early_close_rule(HolidayRule::fixed(7, 3), early_close)
+        .with_early_close_rule(
+            HolidayRule::spec("YY-11-FRI>=23").expect("day after Thanksgiving spec is valid"),
+            early_close,
+        )
+        .with_early_close_rule(HolidayRule::fixed(12, 24), early_close)
+        // New Year's Day
+        .with_holiday_rule(HolidayRule::fixed(1, 1).observed(Observance::SundayToMonday))
+        // Martin Luther King, Jr. Day
+        .with_holiday_rule(HolidayRule::nth_weekday(1, Weekday::Mon, 3).since(1998))
+        // Washington's Birthday
+        .with_holiday_rule(HolidayRule::nth_weekday(2, Weekday::Mon, 3))
+        // Good Friday
+        .with_holiday_rule(HolidayRule::easter(-2))
+        // Memorial Day
+        .with_holiday_rule(HolidayRule::nth_weekday(5, Weekday::Mon, -1))
+        // Juneteenth National Independence Day
+        .with_holiday_rule(
+            HolidayRule::fixed(6, 19)
+                .observed(Observance::Nearest)
+                .since(2022),
+        )
+        // Independence Day
+        .with_holiday_rule(HolidayRule::fixed(7, 4).observed(Observance::Nearest))
+        // Labor Day
+        .with_holiday_rule(HolidayRule::nth_weekday(9, Weekday::Mon, 1))
+        // Thanksgiving Day
+        .with_holiday_rule(HolidayRule::nth_weekday(11, Weekday::Thu, 4))
+        // Christmas Day
+        .with_holiday_rule(HolidayRule::fixed(12, 25).observed(Observance::Nearest))
+        // September 11 attacks
+        .with_closure(date(2001, 9, 11)..date(2001, 9, 15))
+        .with_holidays([
+            // Days of mourning for Presidents Reagan, Ford, Bush and Carter
+            date(2004, 6, 11),
+            date(2007, 1, 2),
+            date(2018, 12, 5),
+            date(2025, 1, 9),
+        ])
+        // Hurricane Sandy
+        .with_closure(date(2012, 10, 29)..date(2012, 10, 31))
+}
+
+/// UK bank holidays in England and Wales, with substitute days for those
+/// falling on a weekend.
+pub fn uk_england_wales() -> HolidayCalendar {
+    HolidayCalendar::new()
+        // New Year's Day
+        .with_holiday_rule(
+            HolidayRule::fixed(1, 1)
+                .observed(Observance::Substitute)
+                .since(1974),
+        )
+        // Good Friday and Easter Monday
+        .with_holiday_rule(HolidayRule::easter(-2))
+        .with_holiday_rule(HolidayRule::easter(1))
+        // Early May bank holiday, moved to VE Day in 1995 and 2020
+        .with_holiday_rule(
+            HolidayRule::nth_weekday(5, Weekday::Mon, 1)
+                .since(1978)
+                .except_in(1995)
+                .except_in(2020),
+        )
+        .with_holidays([date(1995, 5, 8), date(2020, 5, 8)])
+        // Spring bank holiday, moved for the 2002, 2012 and 2022 jubilees
+        .with_holiday_rule(
+            HolidayRule::nth_weekday(5, Weekday::Mon, -1)
+                .since(1971)
+                .except_in(2002)
+                .except_in(2012)
+                .except_in(2022),
+        )
+        .with_holidays([
+            date(2002, 6, 3),
+            date(2002, 6, 4),
+            date(2012, 6, 4),
+            date(2012, 6, 5),
+            date(2022, 6, 2),
+            date(2022, 6, 3),
+        ])
+        // Summer bank holiday
+        .with_holiday_rule(HolidayRule::nth_weekday(8, Weekday::Mon, -1).since(1971))
+        // Christmas Day and Boxing Day
+        .with_holiday_rule(HolidayRule::fixed(12, 25).observed(Observance::Substitute))
+        .with_holiday_rule(HolidayRule::fixed(12, 26).observed(Observance::Substitute))
+        .with_holidays([
+            // Millennium celebrations
+            date(1999, 12, 31),
+            // Royal wedding
+            date(2011, 4, 29),
+            // State funeral of Queen Elizabeth II
+            date(2022, 9, 19),
+            // Coronation of King Charles III
+            date(2023, 5, 8),
+        ])
+}
+
+/// TARGET2 / T2 closing days, on which euro payments do not settle.
+pub fn target2() -> HolidayCalendar {
+    HolidayCalendar::new()
+        // New Year's Day and Christmas Day
+        .with_holiday_rule(HolidayRule::fixed(1, 1))
+        .with_holiday_rule(HolidayRule::fixed(12, 25))
+        // Good Friday, Easter Monday, Labour Day and Boxing Day
+        .with_holiday_rule(HolidayRule::easter(-2).since(2000))
+        .with_holiday_rule(HolidayRule::easter(1).since(2000))
+        .with_holiday_rule(HolidayRule::fixed(5, 1).since(2000))
+        .with_holiday_rule(HolidayRule::fixed(12, 26).since(2000))
+        // Year-end closures
+        .with_holidays([date(1999, 12, 31), date(2001, 12, 31)])
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("reference calendar dates are valid")
+}
+
+fn time(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).expect("reference session times are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    /// The holidays of `cal` in `year` that fall on a working day.
+    fn closures(cal: &HolidayCalendar, year: i32) -> BTreeSet<NaiveDate> {
+        cal.holidays_between(date(year, 1, 1), date(year, 12, 31))
+            .unwrap()
+            .into_iter()
+            .filter(|date| !cal.is_weekend(*date))
+            .collect()
+    }
+
+    fn dates(year: i32, days: &[(u32, u32)]) -> BTreeSet<NaiveDate> {
+        days.iter().map(|&(m, d)| date(year, m, d)).collect()
+    }
+
+    #[test]
+    fn test_us_federal() {
+        let cal = us_federal();
+        let expected = [
+            (
+                2020,
+                vec![
+                    (1, 1),
+                    (1, 20),
+                    (2, 17),
+                    (5, 25),
+                    (7, 3),
+                    (9, 7),
+                    (10, 12),
+                    (11, 11),
+                    (11, 26),
+                    (12, 25),
+                ],
+            ),
+            (
+                2021,
+                vec![
+                    (1, 1),
+                    (1, 18),
+                    (2, 15),
+                    (5, 31),
+                    (6, 18),
+                    (7, 5),
+                    (9, 6),
+                    (10, 11),
+                    (11, 11),
+                    (11, 25),
+                    (12, 24),
+                    (12, 31),
+                ],
+            ),
+            (
+                2022,
+                vec![
+                    (1, 17),
+                    (2, 21),
+                    (5, 30),
+                    (6, 20),
+                    (7, 4),
+                    (9, 5),
+                    (10, 10),
+                    (11, 11),
+                    (11, 24),
+                    (12, 26),
+                ],
+            ),
+            (
+                2023,
+                vec![
+                    (1, 2),
+                    (1, 16),
+                    (2, 20),
+                    (5, 29),
+                    (6, 19),
+                    (7, 4),
+                    (9, 4),
+                    (10, 9),
+                    (11, 10),
+                    (11, 23),
+                    (12, 25),
+                ],
+            ),
+        ];
+        for (year, days) in expected {
+            assert_eq!(closures(&cal, year), dates(year, &days), "{year}");
+        }
+    }
+
+    #[test]
+    fn test_nyse() {
+        let cal = nyse();
+        let expected = [
+            (
+                2021,
+                vec![
+                    (1, 1),
+                    (1, 18),
+                    (2, 15),
+                    (4, 2),
+                    (5, 31),
+                    (7, 5),
+                    (9, 6),
+                    (11, 25),
+                    (12, 24),
+                ],
+            ),
+            (
+                2022,
+                vec![
+                    (1, 17),
+                    (2, 21),
+                    (4, 15),
+                    (5, 30),
+                    (6, 20),
+                    (7, 4),
+                    (9, 5),
+                    (11, 24),
+                    (12, 26),
+                ],
+            ),
+            (
+                2023,
+                vec![
+                    (1, 2),
+                    (1, 16),
+                    (2, 20),
+                    (4, 7),
+                    (5, 29),
+                    (6, 19),
+                    (7, 4),
+                    (9, 4),
+                    (11, 23),
+                    (12, 25),
+                ],
+            ),
+            (
+                2024,
+                vec![
+                    (1, 1),
+                    (1, 15),
+                    (2, 19),
+                    (3, 29),
+                    (5, 27),
+                    (6, 19),
+                    (7, 4),
+                    (9, 2),
+                    (11, 28),
+                    (12, 25),
+                ],
+            ),
+            (
+                2025,
+                vec![
+                    (1, 1),
+                    (1, 9),
+                    (1, 20),
+                    (2, 17),
+                    (4, 18),
+                    (5, 26),
+                    (6, 19),
+                    (7, 4),
+                    (9, 1),
+                    (11, 27),
+                    (12, 25),
+                ],
+            ),
+        ];
+        for (year, days) in expected {
+            assert_eq!(closures(&cal, year), dates(year, &days), "{year}");
+        }
+    }
+
+    #[test]
+    fn test_nyse_early_closes() {
+        use crate::biz_day::{BizDayProcessor, Session};
+
+        let cal = nyse();
+        let early_closes = |year| -> BTreeSet<NaiveDate> {
+            date(year, 1, 1)
+                .iter_days()
+                .take_while(|day| *day <= date(year, 12, 31))
+                .filter(|day| cal.session(*day).unwrap().close() == Some(time(13, 0)))
+                .collect()
+        };
+        assert_eq!(early_closes(2023), dates(2023, &[(7, 3), (11, 24)]));
+        assert_eq!(
+            early_closes(2024),
+            dates(2024, &[(7, 3), (11, 29), (12, 24)])
+        );
+        assert_eq!(
+            early_closes(2025),
+            dates(2025, &[(7, 3), (11, 28), (12, 24)])
+        );
+
+        assert_eq!(
+            cal.session(date(2025, 12, 23)).unwrap(),
+            Session::Hours {
+                open: time(9, 30),
+                close: time(16, 0)
+            }
+        );
+        // Christmas Eve 2021 was the observed Christmas holiday.
+        assert_eq!(cal.session(date(2021, 12, 24)).unwrap(), Session::Closed);
+    }
+
+    #[test]
+    fn test_uk_england_wales() {
+        let cal = uk_england_wales();
+        let expected = [
+            (
+                2020,
+                vec![
+                    (1, 1),
+                    (4, 10),
+                    (4, 13),
+                    (5, 8),
+                    (5, 25),
+                    (8, 31),
+                    (12, 25),
+                    (12, 28),
+                ],
+            ),
+            (
+                2021,
+                vec![
+                    (1, 1),
+                    (4, 2),
+                    (4, 5),
+                    (5, 3),
+                    (5, 31),
+                    (8, 30),
+                    (12, 27),
+                    (12, 28),
+                ],
+            ),
+            (
+                2022,
+                vec![
+                    (1, 3),
+                    (4, 15),
+                    (4, 18),
+                    (5, 2),
+                    (6, 2),
+                    (6, 3),
+                    (8, 29),
+                    (9, 19),
+                    (12, 26),
+                    (12, 27),
+                ],
+            ),
+            (
+                2023,
+                vec![
+                    (1, 2),
+                    (4, 7),
+                    (4, 10),
+                    (5, 1),
+                    (5, 8),
+                    (5, 29),
+                    (8, 28),
+                    (12, 25),
+                    (12, 26),
+                ],
+            ),
+        ];
+        for (year, days) in expected {
+            assert_eq!(closures(&cal, year), dates(year, &days), "{year}");
+        }
+    }
+
+    #[test]
+    fn test_target2() {
+        let cal = target2();
+        let expected = [
+            (2021, vec![(1, 1), (4, 2), (4, 5)]),
+            (
+                2024,
+                vec![(1, 1), (3, 29), (4, 1), (5, 1), (12, 25), (12, 26)],
+            ),
+            (
+                2025,
+                vec![(1, 1), (4, 18), (4, 21), (5, 1), (12, 25), (12, 26)],
+            ),
+        ];
+        for (year, days) in expected {
+            assert_eq!(closures(&cal, year), dates(year, &days), "{year}");
+        }
+    }
+}
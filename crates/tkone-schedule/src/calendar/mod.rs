@@ -0,0 +1,636 @@
+//! Holiday calendars for business day processing.
+//!
+//! [`HolidayCalendar`](crate::calendar::HolidayCalendar) is a
+//! [`BizDayProcessor`](crate::biz_day::BizDayProcessor) built from a weekend
+//! mask and a set of holidays, either fixed dates or yearly rules written as
+//! date specs. Pass it wherever a
+//! [`WeekendSkipper`](crate::biz_day::WeekendSkipper) would go to have `~NB` /
+//! `~PB` / `~B` / `~nN` / `~nP` adjustments and `nBD` steps skip public
+//! holidays too.
+//!
+//! Exchange holiday lists can be loaded from iCalendar files with
+//! [`HolidayCalendar::with_ics`](crate::calendar::HolidayCalendar::with_ics)
+//! and from CSV files with
+//! [`HolidayCalendar::with_csv`](crate::calendar::HolidayCalendar::with_csv).
+//!
+//! [`markets`](crate::calendar::markets) has ready-made calendars for the US,
+//! NYSE, England and Wales and TARGET2, built from
+//! [`HolidayRule`](crate::calendar::HolidayRule)s.
+//!
+//! [`JointCalendar`](crate::calendar::JointCalendar) and
+//! [`AnyCalendar`](crate::calendar::AnyCalendar) combine several processors,
+//! of the same or of different types, into one whose business days are open
+//! in all of them, or in any.
+//!
+//! A calendar given opening hours or early closes reports them through
+//! [`BizDayProcessor::session`](crate::biz_day::BizDayProcessor::session).
+
+mod composite;
+mod csv;
+mod ics;
+pub mod markets;
+mod rule;
+
+pub use composite::{AnyCalendar, JointCalendar};
+pub use rule::{HolidayRule, Observance};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use fallible_iterator::FallibleIterator;
+
+use crate::biz_day::{BizDayProcessor, Direction, Session};
+use crate::date::NaiveSpecIterator;
+use crate::prelude::*;
+
+/// How far a rule's business day adjustment may move a holiday from its
+/// nominal date and still be found.
+const MAX_RULE_SHIFT_DAYS: i64 = 7;
+
+/// How long a run of non-business days may be before `add` / `sub` fail with
+/// [`Error::IteratorNotConverged`].
+const MAX_CLOSED_DAYS: i64 = 366;
+
+/// A [`BizDayProcessor`] with a configurable weekend and a set of holidays.
+///
+/// | Method | Adds |
+/// |--------|------|
+/// | [`with_weekend`](Self::with_weekend) | Replaces the weekend (Saturday and Sunday by default) |
+/// | [`with_holiday`](Self::with_holiday) / [`with_holidays`](Self::with_holidays) | One-off holiday dates |
+/// | [`with_closure`](Self::with_closure) | A closure of several consecutive days |
+/// | [`with_rule`](Self::with_rule) | A holiday recurring every year, written as a date spec |
+/// | [`with_holiday_rule`](Self::with_holiday_rule) | A [`HolidayRule`] with its own weekend observance and years |
+/// | [`with_ics`](Self::with_ics) | The all-day events of an iCalendar file |
+/// | [`with_csv`](Self::with_csv) | The rows of a `date,name` CSV file |
+/// | [`with_session`](Self::with_session) | Regular opening hours |
+/// | [`with_early_close`](Self::with_early_close) / [`with_early_close_rule`](Self::with_early_close_rule) | Business days closing early |
+///
+/// A rule is any date spec without a cadence (`nY`, `nM`, `nW`, `nD`, `nBD`,
+/// `nWD`), such as `YY-12-25`, `YY-05-MON#L` or `YY-EASTER-2`. An adjustment
+/// on the rule gives the day the holiday is observed: `YY-12-25~NB` moves a
+/// weekend Christmas to the next day that is not a weekend day of this
+/// calendar. `~NW` / `~PW` / `~W` keep their fixed Saturday–Sunday meaning.
+///
+/// [`add`](BizDayProcessor::add) and [`sub`](BizDayProcessor::sub) jump whole
+/// weeks at a time and only walk day by day over the last week.
+/// [`Direction::Nearest`] follows the [`WeekendSkipper`](crate::biz_day::WeekendSkipper)
+/// conventions: it picks the closest business day, stepping forward on a tie,
+/// but stays within the month when the closest one lies outside it — so a
+/// closed 1st of the month steps forward and a closed month end steps back.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::biz_day::BizDayProcessor;
+/// use tkone_schedule::calendar::HolidayCalendar;
+/// use chrono::{NaiveDate, Weekday};
+///
+/// // A Gulf market: Friday–Saturday weekend, National Day on 2 December
+/// let cal = HolidayCalendar::new()
+///     .with_weekend(&[Weekday::Fri, Weekday::Sat])
+///     .unwrap()
+///     .with_rule("YY-12-02")
+///     .unwrap();
+///
+/// let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert!(cal.is_biz_day(&day(11, 30)).unwrap()); // Sunday
+/// assert!(!cal.is_biz_day(&day(12, 2)).unwrap());
+///
+/// // Monday 1 December + 2 business days skips the holiday → Thursday 4th
+/// assert_eq!(cal.add(&day(12, 1), 2).unwrap(), day(12, 4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    /// Weekend flags indexed from Monday.
+    weekend: [bool; 7],
+    dates: BTreeSet<NaiveDate>,
+    rules: Vec<HolidayRule>,
+    /// Regular opening and closing times.
+    hours: Option<(NaiveTime, NaiveTime)>,
+    early_closes: BTreeMap<NaiveDate, NaiveTime>,
+    early_close_rules: Vec<(HolidayRule, NaiveTime)>,
+}
+
+impl Default for HolidayCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HolidayCalendar {
+    /// A calendar with a Saturday–Sunday weekend and no holidays.
+    pub fn new() -> Self {
+        let mut weekend = [false; 7];
+        weekend[Weekday::Sat.num_days_from_monday() as usize] = true;
+        weekend[Weekday::Sun.num_days_from_monday() as usize] = true;
+        Self {
+            weekend,
+            dates: BTreeSet::new(),
+            rules: Vec::new(),
+            hours: None,
+            early_closes: BTreeMap::new(),
+            early_close_rules: Vec::new(),
+        }
+    }
+
+    /// Replace the weekend with `days`, which may be empty but must leave at
+    /// least one working day.
+    pub fn with_weekend(mut self, days: &[Weekday]) -> Result<Self> {
+        let mut weekend = [false; 7];
+        for day in days {
+            weekend[day.num_days_from_monday() as usize] = true;
+        }
+        if weekend.iter().all(|&closed| closed) {
+            return Err(Error::InvalidHolidayCalendar(
+                "the weekend must leave at least one working day".to_string(),
+            ));
+        }
+        self.weekend = weekend;
+        Ok(self)
+    }
+
+    /// Add a one-off holiday.
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.dates.insert(date);
+        self
+    }
+
+    /// Add several one-off holidays.
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.dates.extend(dates);
+        self
+    }
+
+    /// Add a closure covering each day of `days`. The end is exclusive, the
+    /// first day open again, as in a `start,end,name` row read by
+    /// [`with_csv`](Self::with_csv), an iCalendar `DTEND` or a tempo
+    /// `calendar_holiday` row.
+    pub fn with_closure(mut self, days: Range<NaiveDate>) -> Self {
+        self.dates
+            .extend(days.start.iter_days().take_while(|date| *date < days.end));
+        self
+    }
+
+    /// Add a holiday that recurs as the date spec `rule` selects.
+    ///
+    /// Fails with [`Error::InvalidHolidayCalendar`] for a rule with a cadence,
+    /// which would have no start date to count from.
+    pub fn with_rule(self, rule: &str) -> Result<Self> {
+        Ok(self.with_holiday_rule(HolidayRule::spec(rule)?))
+    }
+
+    /// Add a recurring holiday.
+    pub fn with_holiday_rule(mut self, rule: HolidayRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Open every business day from `open` until `close`.
+    ///
+    /// Fails with [`Error::InvalidHolidayCalendar`] unless `close` is after
+    /// `open`.
+    pub fn with_session(mut self, open: NaiveTime, close: NaiveTime) -> Result<Self> {
+        if close <= open {
+            return Err(Error::InvalidHolidayCalendar(format!(
+                "session closes at {close}, not after it opens at {open}"
+            )));
+        }
+        self.hours = Some((open, close));
+        Ok(self)
+    }
+
+    /// Close early at `close` on `date`, which stays a business day.
+    pub fn with_early_close(mut self, date: NaiveDate, close: NaiveTime) -> Self {
+        self.early_closes.insert(date, close);
+        self
+    }
+
+    /// Close early at `close` on the days `rule` selects, such as Christmas
+    /// Eve. A day the rule selects that is also a holiday stays closed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::{BizDayProcessor, Session};
+    /// use tkone_schedule::calendar::{HolidayCalendar, HolidayRule};
+    /// use chrono::{NaiveDate, NaiveTime};
+    ///
+    /// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// let cal = HolidayCalendar::new()
+    ///     .with_session(time(9, 30), time(16, 0))
+    ///     .unwrap()
+    ///     .with_early_close_rule(HolidayRule::fixed(12, 24), time(13, 0));
+    ///
+    /// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    /// assert_eq!(cal.session(date(12, 23)).unwrap(), Session::Hours { open: time(9, 30), close: time(16, 0) });
+    /// assert_eq!(cal.session(date(12, 24)).unwrap(), Session::Hours { open: time(9, 30), close: time(13, 0) });
+    /// assert_eq!(cal.session(date(12, 27)).unwrap(), Session::Closed); // Saturday
+    /// ```
+    pub fn with_early_close_rule(mut self, rule: HolidayRule, close: NaiveTime) -> Self {
+        self.early_close_rules.push((rule, close));
+        self
+    }
+
+    /// Whether `date` falls on the weekend.
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend[date.weekday().num_days_from_monday() as usize]
+    }
+
+    /// Whether `date` is a holiday, whether or not it also falls on the weekend.
+    pub fn is_holiday(&self, date: NaiveDate) -> Result<bool> {
+        Ok(self.dates.contains(&date) || !self.rule_holidays(date, date)?.is_empty())
+    }
+
+    /// Every holiday in `from..=to`, fixed dates and rules alike.
+    pub fn holidays_between(&self, from: NaiveDate, to: NaiveDate) -> Result<BTreeSet<NaiveDate>> {
+        if from > to {
+            return Ok(BTreeSet::new());
+        }
+        let mut holidays = self.rule_holidays(from, to)?;
+        holidays.extend(self.dates.range(from..=to));
+        Ok(holidays)
+    }
+
+    /// Where a holiday on `date` is observed under `observance`, or `None`
+    /// when it is not. [`Observance::Substitute`] is resolved separately, once
+    /// every other holiday is known.
+    fn observe(&self, date: NaiveDate, observance: Observance) -> Option<NaiveDate> {
+        match observance {
+            Observance::Nearest if self.is_weekend(date) => {
+                let working = |date: &NaiveDate| !self.is_weekend(*date);
+                let next = date.iter_days().find(working)?;
+                let prev = date.iter_days().rev().find(working)?;
+                Some(if date - prev < next - date {
+                    prev
+                } else {
+                    next
+                })
+            }
+            Observance::SundayToMonday if date.weekday() == Weekday::Sun => date.succ_opt(),
+            Observance::SundayToMonday if date.weekday() == Weekday::Sat => None,
+            _ => Some(date),
+        }
+    }
+
+    /// The days in `from..=to` on which a rule holiday is observed.
+    fn rule_holidays(&self, from: NaiveDate, to: NaiveDate) -> Result<BTreeSet<NaiveDate>> {
+        if self.rules.is_empty() {
+            return Ok(BTreeSet::new());
+        }
+        // Rules resolve their adjustments against the weekend alone.
+        let weekend = Self {
+            weekend: self.weekend,
+            ..Self::new()
+        };
+        // Substitute days go to the first free working day, so the holidays
+        // they may collide with are gathered over a wider window.
+        let shift = Duration::days(MAX_RULE_SHIFT_DAYS);
+        let (wide_from, wide_to) = (from - shift, to + shift);
+        let mut holidays: BTreeSet<NaiveDate> =
+            self.dates.range(wide_from..=wide_to).copied().collect();
+        let mut substitutes = Vec::new();
+        for rule in &self.rules {
+            let start = (wide_from - shift - Duration::days(rule.span as i64)).max(rule.since);
+            let probe = start.pred_opt().unwrap_or(start).and_time(NaiveTime::MIN);
+            let mut iter =
+                NaiveSpecIterator::from_spec_after(rule.spec.clone(), weekend.clone(), probe);
+            while let Some(occurrence) = iter.next()? {
+                let actual = occurrence.actual().date();
+                if actual > wide_to + shift || actual > rule.until {
+                    break;
+                }
+                if !rule.includes(actual) {
+                    continue;
+                }
+                let observed = occurrence.observed().date();
+                let closed = observed.iter_days().take(rule.span as usize);
+                if rule.observance == Observance::Substitute {
+                    substitutes.extend(closed);
+                } else {
+                    holidays.extend(closed.filter_map(|date| self.observe(date, rule.observance)));
+                }
+            }
+        }
+
+        substitutes.sort();
+        for date in substitutes {
+            let free = date
+                .iter_days()
+                .find(|date| !self.is_weekend(*date) && !holidays.contains(date));
+            holidays.extend(free);
+        }
+        Ok(holidays
+            .into_iter()
+            .filter(|date| (from..=to).contains(date))
+            .collect())
+    }
+
+    /// The earliest early close on `date`, if any.
+    fn early_close(&self, date: NaiveDate) -> Result<Option<NaiveTime>> {
+        let mut earliest = self.early_closes.get(&date).copied();
+        for (rule, close) in &self.early_close_rules {
+            let days = Self {
+                weekend: self.weekend,
+                rules: vec![rule.clone()],
+                ..Self::new()
+            };
+            if days.is_holiday(date)? {
+                earliest = Some(earliest.map_or(*close, |earliest| earliest.min(*close)));
+            }
+        }
+        Ok(earliest)
+    }
+
+    /// `dtm` moved by `num` business days, forwards or backwards.
+    ///
+    /// Whole weeks are skipped while more than a week's worth of business
+    /// days remain; each week holds the working days of the weekend mask
+    /// less any holidays that fall on them.
+    fn shift(&self, dtm: &NaiveDateTime, num: u32, forward: bool) -> Result<NaiveDateTime> {
+        let per_week = self.weekend.iter().filter(|&&closed| !closed).count() as u32;
+        let step = if forward {
+            Duration::days(1)
+        } else {
+            Duration::days(-1)
+        };
+        let mut current = *dtm;
+        let mut remaining = num;
+        let mut closed_days = 0;
+
+        while remaining > per_week {
+            let weeks = (remaining - 1) / per_week;
+            let next = current + step * 7 * weeks as i32;
+            let (from, to) = if forward {
+                (current.date() + step, next.date())
+            } else {
+                (next.date(), current.date() + step)
+            };
+            let holidays = self
+                .holidays_between(from, to)?
+                .into_iter()
+                .filter(|date| !self.is_weekend(*date))
+                .count() as u32;
+            let counted = weeks * per_week - holidays;
+            closed_days = if counted == 0 {
+                closed_days + 7 * weeks as i64
+            } else {
+                0
+            };
+            if closed_days > MAX_CLOSED_DAYS {
+                return Err(Error::IteratorNotConverged);
+            }
+            remaining -= counted;
+            current = next;
+        }
+
+        while remaining > 0 {
+            current += step;
+            if self.is_biz_day(&current)? {
+                remaining -= 1;
+                closed_days = 0;
+            } else {
+                closed_days += 1;
+                if closed_days > MAX_CLOSED_DAYS {
+                    return Err(Error::IteratorNotConverged);
+                }
+            }
+        }
+        Ok(current)
+    }
+
+    /// The closest business day to `dtm`, stepping forward on a tie and
+    /// staying within the month when the closest one lies outside it.
+    fn nearest_biz_day(&self, dtm: &NaiveDateTime) -> Result<NaiveDateTime> {
+        if self.is_biz_day(dtm)? {
+            return Ok(*dtm);
+        }
+        let prev = self.shift(dtm, 1, false)?;
+        let next = self.shift(dtm, 1, true)?;
+        Ok(nearest(dtm, prev, next))
+    }
+}
+
+/// Whichever of `prev` and `next` is closer to `dtm`, preferring `next` on a
+/// tie and the one in `dtm`'s month when only one of them is.
+fn nearest(dtm: &NaiveDateTime, prev: NaiveDateTime, next: NaiveDateTime) -> NaiveDateTime {
+    let same_month = |other: &NaiveDateTime| other.month() == dtm.month();
+    let (closest, other) = if *dtm - prev < next - *dtm {
+        (prev, next)
+    } else {
+        (next, prev)
+    };
+    if !same_month(&closest) && same_month(&other) {
+        other
+    } else {
+        closest
+    }
+}
+
+impl BizDayProcessor for HolidayCalendar {
+    fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+        let date = dtm.date();
+        Ok(!self.is_weekend(date) && !self.is_holiday(date)?)
+    }
+
+    fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+        match direction {
+            Direction::Nearest => self.nearest_biz_day(dtm),
+            Direction::Prev if self.is_biz_day(dtm)? => Ok(*dtm),
+            Direction::Prev => self.shift(dtm, 1, false),
+            Direction::Next if self.is_biz_day(dtm)? => Ok(*dtm),
+            Direction::Next => self.shift(dtm, 1, true),
+        }
+    }
+
+    fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        self.shift(dtm, num, true)
+    }
+
+    fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+        self.shift(dtm, num, false)
+    }
+
+    /// [`Session::Hours`] on a business day with opening hours or an early
+    /// close, from midnight when only the early close is known, and
+    /// [`Session::Full`] on any other business day.
+    fn session(&self, date: NaiveDate) -> Result<Session> {
+        if !self.is_biz_day(&date.and_time(NaiveTime::MIN))? {
+            return Ok(Session::Closed);
+        }
+        let early = self.early_close(date)?;
+        Ok(match (self.hours, early) {
+            (Some((open, close)), early) => Session::Hours {
+                open,
+                close: early.map_or(close, |early| early.min(close)),
+            },
+            (None, Some(close)) => Session::Hours {
+                open: NaiveTime::MIN,
+                close,
+            },
+            (None, None) => Session::Full,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biz_day::WeekendSkipper;
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    /// Steps one day at a time, as a reference for the week-skipping `shift`.
+    fn shift_by_days(
+        cal: &HolidayCalendar,
+        dtm: NaiveDateTime,
+        num: u32,
+        forward: bool,
+    ) -> NaiveDateTime {
+        let step = if forward {
+            Duration::days(1)
+        } else {
+            Duration::days(-1)
+        };
+        let (mut current, mut remaining) = (dtm, num);
+        while remaining > 0 {
+            current += step;
+            if cal.is_biz_day(&current).unwrap() {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    #[test]
+    fn test_add_sub_skip_weeks_and_holidays() {
+        let cal = HolidayCalendar::new()
+            .with_rule("YY-12-25~NB")
+            .unwrap()
+            .with_rule("YY-01-01~NB")
+            .unwrap()
+            .with_rule("YY-EASTER-2")
+            .unwrap()
+            .with_holiday(NaiveDate::from_ymd_opt(2025, 5, 8).unwrap());
+        let gulf = cal
+            .clone()
+            .with_weekend(&[Weekday::Fri, Weekday::Sat])
+            .unwrap();
+
+        for cal in [&cal, &gulf] {
+            for start in [day(2024, 12, 20), day(2025, 4, 17), day(2025, 6, 1)] {
+                for num in [0, 1, 4, 5, 6, 23, 250, 600] {
+                    assert_eq!(
+                        cal.add(&start, num).unwrap(),
+                        shift_by_days(cal, start, num, true)
+                    );
+                    assert_eq!(
+                        cal.sub(&start, num).unwrap(),
+                        shift_by_days(cal, start, num, false)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_adjustment_uses_weekend() {
+        // Christmas 2021 and New Year 2022 fall on Saturdays.
+        let cal = HolidayCalendar::new().with_rule("YY-12-25~NB").unwrap();
+        assert!(cal.is_holiday(day(2021, 12, 27).date()).unwrap());
+        assert!(!cal.is_holiday(day(2021, 12, 24).date()).unwrap());
+
+        let gulf = HolidayCalendar::new()
+            .with_weekend(&[Weekday::Fri, Weekday::Sat])
+            .unwrap()
+            .with_rule("YY-12-25~NB")
+            .unwrap();
+        assert!(gulf.is_holiday(day(2021, 12, 26).date()).unwrap());
+    }
+
+    #[test]
+    fn test_nearest_matches_weekend_skipper() {
+        let cal = HolidayCalendar::new();
+        let skipper = WeekendSkipper::new();
+        let mut dtm = day(2024, 1, 1);
+        while dtm < day(2026, 1, 1) {
+            assert_eq!(
+                cal.find_biz_day(&dtm, Direction::Nearest).unwrap(),
+                skipper.find_biz_day(&dtm, Direction::Nearest).unwrap(),
+                "{dtm}"
+            );
+            dtm += Duration::days(1);
+        }
+
+        // A Wednesday holiday is a tie and steps forward.
+        let cal = cal.with_holiday(day(2025, 1, 15).date());
+        assert_eq!(
+            cal.find_biz_day(&day(2025, 1, 15), Direction::Nearest)
+                .unwrap(),
+            day(2025, 1, 16)
+        );
+    }
+
+    #[test]
+    fn test_session_takes_earliest_close() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let eve = day(2025, 12, 24).date();
+        let cal = HolidayCalendar::new()
+            .with_early_close(eve, time(14, 0))
+            .with_early_close_rule(HolidayRule::fixed(12, 24), time(12, 0));
+        assert_eq!(
+            cal.session(eve).unwrap(),
+            Session::Hours {
+                open: NaiveTime::MIN,
+                close: time(12, 0)
+            }
+        );
+        assert_eq!(cal.session(eve.succ_opt().unwrap()).unwrap(), Session::Full);
+
+        // An early close after the regular close leaves the session unchanged.
+        let cal = cal.with_session(time(9, 0), time(11, 0)).unwrap();
+        assert_eq!(
+            cal.session(eve).unwrap(),
+            Session::Hours {
+                open: time(9, 0),
+                close: time(11, 0)
+            }
+        );
+        assert!(matches!(
+            cal.with_session(time(11, 0), time(9, 0)),
+            Err(Error::InvalidHolidayCalendar(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_calendars() {
+        let every_day = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert!(matches!(
+            HolidayCalendar::new().with_weekend(&every_day),
+            Err(Error::InvalidHolidayCalendar(_))
+        ));
+        assert!(matches!(
+            HolidayCalendar::new().with_rule("YY-3M-15"),
+            Err(Error::InvalidHolidayCalendar(_))
+        ));
+
+        let closed = HolidayCalendar::new().with_rule("YY-MM-DD").unwrap();
+        assert_eq!(
+            closed.add(&day(2025, 1, 1), 1),
+            Err(Error::IteratorNotConverged)
+        );
+    }
+}
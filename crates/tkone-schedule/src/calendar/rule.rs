@@ -0,0 +1,310 @@
+//! Recurring holiday definitions.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::date::{Cycle, DayCycle, Spec};
+use crate::prelude::*;
+
+/// Where a holiday that falls on the weekend is observed.
+///
+/// "Weekend" and "working day" follow the weekend of the
+/// [`HolidayCalendar`](super::HolidayCalendar) the rule is added to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Observance {
+    /// On the day itself only; a weekend holiday gives no extra day off.
+    #[default]
+    Actual,
+    /// On the nearest working day, the later one on a tie — Saturday to
+    /// Friday and Sunday to Monday for a Saturday–Sunday weekend (US federal).
+    Nearest,
+    /// On the following Monday when it falls on a Sunday; a Saturday holiday
+    /// is not observed (NYSE New Year's Day).
+    SundayToMonday,
+    /// On the next working day that is not already a holiday, so a weekend
+    /// Christmas and Boxing Day take the following Monday and Tuesday (UK
+    /// substitute days).
+    Substitute,
+}
+
+/// A holiday recurring every year, with the years it applies to and where it
+/// is observed.
+///
+/// | Constructor | Holiday |
+/// |-------------|---------|
+/// | [`fixed`](Self::fixed) | The same month and day every year |
+/// | [`nth_weekday`](Self::nth_weekday) | The *n*th (or *n*th-to-last) weekday of a month |
+/// | [`easter`](Self::easter) | A day a fixed number of days from Western Easter Sunday |
+/// | [`spec`](Self::spec) | Any date spec without a cadence |
+///
+/// One-off closures need no rule; add them with
+/// [`HolidayCalendar::with_holiday`](super::HolidayCalendar::with_holiday).
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::calendar::{HolidayCalendar, HolidayRule, Observance};
+/// use chrono::{NaiveDate, Weekday};
+///
+/// let cal = HolidayCalendar::new()
+///     // Juneteenth, a US federal holiday since 2021
+///     .with_holiday_rule(HolidayRule::fixed(6, 19).observed(Observance::Nearest).since(2021))
+///     // Thanksgiving
+///     .with_holiday_rule(HolidayRule::nth_weekday(11, Weekday::Thu, 4));
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// assert!(!cal.is_holiday(date(2020, 6, 19)).unwrap());
+/// assert!(cal.is_holiday(date(2021, 6, 18)).unwrap()); // Saturday observed on Friday
+/// assert!(cal.is_holiday(date(2024, 11, 28)).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayRule {
+    pub(super) spec: Spec,
+    /// Nominal dates outside `since..=until` are not holidays.
+    pub(super) since: NaiveDate,
+    pub(super) until: NaiveDate,
+    /// Only every `every`th year counted from `since` has the holiday.
+    pub(super) every: u32,
+    /// Days closed from each observed date.
+    pub(super) span: u32,
+    /// Nominal dates that are not holidays.
+    pub(super) except: BTreeSet<NaiveDate>,
+    /// Years without the holiday.
+    pub(super) except_years: BTreeSet<i32>,
+    pub(super) observance: Observance,
+}
+
+impl HolidayRule {
+    pub(super) fn new(spec: Spec) -> Self {
+        Self {
+            spec,
+            since: NaiveDate::MIN,
+            until: NaiveDate::MAX,
+            every: 1,
+            span: 1,
+            except: BTreeSet::new(),
+            except_years: BTreeSet::new(),
+            observance: Observance::Actual,
+        }
+    }
+
+    /// The holiday on `month`/`day` every year, skipped in years without
+    /// that day, as with 29 February.
+    ///
+    /// # Panics
+    ///
+    /// If `month` is not 1–12 or `day` is not 1–31.
+    pub fn fixed(month: u32, day: u32) -> Self {
+        Self::new(
+            format!("YY-{month:02}-{day}")
+                .parse()
+                .expect("fixed holiday month and day are in range"),
+        )
+    }
+
+    /// The `nth` `weekday` of `month`, counting from the end of the month
+    /// for a negative `nth` (`-1` for the last).
+    ///
+    /// # Panics
+    ///
+    /// If `month` is not 1–12 or `nth` is 0.
+    pub fn nth_weekday(month: u32, weekday: Weekday, nth: i8) -> Self {
+        let day = match weekday {
+            Weekday::Mon => "MON",
+            Weekday::Tue => "TUE",
+            Weekday::Wed => "WED",
+            Weekday::Thu => "THU",
+            Weekday::Fri => "FRI",
+            Weekday::Sat => "SAT",
+            Weekday::Sun => "SUN",
+        };
+        let nth = match nth {
+            -1 => "L".to_string(),
+            nth if nth < 0 => format!("{}L", -nth),
+            nth => nth.to_string(),
+        };
+        Self::new(
+            format!("YY-{month:02}-{day}#{nth}")
+                .parse()
+                .expect("nth weekday month and ordinal are in range"),
+        )
+    }
+
+    /// The day `offset` days from Western Easter Sunday: `-2` for Good
+    /// Friday, `1` for Easter Monday.
+    pub fn easter(offset: i32) -> Self {
+        Self::new(
+            format!("YY-EASTER{offset:+}")
+                .parse()
+                .expect("Easter spec is well formed"),
+        )
+    }
+
+    /// The holiday selected by the date spec `rule`, which may carry its own
+    /// business day adjustment.
+    ///
+    /// Fails with [`Error::InvalidHolidayCalendar`] for a rule with a cadence,
+    /// which would have no start date to count from.
+    pub fn spec(rule: &str) -> Result<Self> {
+        let spec: Spec = rule.parse()?;
+        if has_cadence(&spec) {
+            return Err(Error::InvalidHolidayCalendar(format!(
+                "holiday rule `{rule}` must not use a cadence"
+            )));
+        }
+        Ok(Self::new(spec))
+    }
+
+    /// Observe the holiday as `observance` says when it falls on the weekend.
+    pub fn observed(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Apply the rule from `year` on.
+    pub fn since(mut self, year: i32) -> Self {
+        self.since = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(NaiveDate::MIN);
+        self
+    }
+
+    /// Apply the rule up to and including `year`.
+    pub fn until(mut self, year: i32) -> Self {
+        self.until = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(NaiveDate::MAX);
+        self
+    }
+
+    /// Skip the holiday in `year`, as when it is moved to a one-off date.
+    pub fn except_in(mut self, year: i32) -> Self {
+        self.except_years.insert(year);
+        self
+    }
+
+    /// Whether the rule's holiday falls on the nominal date `actual`.
+    pub(super) fn includes(&self, actual: NaiveDate) -> bool {
+        (self.since..=self.until).contains(&actual)
+            && (actual.year() - self.since.year()).rem_euclid(self.every as i32) == 0
+            && !self.except.contains(&actual)
+            && !self.except_years.contains(&actual.year())
+    }
+}
+
+/// Whether `spec` steps relative to where iteration starts, which a holiday
+/// rule cannot.
+fn has_cadence(spec: &Spec) -> bool {
+    let relative = |cycle: &Cycle| match cycle {
+        Cycle::NextNth(_) => true,
+        Cycle::IsoWeeks(weeks) => matches!(weeks.as_ref(), Cycle::NextNth(_)),
+        _ => false,
+    };
+    relative(&spec.years) || relative(&spec.months) || matches!(spec.days, DayCycle::NextNth(..))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::HolidayCalendar;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn holidays(cal: &HolidayCalendar, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        cal.holidays_between(from, to)
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_since_until_and_except_in() {
+        let rule = HolidayRule::fixed(6, 19)
+            .since(2021)
+            .until(2024)
+            .except_in(2023);
+        for (year, expected) in [
+            (2020, false),
+            (2021, true),
+            (2023, false),
+            (2024, true),
+            (2025, false),
+        ] {
+            assert_eq!(rule.includes(date(year, 6, 19)), expected, "{year}");
+        }
+
+        // 19 June 2021 is a Saturday; with `Actual` it stays on the day itself.
+        let cal = HolidayCalendar::new().with_holiday_rule(rule);
+        assert_eq!(
+            holidays(&cal, date(2019, 1, 1), date(2026, 12, 31)),
+            [date(2021, 6, 19), date(2022, 6, 19), date(2024, 6, 19)]
+        );
+    }
+
+    #[test]
+    fn test_every_counts_years_from_since() {
+        let rule = HolidayRule {
+            every: 4,
+            ..HolidayRule::fixed(11, 5).since(2024)
+        };
+        assert!(rule.includes(date(2024, 11, 5)));
+        assert!(!rule.includes(date(2025, 11, 5)));
+        assert!(!rule.includes(date(2027, 11, 5)));
+        assert!(rule.includes(date(2028, 11, 5)));
+
+        let cal = HolidayCalendar::new().with_holiday_rule(rule);
+        assert_eq!(
+            holidays(&cal, date(2020, 1, 1), date(2032, 12, 31)),
+            [date(2024, 11, 5), date(2028, 11, 5), date(2032, 11, 5)]
+        );
+    }
+
+    #[test]
+    fn test_substitute_skips_neighbouring_holiday() {
+        let cal = HolidayCalendar::new()
+            .with_holiday_rule(HolidayRule::fixed(12, 25).observed(Observance::Substitute))
+            .with_holiday_rule(HolidayRule::fixed(12, 26).observed(Observance::Substitute));
+
+        // 2022: Christmas on Sunday, Boxing Day on Monday → Christmas moves
+        // past Boxing Day to Tuesday.
+        assert_eq!(
+            holidays(&cal, date(2022, 12, 20), date(2022, 12, 31)),
+            [date(2022, 12, 26), date(2022, 12, 27)]
+        );
+        // 2021: both on the weekend → Monday and Tuesday.
+        assert_eq!(
+            holidays(&cal, date(2021, 12, 20), date(2021, 12, 31)),
+            [date(2021, 12, 27), date(2021, 12, 28)]
+        );
+    }
+
+    #[test]
+    fn test_nearest_follows_calendar_weekend() {
+        let rule = HolidayRule::fixed(5, 23).observed(Observance::Nearest);
+
+        // Friday–Saturday weekend: a Friday holiday is observed on Thursday,
+        // a Saturday one on Sunday.
+        let gulf = HolidayCalendar::new()
+            .with_weekend(&[Weekday::Fri, Weekday::Sat])
+            .unwrap()
+            .with_holiday_rule(rule.clone());
+        assert_eq!(
+            holidays(&gulf, date(2025, 5, 1), date(2025, 5, 31)),
+            [date(2025, 5, 22)]
+        );
+        assert_eq!(
+            holidays(&gulf, date(2026, 5, 1), date(2026, 5, 31)),
+            [date(2026, 5, 24)]
+        );
+
+        // A Friday-only weekend ties between Thursday and Saturday; the later
+        // day wins.
+        let friday = HolidayCalendar::new()
+            .with_weekend(&[Weekday::Fri])
+            .unwrap()
+            .with_holiday_rule(rule);
+        assert_eq!(
+            holidays(&friday, date(2025, 5, 1), date(2025, 5, 31)),
+            [date(2025, 5, 24)]
+        );
+    }
+}
@@ -1,8 +1,13 @@
-use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 
 use crate::{
     biz_day::BizDayProcessor,
-    date::spec::{Cycle, DayCycle, LastDayOption, NextNthDayOption, WeekdayOption},
+    date::spec::{
+        BizDayOption, Cycle, DayCycle, EasterCalendar, LastDayOption, NextNthDayOption,
+        WeekdayOption,
+    },
     utils::{DateLikeUtils, WeekdayStartingMonday},
 };
 
@@ -80,6 +85,8 @@ impl DateComponent for Cycle {
                         .unwrap(),
                 )
             }
+            // Week numbers and quarters never drive the year component.
+            Cycle::IsoWeeks(_) | Cycle::Quarters(_) => None,
         }
     }
 }
@@ -179,13 +186,17 @@ impl DateComponent for DayCycle {
                     }
                     WeekdayOption::Starting(occurrence) => {
                         let occurrence = occurrence.unwrap_or(1);
-                        let wd = weekdays.iter().next().unwrap().0; // This logic assumes single weekday for now
                         let mut target_dt = after.clone();
                         loop {
-                            if let Some(candidate) = target_dt.to_months_weekday(&wd, occurrence) {
-                                if candidate > *after {
-                                    return Some(candidate);
-                                }
+                            // Each listed weekday contributes its own nth
+                            // occurrence; take the earliest one still ahead.
+                            let candidate = weekdays
+                                .iter()
+                                .filter_map(|wd| target_dt.to_months_weekday(&wd.0, occurrence))
+                                .filter(|candidate| candidate > after)
+                                .min();
+                            if let Some(candidate) = candidate {
+                                return Some(candidate);
                             }
                             // Move to next month to try again
                             let (y, m) = ffwd_months(&target_dt, 1);
@@ -197,15 +208,17 @@ impl DateComponent for DayCycle {
                     }
                     WeekdayOption::Ending(occurrence) => {
                         let occurrence = occurrence.unwrap_or(1);
-                        let wd = weekdays.iter().next().unwrap().0; // This logic assumes single weekday for now
                         let mut target_dt = after.clone();
                         loop {
-                            if let Some(candidate) =
-                                target_dt.to_months_last_weekday(&wd, occurrence)
-                            {
-                                if candidate > *after {
-                                    return Some(candidate);
-                                }
+                            let candidate = weekdays
+                                .iter()
+                                .filter_map(|wd| {
+                                    target_dt.to_months_last_weekday(&wd.0, occurrence)
+                                })
+                                .filter(|candidate| candidate > after)
+                                .min();
+                            if let Some(candidate) = candidate {
+                                return Some(candidate);
                             }
                             // Move to next month to try again
                             let (y, m) = ffwd_months(&target_dt, 1);
@@ -215,12 +228,135 @@ impl DateComponent for DayCycle {
                                 .unwrap();
                         }
                     }
+                    WeekdayOption::OnOrAfter(_) | WeekdayOption::OnOrBefore(_) => {
+                        let mut target_dt = *after;
+                        loop {
+                            let candidate = weekdays
+                                .iter()
+                                .filter_map(|wd| anchored_weekday(&target_dt, &wd.0, option))
+                                .filter(|candidate| candidate > after)
+                                .min();
+                            if let Some(candidate) = candidate {
+                                return Some(candidate);
+                            }
+                            // Move to next month to try again
+                            let (y, m) = ffwd_months(&target_dt, 1);
+                            target_dt = NaiveDate::from_ymd_opt(y as i32, m, 1)
+                                .unwrap()
+                                .and_hms_opt(0, 0, 0)
+                                .unwrap();
+                        }
+                    }
+                }
+            }
+            DayCycle::OnBizDay(BizDayOption::Every(n)) => {
+                // Next multiple of `n` among this month's business days; the
+                // count restarts from the first business day of the next month.
+                if let Some(candidate) = next_biz_day_multiple(after, *n, &context.bd_processor)
+                {
+                    return Some(candidate);
                 }
+                let (y, m) = ffwd_months(after, 1);
+                let next_month = NaiveDate::from_ymd_opt(y as i32, m, 1)
+                    .unwrap()
+                    .and_time(after.time());
+                biz_day_of_month(&next_month, &BizDayOption::Every(*n), &context.bd_processor)
+            }
+            DayCycle::OnBizDay(option) => {
+                // Try the current month first; if its selected business day has
+                // already passed, fall through to the following month.
+                if let Some(candidate) =
+                    biz_day_of_month(after, option, &context.bd_processor)
+                {
+                    if candidate > *after {
+                        return Some(candidate);
+                    }
+                }
+                let (y, m) = ffwd_months(after, 1);
+                let next_month = NaiveDate::from_ymd_opt(y as i32, m, 1)
+                    .unwrap()
+                    .and_time(after.time());
+                biz_day_of_month(&next_month, option, &context.bd_processor)
             }
+            // Day-of-year and Easter specs are resolved by `next_year_day`
+            // and `next_easter_day`.
+            DayCycle::OnYearDays(_) | DayCycle::OnEaster(..) => None,
         }
     }
 }
 
+/// Resolve the `weekday` on or after / on or before the anchor day of an
+/// `OnOrAfter` / `OnOrBefore` option within the month of `dtm`, keeping the
+/// time of `dtm`.
+///
+/// Returns `None` when that weekday falls outside the month.
+fn anchored_weekday(
+    dtm: &NaiveDateTime,
+    weekday: &Weekday,
+    option: &WeekdayOption,
+) -> Option<NaiveDateTime> {
+    let last_day = dtm.to_last_day_of_month();
+    // Anchors outside the month clamp to its first or last day.
+    let at_anchor = |anchor: &Option<u32>| match anchor {
+        Some(day) if *day < last_day.day() => dtm.with_day((*day).max(1)),
+        _ => Some(last_day),
+    };
+    let candidate = match option {
+        WeekdayOption::OnOrAfter(anchor) => at_anchor(anchor)?.to_weekday(weekday),
+        WeekdayOption::OnOrBefore(anchor) => at_anchor(anchor)?.to_prev_weekday(weekday),
+        _ => return None,
+    };
+    (candidate.year() == dtm.year() && candidate.month() == dtm.month()).then_some(candidate)
+}
+
+/// Resolve the business day selected by `option` within the month of `dtm`,
+/// keeping the time of `dtm`. For `Every(n)` this is the first selected day,
+/// the *n*th business day.
+///
+/// Returns `None` when the month has fewer business days than requested.
+fn biz_day_of_month<BDP: BizDayProcessor>(
+    dtm: &NaiveDateTime,
+    option: &BizDayOption,
+    bdp: &BDP,
+) -> Option<NaiveDateTime> {
+    let candidate = match option {
+        BizDayOption::Starting(n) | BizDayOption::Every(n) => {
+            let before_first = dtm.to_first_day_of_month() - Duration::days(1);
+            bdp.add(&before_first, *n).ok()?
+        }
+        BizDayOption::Ending(n) => {
+            let after_last = dtm.to_last_day_of_month() + Duration::days(1);
+            bdp.sub(&after_last, n.unwrap_or(1)).ok()?
+        }
+    };
+    (candidate.year() == dtm.year() && candidate.month() == dtm.month()).then_some(candidate)
+}
+
+/// Resolve the first business day strictly after `after`, within the same
+/// month, whose position among the month's business days is a multiple of
+/// `n`. Keeps the time of `after`.
+///
+/// Returns `None` when no such business day remains in the month.
+fn next_biz_day_multiple<BDP: BizDayProcessor>(
+    after: &NaiveDateTime,
+    n: u32,
+    bdp: &BDP,
+) -> Option<NaiveDateTime> {
+    if n == 0 {
+        return None;
+    }
+    let mut elapsed = 0;
+    let mut day = after.to_first_day_of_month();
+    while day.date() <= after.date() {
+        if bdp.is_biz_day(&day).ok()? {
+            elapsed += 1;
+        }
+        day += Duration::days(1);
+    }
+    let target = (elapsed / n + 1) * n;
+    biz_day_of_month(after, &BizDayOption::Starting(target), bdp)
+}
+
 /// A utility function to find the next date in a sequence of months.
 ///
 /// `must_advance` controls the behaviour for `NextNth` cycles when the
@@ -299,7 +435,286 @@ pub(super) fn find_next_in_month_cycle(
                     .unwrap(),
             )
         }
+        // Quarters are resolved to months by `quarter_months` beforehand.
+        Cycle::IsoWeeks(_) | Cycle::Quarters(_) => None,
+    }
+}
+
+/// Resolve a [`Cycle::Quarters`] month rule into the calendar months the
+/// year → month → day pipeline should visit, given the first month (1–12) of
+/// the fiscal year. Any other month rule is returned unchanged.
+///
+/// Day rules counted from the end of a period land in the last month of each
+/// selected quarter, other fixed day rules in its first month, and rolling
+/// rules visit all three months.
+pub(super) fn quarter_months<'a>(
+    months: &'a Cycle,
+    days: &DayCycle,
+    fiscal_year_start: u32,
+) -> Cow<'a, Cycle> {
+    let Cycle::Quarters(quarters) = months else {
+        return Cow::Borrowed(months);
+    };
+    let selected: Vec<u32> = match quarters.as_ref() {
+        // Quarters outside 1–4 never match.
+        Cycle::Values(values) => values.range(1..=4).copied().collect(),
+        Cycle::NextNth(n) => (1..=4).filter(|q| (q - 1) % n == 0).collect(),
+        _ => (1..=4).collect(),
+    };
+    let offsets: &[u32] = match days {
+        DayCycle::OnDays {
+            days,
+            option: LastDayOption::LastDay,
+        } if days.is_empty() => &[2],
+        DayCycle::OnBizDay(BizDayOption::Ending(_))
+        | DayCycle::OnWeekDays {
+            option:
+                WeekdayOption::Ending(_)
+                | WeekdayOption::OnOrAfter(None)
+                | WeekdayOption::OnOrBefore(None),
+            ..
+        } => &[2],
+        DayCycle::OnDays { .. }
+        | DayCycle::OnBizDay(BizDayOption::Starting(_))
+        | DayCycle::OnWeekDays {
+            option:
+                WeekdayOption::Starting(_)
+                | WeekdayOption::OnOrAfter(Some(_))
+                | WeekdayOption::OnOrBefore(Some(_)),
+            ..
+        } => &[0],
+        _ => &[0, 1, 2],
+    };
+    let months = selected
+        .iter()
+        .flat_map(|q| offsets.iter().map(move |k| (q - 1) * 3 + k))
+        .map(|offset| (fiscal_year_start - 1 + offset) % 12 + 1)
+        .collect();
+    Cow::Owned(Cycle::Values(months))
+}
+
+/// Find the first date strictly after `after` for an `nBDA` rule under a
+/// month rule spanning several months (`3M`, `QQ`, `Q1`), keeping the time of
+/// `after`.
+///
+/// Business days are counted from the start of each selected period, so the
+/// count runs across all of its months and restarts at the next period.
+/// Quarters start from `fiscal_year_start`, while a `NextNth` month cadence is
+/// aligned to the month of the iterator start.
+pub(super) fn next_period_biz_day<BDP: BizDayProcessor>(
+    years: &Cycle,
+    months: &Cycle,
+    n: u32,
+    after: &NaiveDateTime,
+    context: &IterContext<BDP>,
+    fiscal_year_start: u32,
+) -> Option<NaiveDateTime> {
+    // Months are numbered consecutively from year 0 so periods can straddle
+    // a year end.
+    let month_index = |dtm: &NaiveDateTime| dtm.year() * 12 + dtm.month0() as i32;
+    let first_day = |index: i32| {
+        NaiveDate::from_ymd_opt(index.div_euclid(12), index.rem_euclid(12) as u32 + 1, 1)
+    };
+    let (anchor, length, quarters) = match months {
+        Cycle::Quarters(quarters) => (fiscal_year_start as i32 - 1, 3, Some(quarters.as_ref())),
+        Cycle::NextNth(length) => (month_index(&context.start_dt), *length as i32, None),
+        _ => return None,
+    };
+    if n == 0 {
+        return None;
+    }
+    let start_year = context.start_dt.year();
+    let mut period = month_index(after) - (month_index(after) - anchor).rem_euclid(length);
+    while period.div_euclid(12) < after.year() + MAX_YEAR_SCAN {
+        let start = first_day(period)?;
+        let quarter = ((period - anchor).rem_euclid(12) / 3 + 1) as u32;
+        period += length;
+        if let Cycle::Values(values) = years {
+            if values.iter().all(|&y| (y as i32) < start.year()) {
+                return None;
+            }
+        }
+        if let Some(quarters) = quarters {
+            let selected = match quarters {
+                Cycle::Values(values) => values.contains(&quarter),
+                Cycle::NextNth(k) => (quarter - 1).is_multiple_of(*k),
+                _ => true,
+            };
+            if !selected {
+                continue;
+            }
+        }
+        let end = first_day(period)?;
+        let mut elapsed = 0;
+        for day in start.iter_days().take_while(|day| *day < end) {
+            let day = day.and_time(after.time());
+            if !context.bd_processor.is_biz_day(&day).ok()? {
+                continue;
+            }
+            elapsed += 1;
+            if elapsed % n == 0
+                && day.date() > after.date()
+                && year_matches(years, day.year(), start_year)
+            {
+                return Some(day);
+            }
+        }
+    }
+    None
+}
+
+/// The Gregorian calendar repeats every 400 years, so a year-based search that
+/// has found nothing within this many years never will.
+const MAX_YEAR_SCAN: i32 = 400;
+
+/// Whether `year` is selected by the year component of a spec, with `NextNth`
+/// aligned to `start_year`.
+fn year_matches(years: &Cycle, year: i32, start_year: i32) -> bool {
+    match years {
+        Cycle::AsIs | Cycle::ForEach => true,
+        Cycle::Values(values) => values.contains(&(year as u32)),
+        Cycle::NextNth(n) => {
+            let diff = year - start_year;
+            diff >= 0 && diff % (*n as i32) == 0
+        }
+        Cycle::IsoWeeks(_) | Cycle::Quarters(_) => false,
+    }
+}
+
+/// Find the first date strictly after `after` for a day-of-year spec
+/// (`<years>-D<days>`), keeping the time of `after`.
+///
+/// Day 366 is only produced in leap years.
+pub(super) fn next_year_day<BDP: BizDayProcessor>(
+    years: &Cycle,
+    days: &BTreeSet<u32>,
+    after: &NaiveDateTime,
+    context: &IterContext<BDP>,
+) -> Option<NaiveDateTime> {
+    let start_year = context.start_dt.year();
+    for year in after.year()..after.year() + MAX_YEAR_SCAN {
+        if let Cycle::Values(values) = years {
+            if values.iter().all(|&y| (y as i32) < year) {
+                return None;
+            }
+        }
+        if !year_matches(years, year, start_year) {
+            continue;
+        }
+        let candidate = days
+            .iter()
+            .filter_map(|&ordinal| NaiveDate::from_yo_opt(year, ordinal))
+            .map(|date| date.and_time(after.time()))
+            .find(|candidate| candidate > after);
+        if candidate.is_some() {
+            return candidate;
+        }
+    }
+    None
+}
+
+/// Find the first date strictly after `after` for an Easter spec
+/// (`<years>-EASTER[±n]`), keeping the time of `after`.
+///
+/// Years are matched on the year of Easter Sunday itself, so an offset that
+/// crosses into a neighbouring year still belongs to the selected year.
+pub(super) fn next_easter_day<BDP: BizDayProcessor>(
+    years: &Cycle,
+    calendar: EasterCalendar,
+    offset: i32,
+    after: &NaiveDateTime,
+    context: &IterContext<BDP>,
+) -> Option<NaiveDateTime> {
+    let start_year = context.start_dt.year();
+    // A positive offset may carry an earlier year's Easter past `after`.
+    let back = 1 + offset.max(0) / 365;
+    for year in after.year() - back..after.year() + MAX_YEAR_SCAN {
+        if let Cycle::Values(values) = years {
+            if values.iter().all(|&y| (y as i32) < year) {
+                return None;
+            }
+        }
+        if !year_matches(years, year, start_year) {
+            continue;
+        }
+        let candidate = NaiveDate::from_ymd_opt(year, 1, 1)?
+            .to_easter(calendar)?
+            .checked_add_signed(Duration::days(offset as i64))?
+            .and_time(after.time());
+        if candidate > *after {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Find the first date strictly after `after` for an ISO week spec
+/// (`<years>-W<weeks>-<weekdays>`), keeping the time of `after`.
+///
+/// The year component is matched against the ISO week-numbering year, and a
+/// `NextNth` week cadence is aligned to the ISO week of the iterator start.
+/// Week 53 is only produced in ISO years that have one.
+pub(super) fn next_iso_week_day<BDP: BizDayProcessor>(
+    years: &Cycle,
+    weeks: &Cycle,
+    days: &DayCycle,
+    after: &NaiveDateTime,
+    context: &IterContext<BDP>,
+) -> Option<NaiveDateTime> {
+    let weekdays: Vec<Weekday> = match days {
+        DayCycle::OnWeekDays { weekdays, .. } => weekdays.iter().map(|wd| wd.0).collect(),
+        _ => vec![
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ],
+    };
+    let start_monday = context.start_dt.date().week(Weekday::Mon).first_day();
+    let start_year = context.start_dt.iso_week().year();
+    let after_year = after.iso_week().year();
+
+    for year in after_year..after_year + MAX_YEAR_SCAN {
+        if let Cycle::Values(values) = years {
+            if values.iter().all(|&y| (y as i32) < year) {
+                return None;
+            }
+        }
+        if !year_matches(years, year, start_year) {
+            continue;
+        }
+        let weeks_in_year = NaiveDate::from_ymd_opt(year, 12, 28)?.iso_week().week();
+        for week in 1..=weeks_in_year {
+            let Some(monday) = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon) else {
+                continue;
+            };
+            if monday + Duration::days(6) < after.date() {
+                continue;
+            }
+            let week_selected = match weeks {
+                Cycle::Values(values) => values.contains(&week),
+                Cycle::NextNth(n) => {
+                    ((monday - start_monday).num_weeks()).rem_euclid(*n as i64) == 0
+                }
+                _ => true,
+            };
+            if !week_selected {
+                continue;
+            }
+            let candidate = weekdays
+                .iter()
+                .map(|&wd| monday + Duration::days(wd.num_days_from_monday() as i64))
+                .map(|date| date.and_time(after.time()))
+                .find(|candidate| candidate > after);
+            if candidate.is_some() {
+                return candidate;
+            }
+        }
     }
+    None
 }
 
 pub(super) fn ffwd_months(dtm: &NaiveDateTime, num: u32) -> (u32, u32) {
@@ -1,11 +1,18 @@
 use super::{
     component::{self, DateComponent},
-    spec::{BizDayAdjustment, Cycle, DayCycle, LastDayOption, NextNthDayOption, Spec},
+    set::{SpecSet, SpecSetIterator},
+    spec::{
+        parse_spec_strict, BizDayAdjustment, BizDayOption, Cycle, DayCycle, LastDayOption,
+        NextNthDayOption, Spec, WeekdayOption,
+    },
 };
-use crate::biz_day::WeekendSkipper;
+use crate::biz_day::{Session, WeekendSkipper};
+use crate::reverse::{NaiveReverse, Rewind};
 use crate::utils::next_result_to_tz;
 use crate::{biz_day::BizDayProcessor, prelude::*, DstPolicy, Occurrence};
-use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
 use fallible_iterator::FallibleIterator;
 use std::{marker::PhantomData, sync::LazyLock};
 
@@ -15,6 +22,7 @@ use std::{marker::PhantomData, sync::LazyLock};
 // supply an end) are callable.
 pub struct StartDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct NoStart;
+pub struct Before;
 pub struct EndDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct EndSpec(String);
 pub struct NoEnd;
@@ -36,6 +44,7 @@ pub struct NotSealed;
 /// | `new(spec, bdp, tz)` | Next occurrence after `Utc::now()` | open-ended schedule from now |
 /// | `new_after(spec, bdp, dtm)` | First occurrence **after** `dtm` | schedule from a known cursor |
 /// | `new_with_start(spec, bdp, start)` | `start` itself is the first item | anchor to a fixed start date |
+/// | `new_before(spec, bdp, dtm)` | Latest occurrence **before** `dtm`, then earlier ones | back-fills and reconciliation |
 ///
 /// # Examples
 ///
@@ -67,6 +76,8 @@ pub struct SpecIteratorBuilder<Tz: TimeZone, BDP: BizDayProcessor, START, END, S
     end: END,
     timezone: Tz,
     dst_policy: DstPolicy,
+    fiscal_year_start: u32,
+    strict: bool,
     marker_sealed: PhantomData<S>,
 }
 
@@ -82,6 +93,64 @@ impl<Tz: TimeZone, BDP: BizDayProcessor, START, END, S>
         self.dst_policy = policy;
         self
     }
+
+    /// Set the first month (1–12) of the fiscal year used by quarter tokens
+    /// (`QQ`, `nQ`, `Q1`…`Q4`).
+    ///
+    /// Defaults to `1` (January), i.e. calendar quarters. With `4`, `Q1` is
+    /// April–June and `YY-QQ-LBD` falls at the end of June, September,
+    /// December and March. A year component still names calendar years, so
+    /// `2025-Q4-L` is 2025-03-31. `build()` fails with [`Error::InvalidDateSpec`]
+    /// for a month outside 1–12.
+    pub fn with_fiscal_year_start(mut self, month: u32) -> Self {
+        self.fiscal_year_start = month;
+        self
+    }
+
+    /// Validate the spec with [`parse_spec_strict`] when building, so that
+    /// values that can never match fail `build()` with
+    /// [`Error::InvalidSpecComponent`] instead of yielding nothing.
+    ///
+    /// Every member of a [`SpecSet`] and an end spec are checked the same
+    /// way; spans are relative to the string they were found in.
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::WeekendSkipper;
+    /// use tkone_schedule::date::SpecIteratorBuilder;
+    /// use tkone_schedule::{Error, SpecComponent};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let lenient = SpecIteratorBuilder::new_after("2025-D366", WeekendSkipper::new(), start);
+    /// assert!(lenient.build().is_ok());
+    ///
+    /// let strict = SpecIteratorBuilder::new_after("2025-D366", WeekendSkipper::new(), start).strict();
+    /// match strict.build() {
+    ///     Err(Error::InvalidSpecComponent { component, span, .. }) => {
+    ///         assert_eq!(component, SpecComponent::Day);
+    ///         assert_eq!(span, 6..9);
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Strictly validate the spec, or each member of a spec set, if
+    /// [`strict`](Self::strict) was requested.
+    fn check_strict(&self) -> Result<()> {
+        if !self.strict {
+            return Ok(());
+        }
+        for member in self.spec.split('|') {
+            let trimmed = member.trim_start();
+            let at = trimmed.as_ptr() as usize - self.spec.as_ptr() as usize;
+            super::validate_strict(trimmed.trim_end(), at)?;
+        }
+        Ok(())
+    }
 }
 
 impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, NoStart, NoEnd, NotSealed> {
@@ -113,11 +182,14 @@ impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, NoStart, N
             bd_processor: bdp,
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            fiscal_year_start: 1,
+            strict: false,
             marker_sealed: PhantomData,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
+        self.check_strict()?;
         Ok(SpecIterator {
             tz: self.dtm.timezone(),
             dst_policy: self.dst_policy,
@@ -125,7 +197,91 @@ impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, NoStart, N
                 &self.spec,
                 self.bd_processor,
                 self.dtm.naive_local(),
-            )?,
+            )?
+            .with_fiscal_year_start(self.fiscal_year_start)?,
+        })
+    }
+
+    /// Build a [`SpecSetIterator`] from a `|`-separated [`SpecSet`] string;
+    /// every member starts strictly after the same datetime.
+    pub fn build_set(self) -> Result<SpecSetIterator<Tz, BDP>> {
+        self.check_strict()?;
+        let set: SpecSet = self.spec.parse()?;
+        let members = set
+            .specs
+            .iter()
+            .map(|member| {
+                NaiveSpecIterator::new_after(
+                    &member.to_string(),
+                    self.bd_processor.clone(),
+                    self.dtm.naive_local(),
+                )?
+                .with_fiscal_year_start(self.fiscal_year_start)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpecSetIterator::new(
+            self.dtm.timezone(),
+            self.dst_policy,
+            members,
+        ))
+    }
+}
+
+impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, Before, NoEnd, NotSealed> {
+    /// Create an iterator that walks **backwards** from `dtm`, yielding
+    /// occurrences strictly before it in descending order.
+    ///
+    /// Each occurrence is exactly the one a forward iterator would yield,
+    /// business day adjustment included; the order is that of the actual
+    /// (unadjusted) dates. `NextNth` cadences count back from `dtm` as
+    /// `new_after` counts forward from it. Iteration ends once no occurrence
+    /// is found within 1000 years of `dtm`.
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::WeekendSkipper;
+    /// use tkone_schedule::date::SpecIteratorBuilder;
+    /// use tkone_schedule::Occurrence;
+    /// use chrono::{TimeZone, Utc};
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// // The last three month ends before 2024-06-15, Saturday 2024-03-30 → Friday
+    /// let dtm = Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap();
+    /// let iter = SpecIteratorBuilder::new_before("YY-1M-L~PW", WeekendSkipper::new(), dtm)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let dates: Vec<_> = iter.take(3).collect().unwrap();
+    /// let day = |m, d| Utc.with_ymd_and_hms(2024, m, d, 0, 0, 0).unwrap();
+    /// assert_eq!(dates, vec![
+    ///     Occurrence::Exact(day(5, 31)),
+    ///     Occurrence::Exact(day(4, 30)),
+    ///     Occurrence::AdjustedEarlier(day(3, 31), day(3, 29)),
+    /// ]);
+    /// ```
+    pub fn new_before(spec: &str, bdp: BDP, dtm: DateTime<Tz>) -> Self {
+        SpecIteratorBuilder {
+            timezone: dtm.timezone(),
+            dtm,
+            start: Before,
+            spec: spec.to_string(),
+            bd_processor: bdp,
+            end: NoEnd,
+            dst_policy: DstPolicy::default(),
+            fiscal_year_start: 1,
+            strict: false,
+            marker_sealed: PhantomData,
+        }
+    }
+
+    pub fn build(self) -> Result<ReverseSpecIterator<Tz, BDP>> {
+        self.check_strict()?;
+        let spec: Spec = self.spec.parse()?;
+        let dtm = self.dtm.naive_local();
+        let rewind = DateRewind::new(spec, self.bd_processor, self.fiscal_year_start)?;
+        Ok(ReverseSpecIterator {
+            tz: self.dtm.timezone(),
+            dst_policy: self.dst_policy,
+            naive_spec_iter: NaiveReverse::new_before(rewind, dtm),
         })
     }
 }
@@ -134,6 +290,7 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
     SpecIteratorBuilder<Tz, BDP, StartDateTime<Tz>, EndDateTime<Tz>, Sealed>
 {
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
+        self.check_strict()?;
         let start = self.start.0;
         Ok(SpecIterator {
             tz: start.timezone(),
@@ -143,15 +300,46 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
                 self.bd_processor,
                 start.naive_local(),
                 self.end.0.naive_local(),
-            )?,
+            )?
+            .with_fiscal_year_start(self.fiscal_year_start)?,
         })
     }
+
+    /// Build a [`SpecSetIterator`] from a `|`-separated [`SpecSet`] string;
+    /// every member shares the same start and end.
+    pub fn build_set(self) -> Result<SpecSetIterator<Tz, BDP>> {
+        self.check_strict()?;
+        let start = self.start.0;
+        let set: SpecSet = self.spec.parse()?;
+        let members = set
+            .specs
+            .iter()
+            .map(|member| {
+                NaiveSpecIterator::new_with_end(
+                    &member.to_string(),
+                    self.bd_processor.clone(),
+                    start.naive_local(),
+                    self.end.0.naive_local(),
+                )?
+                .with_fiscal_year_start(self.fiscal_year_start)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpecSetIterator::new(
+            start.timezone(),
+            self.dst_policy,
+            members,
+        ))
+    }
 }
 
 impl<Tz: TimeZone, BDP: BizDayProcessor>
     SpecIteratorBuilder<Tz, BDP, StartDateTime<Tz>, EndSpec, Sealed>
 {
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
+        self.check_strict()?;
+        if self.strict {
+            parse_spec_strict(&self.end.0)?;
+        }
         let start = self.start.0;
         Ok(SpecIterator {
             tz: start.timezone(),
@@ -161,9 +349,39 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
                 start.naive_local(),
                 self.bd_processor,
                 &self.end.0,
+                self.fiscal_year_start,
             )?,
         })
     }
+
+    /// Build a [`SpecSetIterator`] from a `|`-separated [`SpecSet`] string;
+    /// every member shares the same start and end spec.
+    pub fn build_set(self) -> Result<SpecSetIterator<Tz, BDP>> {
+        self.check_strict()?;
+        if self.strict {
+            parse_spec_strict(&self.end.0)?;
+        }
+        let start = self.start.0;
+        let set: SpecSet = self.spec.parse()?;
+        let members = set
+            .specs
+            .iter()
+            .map(|member| {
+                NaiveSpecIterator::new_with_end_spec(
+                    &member.to_string(),
+                    start.naive_local(),
+                    self.bd_processor.clone(),
+                    &self.end.0,
+                    self.fiscal_year_start,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpecSetIterator::new(
+            start.timezone(),
+            self.dst_policy,
+            members,
+        ))
+    }
 }
 
 impl<Tz: TimeZone, BDP: BizDayProcessor>
@@ -222,6 +440,8 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
             bd_processor: bdp,
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            fiscal_year_start: 1,
+            strict: false,
             marker_sealed: PhantomData,
         }
     }
@@ -245,6 +465,8 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
             bd_processor: self.bd_processor,
             end: EndSpec(end_spec.into()),
             dst_policy: self.dst_policy,
+            fiscal_year_start: self.fiscal_year_start,
+            strict: self.strict,
             marker_sealed: PhantomData,
             timezone: self.timezone,
         }
@@ -266,12 +488,15 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
             bd_processor: self.bd_processor,
             end: EndDateTime(end),
             dst_policy: self.dst_policy,
+            fiscal_year_start: self.fiscal_year_start,
+            strict: self.strict,
             marker_sealed: PhantomData,
             timezone: self.timezone,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
+        self.check_strict()?;
         Ok(SpecIterator::<Tz, BDP> {
             tz: self.start.0.timezone(),
             dst_policy: self.dst_policy,
@@ -279,9 +504,34 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
                 &self.spec,
                 self.bd_processor,
                 self.start.0.naive_local(),
-            )?,
+            )?
+            .with_fiscal_year_start(self.fiscal_year_start)?,
         })
     }
+
+    /// Build a [`SpecSetIterator`] from a `|`-separated [`SpecSet`] string;
+    /// `start` is yielded once, attributed to the first member.
+    pub fn build_set(self) -> Result<SpecSetIterator<Tz, BDP>> {
+        self.check_strict()?;
+        let set: SpecSet = self.spec.parse()?;
+        let members = set
+            .specs
+            .iter()
+            .map(|member| {
+                NaiveSpecIterator::new_with_start(
+                    &member.to_string(),
+                    self.bd_processor.clone(),
+                    self.start.0.naive_local(),
+                )?
+                .with_fiscal_year_start(self.fiscal_year_start)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpecSetIterator::new(
+            self.start.0.timezone(),
+            self.dst_policy,
+            members,
+        ))
+    }
 }
 
 static WEEKEND_SKIPPER: LazyLock<WeekendSkipper> = LazyLock::new(|| WeekendSkipper::new());
@@ -353,6 +603,31 @@ impl<Tz: TimeZone, BDM: BizDayProcessor> SpecIterator<Tz, BDM> {
     }
 }
 
+/// Timezone-aware calendar-day recurrence iterator that walks backwards,
+/// yielding occurrences in descending order of their actual date.
+///
+/// Construct via [`SpecIteratorBuilder::new_before`]. Implements
+/// [`fallible_iterator::FallibleIterator`].
+#[derive(Debug)]
+pub struct ReverseSpecIterator<Tz: TimeZone, BDP: BizDayProcessor> {
+    tz: Tz,
+    dst_policy: DstPolicy,
+    naive_spec_iter: NaiveReverse<DateRewind<BDP>>,
+}
+
+impl<Tz: TimeZone, BDP: BizDayProcessor> FallibleIterator for ReverseSpecIterator<Tz, BDP> {
+    type Item = Occurrence<DateTime<Tz>>;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        let next = self.naive_spec_iter.next()?;
+        let Some(next) = next else {
+            return Ok(None);
+        };
+        Ok(Some(next_result_to_tz(&self.tz, next, self.dst_policy)?))
+    }
+}
+
 /// Non-timezone-aware calendar-day recurrence iterator.
 ///
 /// The timezone-aware [`SpecIterator`] delegates all date arithmetic to this
@@ -379,12 +654,19 @@ pub struct NaiveSpecIterator<BDP: BizDayProcessor> {
     index: usize,
     start: Option<NaiveDateTime>,
     end: Option<NaiveDateTime>,
+    fiscal_year_start: u32,
+    /// The last resolved date before business day adjustment.
+    unadjusted: Option<Occurrence<NaiveDateTime>>,
 }
 
 impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
     pub(crate) fn new_after(spec: &str, bdp: BDP, dtm: NaiveDateTime) -> Result<Self> {
-        let spec = spec.parse()?;
-        Ok(Self {
+        Ok(Self::from_spec_after(spec.parse()?, bdp, dtm))
+    }
+
+    /// Like [`Self::new_after`] for an already parsed spec.
+    pub(crate) fn from_spec_after(spec: Spec, bdp: BDP, dtm: NaiveDateTime) -> Self {
+        Self {
             spec,
             dtm,
             context: component::IterContext {
@@ -394,7 +676,9 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
             index: 0,
             start: None,
             end: None,
-        })
+            fiscal_year_start: 1,
+            unadjusted: None,
+        }
     }
 
     fn new_with_start(spec: &str, bdp: BDP, start: NaiveDateTime) -> Result<Self> {
@@ -409,6 +693,8 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
             index: 0,
             start: Some(start),
             end: None,
+            fiscal_year_start: 1,
+            unadjusted: None,
         })
     }
 
@@ -429,6 +715,8 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
             index: 0,
             start: Some(start),
             end: Some(end),
+            fiscal_year_start: 1,
+            unadjusted: None,
         })
     }
 
@@ -437,9 +725,11 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
         start: NaiveDateTime,
         bdp: BDP,
         end_spec: &str,
+        fiscal_year_start: u32,
     ) -> Result<Self> {
         let spec = spec.parse()?;
         let end = Self::new_with_start(end_spec, bdp.clone(), start.clone())?
+            .with_fiscal_year_start(fiscal_year_start)?
             .next()?
             .ok_or(Error::InvalidEndSpec)?;
         Ok(Self {
@@ -452,9 +742,28 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
             index: 0,
             start: Some(start),
             end: Some(end.observed().clone()),
+            fiscal_year_start,
+            unadjusted: None,
         })
     }
 
+    /// Set the first month (1–12) of the fiscal year that quarter tokens are
+    /// counted from.
+    pub(crate) fn with_fiscal_year_start(mut self, month: u32) -> Result<Self> {
+        if !(1..=12).contains(&month) {
+            return Err(Error::InvalidDateSpec(format!(
+                "fiscal year start month must be 1-12, got {month}"
+            )));
+        }
+        self.fiscal_year_start = month;
+        Ok(self)
+    }
+
+    /// The nominal step between dates of the spec; see [`spec_cadence`].
+    pub(crate) fn cadence(&self) -> Duration {
+        spec_cadence(&self.spec)
+    }
+
     pub(crate) fn update_cursor(&mut self, dtm: NaiveDateTime) {
         self.dtm = dtm;
         self.start = None;
@@ -462,28 +771,71 @@ impl<BDP: BizDayProcessor> NaiveSpecIterator<BDP> {
     }
 }
 
-impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
-    type Item = Occurrence<NaiveDateTime>;
-    type Error = Error;
-
-    fn next(&mut self) -> Result<Option<Self::Item>> {
-        if let Some(end) = &self.end {
-            if &self.dtm >= end {
-                return Ok(None);
+impl<BDP: BizDayProcessor + Clone> NaiveSpecIterator<BDP> {
+    /// Resolve the first raw date of `spec` strictly after `dtm`, dispatching
+    /// on the spec form.
+    fn next_candidate(
+        spec: &Spec,
+        dtm: NaiveDateTime,
+        context: &component::IterContext<BDP>,
+        fiscal_year_start: u32,
+    ) -> Result<Option<NaiveDateTime>> {
+        Ok(match (&spec.months, &spec.days) {
+            (_, DayCycle::OnYearDays(days)) => {
+                component::next_year_day(&spec.years, days, &dtm, context)
             }
-        }
+            (_, DayCycle::OnEaster(calendar, offset)) => {
+                component::next_easter_day(&spec.years, *calendar, *offset, &dtm, context)
+            }
+            (Cycle::IsoWeeks(weeks), _) => {
+                component::next_iso_week_day(&spec.years, weeks, &spec.days, &dtm, context)
+            }
+            (
+                Cycle::Quarters(_) | Cycle::NextNth(2..),
+                DayCycle::OnBizDay(BizDayOption::Every(n)),
+            ) => component::next_period_biz_day(
+                &spec.years,
+                &spec.months,
+                *n,
+                &dtm,
+                context,
+                fiscal_year_start,
+            ),
+            _ => {
+                let months = component::quarter_months(&spec.months, &spec.days, fiscal_year_start);
+                Self::next_calendar_day(spec, &months, dtm, context)?
+            }
+        })
+    }
 
-        if self.index == 0 {
-            if let Some(start) = &self.start {
-                if &self.dtm <= start {
-                    self.dtm = start.clone();
-                    self.index += 1;
-                    return Ok(Some(Occurrence::Exact(start.clone())));
-                }
+    /// Whether `candidate` falls on a date selected by any of the spec's
+    /// exclusion sub-specs.
+    ///
+    /// Each sub-spec is resolved from the end of the previous day with the
+    /// iterator's own context, so cadences stay aligned to the iterator start.
+    fn is_excluded(&self, candidate: &NaiveDateTime) -> Result<bool> {
+        let probe = candidate.date().and_hms_opt(0, 0, 0).unwrap() - chrono::Duration::seconds(1);
+        for exclusion in &self.spec.exclusions {
+            let hit =
+                Self::next_candidate(exclusion, probe, &self.context, self.fiscal_year_start)?;
+            if hit.is_some_and(|hit| hit.date() == candidate.date()) {
+                return Ok(true);
             }
         }
+        Ok(false)
+    }
 
-        let mut candidate = self.dtm;
+    /// Run the year → month → day pipeline of a `<years>-<months>-<days>` spec
+    /// and return the first raw calendar date strictly after `dtm`.
+    ///
+    /// `months` is the spec's month rule with any quarters already resolved.
+    fn next_calendar_day(
+        spec: &Spec,
+        months: &Cycle,
+        dtm: NaiveDateTime,
+        context: &component::IterContext<BDP>,
+    ) -> Result<Option<NaiveDateTime>> {
+        let mut candidate = dtm;
         let mut iterations = 0u32;
         loop {
             iterations += 1;
@@ -512,13 +864,11 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             //    component preserved), so the month component starts scanning
             //    from the beginning of the year.
             let (candidate_for_year, reset_month_on_advance) =
-                if let (Cycle::NextNth(n), Cycle::Values(month_vals)) =
-                    (&self.spec.years, &self.spec.months)
-                {
-                    let diff = candidate.year() - self.context.start_dt.year();
+                if let (Cycle::NextNth(n), Cycle::Values(month_vals)) = (&spec.years, months) {
+                    let diff = candidate.year() - context.start_dt.year();
                     let year_is_valid = diff >= 0 && diff % (*n as i32) == 0;
                     if year_is_valid && !month_vals.is_empty() {
-                        if matches!(&self.spec.days, DayCycle::NextNth(..)) {
+                        if matches!(&spec.days, DayCycle::NextNth(..)) {
                             // For relative day specs: always stabilize the year by
                             // passing first-of-current-month (day==1 guard prevents
                             // year from advancing). Month and year transitions happen
@@ -565,10 +915,7 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                     (candidate, false)
                 };
 
-            let year_candidate = self
-                .spec
-                .years
-                .next_date(&candidate_for_year, &self.context);
+            let year_candidate = spec.years.next_date(&candidate_for_year, context);
             let Some(year_candidate) = year_candidate else {
                 return Ok(None);
             };
@@ -578,7 +925,7 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             let year_candidate = if reset_month_on_advance
                 && year_candidate.year() != candidate.year()
             {
-                if let Cycle::Values(month_vals) = &self.spec.months {
+                if let Cycle::Values(month_vals) = months {
                     month_vals
                         .iter()
                         .next()
@@ -600,16 +947,14 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             // current aligned month so we don't skip dates within that month.
             // For Values months after year advancement: year_candidate is already
             // at the first valid month — don't force-advance past it.
-            let day_is_relative =
-                matches!(&self.spec.days, DayCycle::NextNth(..) | DayCycle::ForEach);
-            let month_must_advance = (year_advanced
-                && !matches!(&self.spec.months, Cycle::Values(_)))
-                || day_is_relative;
+            let day_is_relative = matches!(&spec.days, DayCycle::NextNth(..) | DayCycle::ForEach);
+            let month_must_advance =
+                (year_advanced && !matches!(months, Cycle::Values(_))) || day_is_relative;
 
             let month_candidate = component::find_next_in_month_cycle(
-                &self.spec.months,
+                months,
                 &year_candidate,
-                &self.context,
+                context,
                 month_must_advance,
             );
             let Some(month_candidate) = month_candidate else {
@@ -630,23 +975,27 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             // Both cases require the day sequence to restart from the beginning of the
             // new month period.
             let period_reset =
-                month_advanced || (year_advanced && matches!(&self.spec.months, Cycle::Values(_)));
+                month_advanced || (year_advanced && matches!(months, Cycle::Values(_)));
             let day_cursor = if period_reset {
-                match &self.spec.days {
-                    DayCycle::OnDays { .. } if period_reset => {
+                match &spec.days {
+                    DayCycle::OnDays { .. }
+                    | DayCycle::OnBizDay(_)
+                    | DayCycle::OnWeekDays { .. }
+                        if period_reset =>
+                    {
                         (month_candidate.date().pred_opt().unwrap())
                             .and_hms_opt(0, 0, 0)
                             .unwrap()
                     }
                     DayCycle::NextNth(n, NextNthDayOption::Regular)
-                        if matches!(&self.spec.months, Cycle::Values(_)) =>
+                        if matches!(months, Cycle::Values(_)) =>
                     {
                         // For Values months + regular NextNth days: set cursor to
                         // (month_start - n) so that next_date lands exactly on
                         // month_start, restarting the sequence from day 1.
                         month_candidate - chrono::Duration::days(*n as i64)
                     }
-                    DayCycle::NextNth(..) if !matches!(&self.spec.months, Cycle::Values(_)) => {
+                    DayCycle::NextNth(..) if !matches!(months, Cycle::Values(_)) => {
                         // Preserve same day-of-month only for relative month cycles
                         // (NextNth, ForEach, AsIs) so combined specs like "1M-7D"
                         // accumulate month + days together.
@@ -673,10 +1022,8 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 // month and roll over (e.g. day={1} when month_candidate is the 1st
                 // of a 6-month aligned period).  Step back one day so the component
                 // can find the first valid day in the current period.
-                match &self.spec.days {
-                    DayCycle::NextNth(..) if matches!(&self.spec.months, Cycle::Values(_)) => {
-                        candidate
-                    }
+                match &spec.days {
+                    DayCycle::NextNth(..) if matches!(months, Cycle::Values(_)) => candidate,
                     DayCycle::OnDays { days, .. }
                         if !days.is_empty()
                             && days.iter().all(|&d| d <= month_candidate.day()) =>
@@ -687,11 +1034,23 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                             .map(|d| d.and_time(month_candidate.time()))
                             .unwrap_or(month_candidate)
                     }
+                    // Likewise for business-day-of-month and weekday specs
+                    // landing on the 1st of a realigned period: the 1st itself
+                    // may be the selected day.
+                    DayCycle::OnBizDay(_) | DayCycle::OnWeekDays { .. }
+                        if month_candidate.day() == 1 =>
+                    {
+                        month_candidate
+                            .date()
+                            .pred_opt()
+                            .map(|d| d.and_time(month_candidate.time()))
+                            .unwrap_or(month_candidate)
+                    }
                     _ => month_candidate,
                 }
             };
 
-            let day_candidate = self.spec.days.next_date(&day_cursor, &self.context);
+            let day_candidate = spec.days.next_date(&day_cursor, context);
             let Some(day_candidate) = day_candidate else {
                 // This month has no valid days; advance to the next month and retry.
                 let (y, m) = component::ffwd_months(&month_candidate, 1);
@@ -702,7 +1061,7 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 continue;
             };
 
-            if day_candidate > self.dtm {
+            if day_candidate > dtm {
                 // --- Year re-validation ---
                 // Only necessary when the year spec restricts to a finite set of
                 // years (Values) or a sparse cadence (NextNth with n>1 effectively
@@ -710,16 +1069,17 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 // For NextNth, check alignment explicitly instead of calling
                 // next_date (which would always return the *next* aligned year,
                 // triggering a false mismatch for the current valid year).
-                let year_is_valid = match &self.spec.years {
+                let year_is_valid = match &spec.years {
                     Cycle::AsIs | Cycle::ForEach => true,
                     Cycle::Values(values) => values.contains(&(day_candidate.year() as u32)),
                     Cycle::NextNth(n) => {
-                        let diff = day_candidate.year() - self.context.start_dt.year();
+                        let diff = day_candidate.year() - context.start_dt.year();
                         diff >= 0 && diff % (*n as i32) == 0
                     }
+                    Cycle::IsoWeeks(_) | Cycle::Quarters(_) => false,
                 };
                 if !year_is_valid {
-                    let year_recheck = self.spec.years.next_date(&day_candidate, &self.context);
+                    let year_recheck = spec.years.next_date(&day_candidate, context);
                     match year_recheck {
                         None => return Ok(None),
                         Some(ref y) => {
@@ -733,13 +1093,9 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 // --- Month re-validation ---
                 // For Values months: the day component may have overflowed into
                 // a month outside the allowed set.
-                if let Cycle::Values(_) = &self.spec.months {
-                    let month_recheck = component::find_next_in_month_cycle(
-                        &self.spec.months,
-                        &day_candidate,
-                        &self.context,
-                        true,
-                    );
+                if let Cycle::Values(_) = months {
+                    let month_recheck =
+                        component::find_next_in_month_cycle(months, &day_candidate, context, true);
                     match month_recheck {
                         None => return Ok(None),
                         Some(ref m)
@@ -756,9 +1112,9 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
 
                 // For NextNth months: the day component may have rolled into a
                 // month that is not in the N-month cadence.  Re-align if needed.
-                if let Cycle::NextNth(n) = &self.spec.months {
-                    let start_month = self.context.start_dt.month() as i32;
-                    let start_year = self.context.start_dt.year();
+                if let Cycle::NextNth(n) = months {
+                    let start_month = context.start_dt.month() as i32;
+                    let start_year = context.start_dt.year();
                     let total = (day_candidate.year() - start_year) * 12
                         + (day_candidate.month() as i32 - start_month);
                     if total.rem_euclid(*n as i32) != 0 {
@@ -773,8 +1129,7 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                     }
                 }
 
-                candidate = day_candidate;
-                break;
+                return Ok(Some(day_candidate));
             } else {
                 // No progress: if the pipeline returned the same value it
                 // started with, force a one-day advance to avoid an infinite
@@ -786,6 +1141,121 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 };
             }
         }
+    }
+
+    /// Apply the spec's business day adjustment to `next_result`, a date
+    /// that has already been resolved and had any overflow applied.
+    fn adjust_biz_day(
+        &self,
+        next_result: Occurrence<NaiveDateTime>,
+    ) -> Result<Occurrence<NaiveDateTime>> {
+        // Prev(n) / Next(n) are unconditional offsets; the directional variants
+        // (BizDay / Weekday) only apply when the actual date is not a biz day.
+        Ok(if let Some(biz_day_adj) = &self.spec.biz_day_adj {
+            let (actual, observed) = next_result.as_tuple();
+            match biz_day_adj {
+                BizDayAdjustment::Prev(num) => Occurrence::AdjustedEarlier(
+                    actual.clone(),
+                    self.context.bd_processor.sub(observed, *num)?,
+                ),
+                BizDayAdjustment::Next(num) => Occurrence::AdjustedLater(
+                    actual.clone(),
+                    self.context.bd_processor.add(observed, *num)?,
+                ),
+                _ => {
+                    if self.context.bd_processor.is_biz_day(&observed)? {
+                        next_result
+                    } else {
+                        match biz_day_adj {
+                            BizDayAdjustment::Weekday(dir) => {
+                                let adjusted =
+                                    WEEKEND_SKIPPER.find_biz_day(observed, dir.clone())?;
+                                adjusted_to_next_result(*actual, adjusted)
+                            }
+                            BizDayAdjustment::BizDay(dir) => {
+                                let adjusted = self
+                                    .context
+                                    .bd_processor
+                                    .find_biz_day(observed, dir.clone())?;
+                                adjusted_to_next_result(*actual, adjusted)
+                            }
+                            BizDayAdjustment::NA => next_result,
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+            }
+        } else {
+            next_result
+        })
+    }
+
+    /// The last date returned by `next`, re-adjusted as if it occurred at
+    /// `time` of day, so a [`BizDayProcessor`] with an intra-day cut-off can
+    /// move it to a later settlement date. `None` before the first date.
+    pub(crate) fn adjusted_at(&self, time: NaiveTime) -> Result<Option<Occurrence<NaiveDateTime>>> {
+        let Some(unadjusted) = &self.unadjusted else {
+            return Ok(None);
+        };
+        let at = |dtm: &NaiveDateTime| dtm.date().and_time(time);
+        let unadjusted = match unadjusted {
+            Occurrence::Exact(dtm) => Occurrence::Exact(at(dtm)),
+            Occurrence::AdjustedEarlier(actual, observed) => {
+                Occurrence::AdjustedEarlier(at(actual), at(observed))
+            }
+            Occurrence::AdjustedLater(actual, observed) => {
+                Occurrence::AdjustedLater(at(actual), at(observed))
+            }
+        };
+        self.adjust_biz_day(unadjusted).map(Some)
+    }
+
+    /// The business day processor's session on `date`.
+    pub(crate) fn session(&self, date: NaiveDate) -> Result<Session> {
+        self.context.bd_processor.session(date)
+    }
+}
+
+impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
+    type Item = Occurrence<NaiveDateTime>;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        if let Some(end) = &self.end {
+            if &self.dtm >= end {
+                return Ok(None);
+            }
+        }
+
+        if self.index == 0 {
+            if let Some(start) = &self.start {
+                if &self.dtm <= start {
+                    self.dtm = start.clone();
+                    self.index += 1;
+                    return Ok(Some(Occurrence::Exact(start.clone())));
+                }
+            }
+        }
+
+        // Resolve the next raw date, skipping any that an exclusion clause
+        // removes before business day adjustment is applied.
+        let mut cursor = self.dtm;
+        let mut iterations = 0u32;
+        let candidate = loop {
+            iterations += 1;
+            if iterations > 10_000 {
+                return Err(Error::IteratorNotConverged);
+            }
+            let candidate =
+                Self::next_candidate(&self.spec, cursor, &self.context, self.fiscal_year_start)?;
+            let Some(candidate) = candidate else {
+                return Ok(None);
+            };
+            if !self.is_excluded(&candidate)? {
+                break candidate;
+            }
+            cursor = candidate;
+        };
 
         // --- Apply NextMonthFirstDay / NextMonthOverflow wrapping ---
         // These options mean: if the target day doesn't exist in this month,
@@ -826,45 +1296,8 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
         };
 
         // --- Apply BizDay adjustment ---
-        // Prev(n) / Next(n) are unconditional offsets; the directional variants
-        // (BizDay / Weekday) only apply when the actual date is not a biz day.
-        let next_result = if let Some(biz_day_adj) = &self.spec.biz_day_adj {
-            let (actual, observed) = next_result.as_tuple();
-            match biz_day_adj {
-                BizDayAdjustment::Prev(num) => Occurrence::AdjustedEarlier(
-                    actual.clone(),
-                    self.context.bd_processor.sub(observed, *num)?,
-                ),
-                BizDayAdjustment::Next(num) => Occurrence::AdjustedLater(
-                    actual.clone(),
-                    self.context.bd_processor.add(observed, *num)?,
-                ),
-                _ => {
-                    if self.context.bd_processor.is_biz_day(&observed)? {
-                        next_result
-                    } else {
-                        match biz_day_adj {
-                            BizDayAdjustment::Weekday(dir) => {
-                                let adjusted =
-                                    WEEKEND_SKIPPER.find_biz_day(observed, dir.clone())?;
-                                adjusted_to_next_result(*actual, adjusted)
-                            }
-                            BizDayAdjustment::BizDay(dir) => {
-                                let adjusted = self
-                                    .context
-                                    .bd_processor
-                                    .find_biz_day(observed, dir.clone())?;
-                                adjusted_to_next_result(*actual, adjusted)
-                            }
-                            BizDayAdjustment::NA => next_result,
-                            _ => unreachable!(),
-                        }
-                    }
-                }
-            }
-        } else {
-            next_result
-        };
+        self.unadjusted = Some(next_result.clone());
+        let next_result = self.adjust_biz_day(next_result)?;
 
         if next_result.actual() <= &self.dtm {
             return Ok(None);
@@ -889,6 +1322,167 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
     }
 }
 
+/// The cadence a backward scan keeps its windows aligned to, so that a
+/// `NextNth` rule counts from the same epoch as a forward iterator started at
+/// the scan's cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Period {
+    Months(u32),
+    Days(u32),
+    BizDays(u32),
+    WeekDays(u32),
+}
+
+impl Period {
+    /// The period of `spec`: the common multiple of its year, quarter and
+    /// month cadences, else its week or day cadence, else a single day.
+    pub(crate) fn of(spec: &Spec) -> Self {
+        let mut months = None;
+        let mut days = None;
+        for spec in std::iter::once(spec).chain(&spec.exclusions) {
+            if let Cycle::NextNth(n) = spec.years {
+                months = Some(lcm(months.unwrap_or(1), n * 12));
+            }
+            match &spec.months {
+                Cycle::NextNth(n) => months = Some(lcm(months.unwrap_or(1), *n)),
+                Cycle::Quarters(quarters) => {
+                    if let Cycle::NextNth(n) = quarters.as_ref() {
+                        months = Some(lcm(months.unwrap_or(1), n * 3));
+                    }
+                }
+                Cycle::IsoWeeks(weeks) => {
+                    if let Cycle::NextNth(n) = weeks.as_ref() {
+                        days = Some(Period::Days(n * 7));
+                    }
+                }
+                _ => {}
+            }
+            if let DayCycle::NextNth(n, option) = &spec.days {
+                days = Some(match option {
+                    NextNthDayOption::Regular => Period::Days(*n),
+                    NextNthDayOption::BizDay => Period::BizDays(*n),
+                    NextNthDayOption::WeekDay => Period::WeekDays(*n),
+                });
+            }
+        }
+        months
+            .map(Period::Months)
+            .or(days)
+            .unwrap_or(Period::Days(1))
+    }
+
+    /// `dtm` moved back by `periods` periods, or `None` before the start of
+    /// the calendar.
+    pub(crate) fn before<BDP: BizDayProcessor>(
+        self,
+        bdp: &BDP,
+        dtm: NaiveDateTime,
+        periods: u32,
+    ) -> Result<Option<NaiveDateTime>> {
+        Ok(match self {
+            Period::Months(n) => n
+                .checked_mul(periods)
+                .and_then(|months| dtm.checked_sub_months(Months::new(months))),
+            Period::Days(n) => (n as i64)
+                .checked_mul(periods as i64)
+                .and_then(Duration::try_days)
+                .and_then(|days| dtm.checked_sub_signed(days)),
+            Period::BizDays(n) => match n.checked_mul(periods) {
+                Some(num) => bdp.sub(&dtm, num).ok(),
+                None => None,
+            },
+            Period::WeekDays(n) => match n.checked_mul(periods) {
+                Some(num) => WEEKEND_SKIPPER.sub(&dtm, num).ok(),
+                None => None,
+            },
+        })
+    }
+}
+
+/// The nominal step between occurrences of `spec`: that of its finest rule
+/// that repeats, i.e. a rolling day rule (`DD`, `nD`, `nBDA`, a weekday set),
+/// else its month, quarter or week rule, else its year rule, else one day.
+/// A listed value steps by its unit, so `[01,07]` steps by a month.
+///
+/// Months count as 28 days and years as 365, never more than the real gap.
+fn spec_cadence(spec: &Spec) -> Duration {
+    let step = |cycle: &Cycle, unit: i64| match cycle {
+        Cycle::ForEach | Cycle::Values(_) => Some(unit),
+        Cycle::NextNth(n) => Some(unit * *n as i64),
+        _ => None,
+    };
+    let days = match &spec.days {
+        DayCycle::ForEach => Some(1),
+        DayCycle::NextNth(n, _) | DayCycle::OnBizDay(BizDayOption::Every(n)) => Some(*n as i64),
+        DayCycle::OnWeekDays {
+            option: WeekdayOption::NA,
+            ..
+        } => Some(7),
+        _ => None,
+    };
+    let months = || match &spec.months {
+        Cycle::Quarters(quarters) => step(quarters, 3 * 28),
+        Cycle::IsoWeeks(weeks) => step(weeks, 7),
+        months => step(months, 28),
+    };
+    let days = days
+        .or_else(months)
+        .or_else(|| step(&spec.years, 365))
+        .unwrap_or(1);
+    Duration::days(days)
+}
+
+fn lcm(a: u32, b: u32) -> u32 {
+    let gcd = |mut a: u32, mut b: u32| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    a / gcd(a, b) * b
+}
+
+/// A date spec scanned backwards by [`NaiveReverse`].
+#[derive(Debug, Clone)]
+pub(crate) struct DateRewind<BDP: BizDayProcessor> {
+    spec: Spec,
+    bdp: BDP,
+    fiscal_year_start: u32,
+    period: Period,
+}
+
+impl<BDP: BizDayProcessor> DateRewind<BDP> {
+    fn new(spec: Spec, bdp: BDP, fiscal_year_start: u32) -> Result<Self> {
+        // Validate the fiscal year start up front rather than on the first scan.
+        NaiveSpecIterator::from_spec_after(spec.clone(), bdp.clone(), NaiveDateTime::MIN)
+            .with_fiscal_year_start(fiscal_year_start)?;
+        Ok(Self {
+            period: Period::of(&spec),
+            spec,
+            bdp,
+            fiscal_year_start,
+        })
+    }
+}
+
+impl<BDP: BizDayProcessor> Rewind for DateRewind<BDP> {
+    type Item = Occurrence<NaiveDateTime>;
+    type Iter = NaiveSpecIterator<BDP>;
+
+    fn window_start(&self, dtm: NaiveDateTime, periods: u32) -> Result<Option<NaiveDateTime>> {
+        self.period.before(&self.bdp, dtm, periods)
+    }
+
+    fn iter_from(&self, start: NaiveDateTime, _scanned: NaiveDateTime) -> Result<Self::Iter> {
+        NaiveSpecIterator::from_spec_after(self.spec.clone(), self.bdp.clone(), start)
+            .with_fiscal_year_start(self.fiscal_year_start)
+    }
+
+    fn key(item: &Self::Item) -> NaiveDateTime {
+        *item.actual()
+    }
+}
+
 fn adjusted_to_next_result(
     dtm: NaiveDateTime,
     adjusted: NaiveDateTime,
@@ -8,6 +8,8 @@
 //! | [`SpecIteratorBuilder`] | Fluent builder for [`SpecIterator`] and [`NaiveSpecIterator`] |
 //! | [`SpecIterator`] | Timezone-aware fallible iterator over [`crate::Occurrence<DateTime<Tz>>`] |
 //! | [`NaiveSpecIterator`] | Non-timezone-aware fallible iterator over [`crate::Occurrence<NaiveDateTime>`] |
+//! | [`SpecSet`] | Union of several specs, written `<spec>|<spec>|…` |
+//! | [`SpecSetIterator`] | Merged, de-duplicated iterator over a [`SpecSet`], tagging each item with its member |
 //!
 //! ## Spec Syntax
 //!
@@ -47,13 +49,18 @@
 
 mod component;
 mod iter;
+mod set;
 mod spec;
 
 #[cfg(test)]
 mod tests;
 
-pub use iter::{NaiveSpecIterator, SpecIterator, SpecIteratorBuilder};
+pub use iter::{NaiveSpecIterator, ReverseSpecIterator, SpecIterator, SpecIteratorBuilder};
+pub(crate) use iter::Period;
+pub(crate) use spec::validate_strict;
+pub use set::{SpecSet, SpecSetIterator};
 
 pub use spec::{
-    parse_spec, BizDayAdjustment, Cycle, DayCycle, LastDayOption, Spec, WeekdayOption,
+    parse_spec, parse_spec_strict, BizDayAdjustment, BizDayOption, Cycle, DayCycle, EasterCalendar,
+    LastDayOption, Spec, WeekdayOption,
 };
@@ -0,0 +1,144 @@
+use super::{iter::NaiveSpecIterator, spec::Spec};
+use crate::utils::next_result_to_tz;
+use crate::{biz_day::BizDayProcessor, prelude::*, DstPolicy, Occurrence};
+use chrono::{DateTime, NaiveDateTime, TimeZone};
+use fallible_iterator::FallibleIterator;
+use std::fmt;
+use std::str::FromStr;
+
+/// A union of several date specs, iterated as one schedule.
+///
+/// Written as member specs separated by `|`, e.g.
+/// `"YY-MM-15|YY-MM-L|YY-[01,07]-MON#1"` for "the 15th and the last day of
+/// every month, plus the first Monday of January and July". Whitespace around
+/// `|` is ignored.
+///
+/// Iterate it with [`SpecIteratorBuilder::build_set`](super::SpecIteratorBuilder::build_set),
+/// which applies the builder's start, end, DST and fiscal year options to
+/// every member.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::date::SpecSet;
+///
+/// let set: SpecSet = "YY-MM-15 | YY-MM-L".parse().unwrap();
+/// assert_eq!(set.specs.len(), 2);
+/// assert_eq!(set.to_string(), "YY-MM-15|YY-MM-L");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpecSet {
+    /// The member specs, in the order they were written. Items yielded by a
+    /// [`SpecSetIterator`] refer to members by their index in this list.
+    pub specs: Vec<Spec>,
+}
+
+impl FromStr for SpecSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let specs = s
+            .split('|')
+            .map(|member| member.trim().parse())
+            .collect::<Result<Vec<Spec>>>()?;
+        Ok(SpecSet { specs })
+    }
+}
+
+impl fmt::Display for SpecSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strs: Vec<String> = self.specs.iter().map(|spec| spec.to_string()).collect();
+        write!(f, "{}", strs.join("|"))
+    }
+}
+
+/// Timezone-aware iterator over the union of a [`SpecSet`]'s members.
+///
+/// Yields `(member, occurrence)` pairs in chronological order of the observed
+/// date, where `member` is the index into [`SpecSet::specs`] of the spec that
+/// produced the occurrence. When several members produce the same observed
+/// date it is yielded once, attributed to the member with the earliest actual
+/// date, then the lowest member index.
+///
+/// Construct via [`SpecIteratorBuilder::build_set`](super::SpecIteratorBuilder::build_set).
+///
+/// # Example
+///
+/// ```rust
+/// use tkone_schedule::biz_day::WeekendSkipper;
+/// use tkone_schedule::date::SpecIteratorBuilder;
+/// use chrono::{TimeZone, Utc};
+/// use fallible_iterator::FallibleIterator;
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let iter = SpecIteratorBuilder::new_after("YY-MM-15|YY-MM-L", WeekendSkipper::new(), start)
+///     .build_set()
+///     .unwrap();
+///
+/// let items: Vec<_> = iter.take(3).collect().unwrap();
+/// // → (0, 2025-01-15), (1, 2025-01-31), (0, 2025-02-15)
+/// assert_eq!(items[1].0, 1);
+/// ```
+#[derive(Debug)]
+pub struct SpecSetIterator<Tz: TimeZone, BDP: BizDayProcessor> {
+    tz: Tz,
+    dst_policy: DstPolicy,
+    members: Vec<NaiveSpecIterator<BDP>>,
+    /// The next unconsumed occurrence of each member; `None` once a member
+    /// needs to be advanced.
+    heads: Vec<Option<Occurrence<NaiveDateTime>>>,
+    exhausted: Vec<bool>,
+    last_observed: Option<NaiveDateTime>,
+}
+
+impl<Tz: TimeZone, BDP: BizDayProcessor> SpecSetIterator<Tz, BDP> {
+    pub(super) fn new(tz: Tz, dst_policy: DstPolicy, members: Vec<NaiveSpecIterator<BDP>>) -> Self {
+        let len = members.len();
+        Self {
+            tz,
+            dst_policy,
+            members,
+            heads: vec![None; len],
+            exhausted: vec![false; len],
+            last_observed: None,
+        }
+    }
+}
+
+impl<Tz: TimeZone, BDP: BizDayProcessor> FallibleIterator for SpecSetIterator<Tz, BDP> {
+    type Item = (usize, Occurrence<DateTime<Tz>>);
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        loop {
+            for (i, member) in self.members.iter_mut().enumerate() {
+                if self.heads[i].is_none() && !self.exhausted[i] {
+                    self.heads[i] = member.next()?;
+                    self.exhausted[i] = self.heads[i].is_none();
+                }
+            }
+
+            // Earliest observed date first; ties go to the earlier actual date,
+            // then to the lower member index.
+            let Some(member) = (0..self.heads.len())
+                .filter(|&i| self.heads[i].is_some())
+                .min_by_key(|&i| {
+                    let head = self.heads[i].as_ref().unwrap();
+                    (*head.observed(), *head.actual())
+                })
+            else {
+                return Ok(None);
+            };
+            let next = self.heads[member].take().unwrap();
+
+            if self.last_observed == Some(*next.observed()) {
+                continue;
+            }
+            self.last_observed = Some(*next.observed());
+            return Ok(Some((
+                member,
+                next_result_to_tz(&self.tz, next, self.dst_policy)?,
+            )));
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use crate::{biz_day::Direction, prelude::*, utils::WeekdayStartingMonday};
-use chrono::Weekday;
+use crate::{
+    biz_day::Direction,
+    date::component::last_day_of_month,
+    prelude::*,
+    utils::{DateLikeUtils, WeekdayStartingMonday},
+};
+use chrono::{Datelike, Weekday};
 
 use std::collections::BTreeSet;
 use std::fmt;
@@ -9,11 +14,11 @@ use nom::{
     Parser,
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1, multispace0},
-    combinator::{all_consuming, map_res, opt, recognize, value},
+    character::complete::{char, digit1, multispace0, multispace1},
+    combinator::{all_consuming, consumed, map, map_res, opt, recognize, value, verify},
     error::Error as NomError,
-    multi::separated_list1,
-    sequence::{delimited, pair, preceded},
+    multi::{many0, separated_list1},
+    sequence::{delimited, pair, preceded, terminated},
     IResult,
 };
 
@@ -25,6 +30,8 @@ use nom::{
 /// | `ForEach` | `YY` / `MM` | Every value in sequence |
 /// | `Values(set)` | `2025` / `[2024,2025]` | Restricted to an explicit set |
 /// | `NextNth(n)` | `1Y` / `3M` | Advance by *n* units, aligned to the iterator start |
+/// | `IsoWeeks(cycle)` | `WW` / `2W` / `W05` / `W[01,27]` | ISO week numbers (week form only) |
+/// | `Quarters(cycle)` | `QQ` / `2Q` / `Q1` / `[Q1,Q3]` | Fiscal quarters (month component only) |
 ///
 /// # Examples
 ///
@@ -52,6 +59,31 @@ pub enum Cycle {
     /// Advance by *n* years (`nY`) or months (`nM`), keeping alignment to the
     /// iterator's start date.
     NextNth(u32),
+    /// ISO 8601 week numbers, used in place of the month component by the
+    /// ISO week form `<years>-<weeks>-<weekdays>`.
+    ///
+    /// The inner cycle counts weeks: `ForEach` is every week (`WW`), `Values`
+    /// lists week numbers 1–53 (`W05` / `W[01,27]`) and `NextNth(n)` is every
+    /// *n*th week aligned to the iterator start (`2W`). In this form the year
+    /// component refers to the ISO week-numbering year.
+    IsoWeeks(Box<Cycle>),
+    /// Fiscal quarters, used in place of a month rule.
+    ///
+    /// The inner cycle counts quarters of the fiscal year configured on the
+    /// iterator builder (calendar quarters by default): `ForEach` is every
+    /// quarter (`QQ`), `Values` lists quarters 1–4 (`Q1` / `[Q1,Q3]`) and
+    /// `NextNth(n)` is every *n*th quarter from the start of the fiscal year
+    /// (`2Q`, `4Q`; *n* must divide 4).
+    ///
+    /// Day rules counted from the end of a period (`L`, `LBD`, `nLBD`, `WD#L`,
+    /// `WD#nL`, `WD<=L`) select dates in the last month of each quarter; other fixed
+    /// day rules select dates in its first month, and rolling rules cover all
+    /// three months.
+    ///
+    /// The year component still refers to calendar years: each selected month
+    /// is matched against it on its own, so a quarter that spans New Year
+    /// takes its months from two different fiscal years.
+    Quarters(Box<Cycle>),
 }
 
 /// How the day component of a date spec advances.
@@ -72,6 +104,16 @@ pub enum Cycle {
 /// | `OnWeekDays{wd, Starting(n)}` | `FRI#2` | *n*th occurrence of weekday from month start |
 /// | `OnWeekDays{wd, Ending(None)}` | `FRI#L` | Last occurrence of weekday in month |
 /// | `OnWeekDays{wd, Ending(n)}` | `FRI#2L` | *n*th-to-last occurrence of weekday |
+/// | `OnWeekDays{wds, Starting(n)}` | `[MON,WED]#2` | *n*th occurrence of each listed weekday |
+/// | `OnWeekDays{wds, Ending(None)}` | `[TUE,THU]#L` | Last occurrence of each listed weekday |
+/// | `OnWeekDays{wd, OnOrAfter(Some(n))}` | `MON>=15` | First weekday on or after day *n* |
+/// | `OnWeekDays{wd, OnOrBefore(None)}` | `FRI<=L` | Last weekday on or before the month end |
+/// | `OnBizDay(Starting(n))` | `5BDM` | *n*th business day of the month |
+/// | `OnBizDay(Ending(None))` | `LBD` | Last business day of the month |
+/// | `OnBizDay(Ending(n))` | `3LBD` | *n*th-to-last business day of the month |
+/// | `OnBizDay(Every(n))` | `5BDA` | Every *n*th business day, counted from the start of each month |
+/// | `OnYearDays(days)` | `D256` / `D[1,182]` | Specific day(s) of the year (day-of-year form only) |
+/// | `OnEaster(cal, offset)` | `EASTER+1` / `OEASTER-2` | Easter Sunday shifted by *offset* days (Easter form only) |
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
 pub enum DayCycle {
     /// Keep the current day-of-month unchanged (`_`).
@@ -94,9 +136,24 @@ pub enum DayCycle {
     OnWeekDays {
         /// The set of target weekdays.
         weekdays: BTreeSet<WeekdayStartingMonday>,
-        /// Which occurrence of the weekday to use.
+        /// Which occurrence of each weekday to use. When several weekdays are
+        /// listed, every selected date is yielded in chronological order.
         option: WeekdayOption,
     },
+    /// The *n*th business day counted from the start or end of each month, or
+    /// every *n*th business day of the month, according to the
+    /// [`crate::biz_day::BizDayProcessor`] supplied to the iterator.
+    OnBizDay(BizDayOption),
+    /// Specific day(s) of the year (1–366), used by the day-of-year form
+    /// `<years>-D<days>`. Day 366 only exists in leap years, which are the
+    /// only years it is produced in.
+    OnYearDays(BTreeSet<u32>),
+    /// Easter Sunday of each year in the given [`EasterCalendar`], shifted by
+    /// a signed number of calendar days, used by the Easter form
+    /// `<years>-EASTER[±n]` / `<years>-OEASTER[±n]`. The year component
+    /// selects the year of the Easter Sunday, even when the offset moves the
+    /// date into a neighbouring year.
+    OnEaster(EasterCalendar, i32),
 }
 
 /// Selects which occurrence of a weekday within the month to use.
@@ -113,6 +170,76 @@ pub enum WeekdayOption {
     /// - `Ending(None)` → last occurrence (`WD#L`).
     /// - `Ending(Some(n))` → *n*th-to-last (`WD#nL`).
     Ending(Option<u8>),
+    /// The first occurrence on or after a day of the month (`WD>=n`).
+    ///
+    /// `None` anchors on the last day of the month (`WD>=L`). An anchor past
+    /// the end of a shorter month is clamped to its last day, and months in
+    /// which no such weekday remains are skipped.
+    OnOrAfter(Option<u32>),
+    /// The last occurrence on or before a day of the month (`WD<=n`).
+    ///
+    /// `None` anchors on the last day of the month (`WD<=L`). An anchor past
+    /// the end of a shorter month is clamped to its last day, and months in
+    /// which no such weekday precedes the anchor are skipped.
+    OnOrBefore(Option<u32>),
+}
+
+/// Selects which business day of the month to use for [`DayCycle::OnBizDay`].
+///
+/// Months that have fewer business days than requested are skipped.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum BizDayOption {
+    /// The *n*th business day from the start of the month (`nBDM`).
+    Starting(u32),
+    /// The *n*th-to-last business day of the month.
+    ///
+    /// - `Ending(None)` → last business day (`LBD`).
+    /// - `Ending(Some(n))` → *n*th-to-last (`nLBD`).
+    Ending(Option<u32>),
+    /// Every *n*th business day counted from the start of the month
+    /// (`nBDA`): business days *n*, 2*n*, 3*n*, … The count restarts at the
+    /// start of every month or period selected by the month rule, unlike the
+    /// rolling `nBD` step; under `3M`, `QQ` or `Q1` it runs across all the
+    /// months of the period.
+    Every(u32),
+}
+
+/// The calendar used to compute Easter Sunday for [`DayCycle::OnEaster`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::date::EasterCalendar;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(
+///     EasterCalendar::Western.easter_sunday(2024),
+///     NaiveDate::from_ymd_opt(2024, 3, 31)
+/// );
+/// assert_eq!(
+///     EasterCalendar::Orthodox.easter_sunday(2024),
+///     NaiveDate::from_ymd_opt(2024, 5, 5)
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum EasterCalendar {
+    /// Western (Gregorian) Easter (`EASTER`).
+    Western,
+    /// Orthodox Easter, computed on the Julian calendar and given as a
+    /// Gregorian date (`OEASTER`).
+    Orthodox,
+}
+
+impl EasterCalendar {
+    /// The Gregorian date of Easter Sunday in `year`, or `None` if the year is
+    /// out of range for [`NaiveDate`](chrono::NaiveDate).
+    ///
+    /// Useful for [`crate::biz_day::BizDayProcessor`] implementations that
+    /// observe Easter-relative holidays such as Good Friday (`-2`) or Easter
+    /// Monday (`+1`).
+    pub fn easter_sunday(&self, year: i32) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(year, 1, 1)?.to_easter(*self)
+    }
 }
 
 /// What to do when the target day-of-month does not exist in the current month.
@@ -167,7 +294,7 @@ pub enum NextNthDayOption {
 /// | `~PB` | `BizDay(Prev)` | non-biz day | roll to previous business day |
 /// | `~3P` | `Prev(3)` | always | 3 business days earlier |
 /// | `~2N` | `Next(2)` | always | 2 business days later |
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Clone)]
 pub enum BizDayAdjustment {
     /// No adjustment (`~NA`). Default.
     #[default]
@@ -191,9 +318,18 @@ pub enum BizDayAdjustment {
 /// # Spec format
 ///
 /// ```text
-/// <years>-<months>-<days>[~<adj>]
+/// <years>-<months>-<days>[!<exclusion>...][~<adj>]
+/// <years>-<weeks>-<weekdays>[!<exclusion>...][~<adj>]     (ISO week form, e.g. YY-W[01,27]-MON)
+/// <years>-D<days-of-year>[!<exclusion>...][~<adj>]        (day-of-year form, e.g. YY-D256)
 /// ```
 ///
+/// Each exclusion clause is introduced by `!` or ` EXCEPT ` and holds a
+/// sub-spec in any of the three forms, without adjustment. Dates the sub-spec
+/// selects are skipped before the adjustment is applied, e.g.
+/// `YY-MM-1WD!YY-MM-01` (every weekday except the 1st) or
+/// `YY-1M-15 EXCEPT YY-12-DD` (the 15th, except in December). Relative day
+/// steps (`nD`, `nBD`, `nWD`) cannot be used in an exclusion.
+///
 /// # Examples
 ///
 /// ```rust
@@ -213,17 +349,26 @@ pub enum BizDayAdjustment {
 ///
 /// // Round-trip
 /// assert_eq!(spec.to_string(), "YY-3M-15");
+///
+/// // Exclusion clauses round-trip in their `!` form
+/// let spec: Spec = "YY-MM-1WD EXCEPT YY-MM-01".parse().unwrap();
+/// assert_eq!(spec.exclusions.len(), 1);
+/// assert_eq!(spec.to_string(), "YY-MM-1WD!YY-MM-01");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Spec {
     /// Year recurrence rule.
     pub years: Cycle,
-    /// Month recurrence rule.
+    /// Month recurrence rule, or the [`Cycle::IsoWeeks`] rule of an ISO week
+    /// spec. Always [`Cycle::AsIs`] in a day-of-year spec.
     pub months: Cycle,
-    /// Day-of-month / weekday recurrence rule.
+    /// Day-of-month / weekday / day-of-year recurrence rule.
     pub days: DayCycle,
     /// Optional business day adjustment applied after the raw date is resolved.
     pub biz_day_adj: Option<BizDayAdjustment>,
+    /// Sub-specs whose dates are skipped (`!` / `EXCEPT` clauses). Exclusions
+    /// never carry an adjustment or exclusions of their own.
+    pub exclusions: Vec<Spec>,
 }
 
 /// Helper struct to provide context for displaying a `Cycle`.
@@ -283,6 +428,22 @@ impl<'a> fmt::Display for CycleDisplayer<'a> {
                     write!(f, "{}M", n)
                 }
             }
+            Cycle::Quarters(quarters) => match quarters.as_ref() {
+                Cycle::NextNth(n) => write!(f, "{}Q", n),
+                Cycle::Values(vals) if vals.len() == 1 => {
+                    write!(f, "Q{}", vals.iter().next().unwrap())
+                }
+                Cycle::Values(vals) => {
+                    let strs: Vec<String> = vals.iter().map(|q| format!("Q{}", q)).collect();
+                    write!(f, "[{}]", strs.join(","))
+                }
+                _ => write!(f, "QQ"),
+            },
+            Cycle::IsoWeeks(weeks) => match weeks.as_ref() {
+                Cycle::NextNth(n) => write!(f, "{}W", n),
+                Cycle::Values(_) => write!(f, "W{}", weeks.display(false)),
+                _ => write!(f, "WW"),
+            },
         }
     }
 }
@@ -342,16 +503,44 @@ impl fmt::Display for DayCycle {
                 if weekdays.len() == 1 {
                     let w = weekdays.iter().next().unwrap();
                     write!(f, "{}", w)?;
-                    match option {
-                        WeekdayOption::Starting(Some(n)) => write!(f, "#{}", n),
-                        WeekdayOption::Ending(None) => write!(f, "#L"),
-                        WeekdayOption::Ending(Some(n)) => write!(f, "#{}L", n),
-                        _ => Ok(()),
-                    }
                 } else {
                     let strs: Vec<String> = weekdays.iter().map(|w| w.to_string()).collect();
-                    write!(f, "[{}]", strs.join(","))
+                    write!(f, "[{}]", strs.join(","))?;
+                }
+                match option {
+                    WeekdayOption::Starting(Some(n)) => write!(f, "#{}", n),
+                    WeekdayOption::Ending(None) => write!(f, "#L"),
+                    WeekdayOption::Ending(Some(n)) => write!(f, "#{}L", n),
+                    WeekdayOption::OnOrAfter(Some(n)) => write!(f, ">={}", n),
+                    WeekdayOption::OnOrAfter(None) => write!(f, ">=L"),
+                    WeekdayOption::OnOrBefore(Some(n)) => write!(f, "<={}", n),
+                    WeekdayOption::OnOrBefore(None) => write!(f, "<=L"),
+                    _ => Ok(()),
+                }
+            }
+            DayCycle::OnBizDay(option) => match option {
+                BizDayOption::Starting(n) => write!(f, "{}BDM", n),
+                BizDayOption::Ending(None) => write!(f, "LBD"),
+                BizDayOption::Ending(Some(n)) => write!(f, "{}LBD", n),
+                BizDayOption::Every(n) => write!(f, "{}BDA", n),
+            },
+            DayCycle::OnYearDays(days) => {
+                if days.len() == 1 {
+                    write!(f, "D{}", days.iter().next().unwrap())
+                } else {
+                    let strs: Vec<String> = days.iter().map(|d| d.to_string()).collect();
+                    write!(f, "D[{}]", strs.join(","))
+                }
+            }
+            DayCycle::OnEaster(calendar, offset) => {
+                match calendar {
+                    EasterCalendar::Western => write!(f, "EASTER")?,
+                    EasterCalendar::Orthodox => write!(f, "OEASTER")?,
                 }
+                if *offset != 0 {
+                    write!(f, "{:+}", offset)?;
+                }
+                Ok(())
             }
         }
     }
@@ -377,13 +566,22 @@ impl fmt::Display for BizDayAdjustment {
 // --- 5. Spec Display (The Root) ---
 impl fmt::Display for Spec {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}-{}-{}",
-            self.years.display(true),
-            self.months.display(false),
-            self.days
-        )?;
+        if let DayCycle::OnYearDays(_) | DayCycle::OnEaster(..) = &self.days {
+            // The day-of-year and Easter forms have no month component.
+            write!(f, "{}-{}", self.years.display(true), self.days)?;
+        } else {
+            write!(
+                f,
+                "{}-{}-{}",
+                self.years.display(true),
+                self.months.display(false),
+                self.days
+            )?;
+        }
+
+        for exclusion in &self.exclusions {
+            write!(f, "!{}", exclusion)?;
+        }
 
         // Handle Adjustment
         if let Some(adj) = &self.biz_day_adj {
@@ -425,22 +623,311 @@ impl FromStr for Spec {
 pub fn parse_spec(input: &str) -> Result<Spec> {
     let full_parser = (
         parse_year_cycle,
-        preceded(char('-'), parse_month_cycle),
-        preceded(char('-'), parse_day_cycle),
+        preceded(char('-'), parse_spec_form),
+        many0(preceded(parse_exclusion_sep, parse_exclusion)),
         opt(preceded(multispace0, parse_adjustment)),
     );
 
     match all_consuming(full_parser).parse(input) {
-        Ok((_, (years, months, days, biz_day_adj))) => Ok(Spec {
+        Ok((_, (years, (months, days), exclusions, biz_day_adj))) => Ok(Spec {
             years,
             months,
             days,
             biz_day_adj,
+            exclusions,
         }),
         Err(_) => Err(Error::InvalidDateSpec(format!("failed to parse: {input}"))),
     }
 }
 
+/// Parse a date spec string into a [`Spec`], also rejecting values that are
+/// well-formed but can never match.
+///
+/// On top of the grammar accepted by [`parse_spec`], strict parsing rejects:
+///
+/// - years outside 1–9999, quarters outside 1–4, ISO weeks outside 1–53 and
+///   months outside 1–12;
+/// - days of the month outside 1–31, or past the end of every selected month
+///   (e.g. `YY-02-30`) when no overflow suffix is given;
+/// - days of the year outside 1–366, and day 366 when none of the selected
+///   years is a leap year (e.g. `2025-D366`);
+/// - weekday occurrences (`WD#n`, `WD#nL`) outside 1–5 and weekday anchors
+///   (`WD>=n`, `WD<=n`) outside 1–31;
+/// - Easter offsets beyond 366 days;
+/// - business day positions (`nBDM`, `nLBD`, `nBDA`) outside 1–23, as no
+///   month has more weekdays; `nBDA` counted across a multi-month period
+///   allows 23 per month of it;
+/// - zero steps (`0Y`, `0M`, `0D`, …) and zero adjustment counts (`~0N`,
+///   `~0P`);
+/// - values listed more than once (`[2024,2024]`, `[MON,MON]`).
+///
+/// Exclusion sub-specs are checked the same way.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSpecComponent`] naming the component, the byte span
+/// of the offending value within `input` and the expected range. Syntax errors
+/// are reported as by [`parse_spec`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::date::{parse_spec, parse_spec_strict};
+/// use tkone_schedule::{Error, SpecComponent};
+///
+/// assert!(parse_spec("YY-13-01").is_ok());
+/// match parse_spec_strict("YY-13-01") {
+///     Err(Error::InvalidSpecComponent { component, span, .. }) => {
+///         assert_eq!(component, SpecComponent::Month);
+///         assert_eq!(span, 3..5);
+///     }
+///     other => panic!("unexpected {other:?}"),
+/// }
+/// ```
+pub fn parse_spec_strict(input: &str) -> Result<Spec> {
+    let spec = parse_spec(input)?;
+    validate_components(input, 0)?;
+    Ok(spec)
+}
+
+/// Check `input` as [`parse_spec_strict`] does, for a date spec found at byte
+/// `base` of a longer string, reporting spans relative to that string.
+pub(crate) fn validate_strict(input: &str, base: usize) -> Result<()> {
+    parse_spec(input)?;
+    validate_components(input, base)
+}
+
+// --- Strict Validation ---
+
+/// Largest offset, in days, strict parsing accepts after an Easter token.
+const MAX_EASTER_OFFSET: u32 = 366;
+
+/// Most business days in a month: no month has more than 23 weekdays.
+const MAX_BIZ_DAYS_IN_MONTH: u32 = 23;
+
+/// Check the components of `input`, a spec that is known to parse, reporting
+/// spans offset by `base`.
+fn validate_components(input: &str, base: usize) -> Result<()> {
+    let mut parser = (
+        consumed(parse_year_cycle),
+        preceded(char('-'), consumed(parse_spec_form)),
+        many0(preceded(parse_exclusion_sep, recognize(parse_exclusion))),
+        opt(preceded(multispace0, consumed(parse_adjustment))),
+    );
+    let Ok((_, ((years_text, years), (form_text, (months, days)), exclusions, adjustment))) =
+        parser.parse(input)
+    else {
+        return Err(Error::InvalidDateSpec(format!("failed to parse: {input}")));
+    };
+    let at = |text: &str| base + (text.as_ptr() as usize - input.as_ptr() as usize);
+
+    check_cycle(SpecComponent::Year, &years, years_text, at(years_text))?;
+    let days_text = match &days {
+        // These forms have no month component.
+        DayCycle::OnYearDays(_) | DayCycle::OnEaster(..) => form_text,
+        _ => {
+            // Month tokens never contain '-'.
+            let (months_text, days_text) = form_text.split_once('-').unwrap_or((form_text, ""));
+            let component = match &months {
+                Cycle::Quarters(_) => SpecComponent::Quarter,
+                Cycle::IsoWeeks(_) => SpecComponent::IsoWeek,
+                _ => SpecComponent::Month,
+            };
+            check_cycle(component, &months, months_text, at(months_text))?;
+            days_text
+        }
+    };
+    check_days(&years, &months, &days, days_text, at(days_text))?;
+
+    for exclusion in exclusions {
+        validate_components(exclusion, at(exclusion))?;
+    }
+    if let Some((text, BizDayAdjustment::Prev(_) | BizDayAdjustment::Next(_))) = adjustment {
+        let expected = "a business day count of at least 1";
+        check_numbers(
+            SpecComponent::Adjustment,
+            text,
+            at(text),
+            1..=u32::MAX,
+            expected,
+        )?;
+    }
+    Ok(())
+}
+
+fn check_cycle(component: SpecComponent, cycle: &Cycle, text: &str, at: usize) -> Result<()> {
+    match (component, cycle) {
+        (_, Cycle::NextNth(_)) => {
+            check_numbers(component, text, at, 1..=u32::MAX, "a step of at least 1")
+        }
+        (SpecComponent::Year, Cycle::Values(_)) => {
+            check_numbers(component, text, at, 1..=9999, "a year, 1-9999")
+        }
+        (SpecComponent::Month, Cycle::Values(_)) => {
+            check_numbers(component, text, at, 1..=12, "a month, 1-12")
+        }
+        (_, Cycle::Quarters(quarters)) if matches!(**quarters, Cycle::Values(_)) => {
+            check_numbers(component, text, at, 1..=4, "a quarter, 1-4")
+        }
+        (_, Cycle::IsoWeeks(weeks)) if matches!(**weeks, Cycle::Values(_)) => {
+            check_numbers(component, text, at, 1..=53, "an ISO week, 1-53")
+        }
+        _ => check_distinct(component, text, at),
+    }
+}
+
+fn check_days(years: &Cycle, months: &Cycle, days: &DayCycle, text: &str, at: usize) -> Result<()> {
+    let component = SpecComponent::Day;
+    match days {
+        DayCycle::OnDays { option, .. } => {
+            check_numbers(component, text, at, 1..=31, "a day of the month, 1-31")?;
+            // Without an overflow suffix a day past the end of every selected
+            // month is never produced.
+            if let (LastDayOption::NA, Cycle::Values(months)) = (option, months) {
+                let longest = months
+                    .iter()
+                    // 2000 is a leap year, so February counts 29 days.
+                    .map(|&m| last_day_of_month(2000, m).day())
+                    .max()
+                    .unwrap_or(31);
+                check_numbers(
+                    component,
+                    text,
+                    at,
+                    1..=longest,
+                    &format!("a day that exists in the selected months, 1-{longest}"),
+                )?;
+            }
+            Ok(())
+        }
+        DayCycle::OnWeekDays { option, .. } => match option {
+            WeekdayOption::Starting(_) | WeekdayOption::Ending(_) => {
+                check_numbers(component, text, at, 1..=5, "a weekday occurrence, 1-5")
+            }
+            WeekdayOption::OnOrAfter(_) | WeekdayOption::OnOrBefore(_) => check_numbers(
+                component,
+                text,
+                at,
+                1..=31,
+                "an anchor day of the month, 1-31",
+            ),
+            WeekdayOption::NA => check_distinct(component, text, at),
+        },
+        DayCycle::NextNth(..) => {
+            check_numbers(component, text, at, 1..=u32::MAX, "a step of at least 1")
+        }
+        DayCycle::OnBizDay(option) => {
+            // `nBDA` counts across every month of a multi-month period.
+            let months_in_period = match (option, months) {
+                (BizDayOption::Every(_), Cycle::Quarters(_)) => 3,
+                (BizDayOption::Every(_), Cycle::NextNth(n)) => *n,
+                _ => 1,
+            };
+            let longest = MAX_BIZ_DAYS_IN_MONTH.saturating_mul(months_in_period);
+            check_numbers(
+                component,
+                text,
+                at,
+                1..=longest,
+                &format!("a business day position, 1-{longest}"),
+            )
+        }
+        DayCycle::OnYearDays(_) => {
+            // Day 366 is never produced unless a selected year is a leap year.
+            let longest = match years {
+                Cycle::Values(years)
+                    if !years
+                        .iter()
+                        .any(|&y| last_day_of_month(y as i32, 2).day() == 29) =>
+                {
+                    365
+                }
+                _ => 366,
+            };
+            check_numbers(
+                component,
+                text,
+                at,
+                1..=longest,
+                &format!("a day that exists in the selected years, 1-{longest}"),
+            )
+        }
+        DayCycle::OnEaster(..) => check_numbers(
+            component,
+            text,
+            at,
+            0..=MAX_EASTER_OFFSET,
+            &format!("an Easter offset, 0-{MAX_EASTER_OFFSET}"),
+        ),
+        DayCycle::AsIs | DayCycle::ForEach => Ok(()),
+    }
+}
+
+/// Check that every number in `text` lies in `range` and that no bracketed
+/// list repeats a value.
+fn check_numbers(
+    component: SpecComponent,
+    text: &str,
+    at: usize,
+    range: std::ops::RangeInclusive<u32>,
+    expected: &str,
+) -> Result<()> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let value = text[start..i].parse().unwrap_or(u32::MAX);
+        if !range.contains(&value) {
+            return Err(component_error(component, text, at, start..i, expected));
+        }
+    }
+    check_distinct(component, text, at)
+}
+
+/// Check that the bracketed list in `text`, if any, lists each value once.
+/// Numbers compare by value, so `01` and `1` are the same.
+fn check_distinct(component: SpecComponent, text: &str, at: usize) -> Result<()> {
+    let (Some(open), Some(close)) = (text.find('['), text.find(']')) else {
+        return Ok(());
+    };
+    let mut seen = BTreeSet::new();
+    let mut start = open + 1;
+    for item in text[open + 1..close].split(',') {
+        let key = item
+            .parse::<u32>()
+            .map_or_else(|_| item.to_string(), |n| n.to_string());
+        if !seen.insert(key) {
+            let span = start..start + item.len();
+            let expected = "each value at most once";
+            return Err(component_error(component, text, at, span, expected));
+        }
+        start += item.len() + 1;
+    }
+    Ok(())
+}
+
+fn component_error(
+    component: SpecComponent,
+    text: &str,
+    at: usize,
+    span: std::ops::Range<usize>,
+    expected: &str,
+) -> Error {
+    Error::InvalidSpecComponent {
+        component,
+        value: text[span.clone()].to_string(),
+        span: at + span.start..at + span.end,
+        expected: expected.to_string(),
+    }
+}
+
 // --- Helpers ---
 
 fn parse_u32(input: &'_ str) -> Res<'_, u32> {
@@ -482,6 +969,7 @@ fn parse_year_cycle(input: &'_ str) -> Res<'_, Cycle> {
 
 fn parse_month_cycle(input: &'_ str) -> Res<'_, Cycle> {
     alt((
+        parse_quarter_cycle, // QQ / 2Q / Q1 / [Q1,Q3] before plain numbers
         parse_cycle_vals,
         parse_cycle_foreach,
         parse_cycle_next_nth,
@@ -490,6 +978,142 @@ fn parse_month_cycle(input: &'_ str) -> Res<'_, Cycle> {
     .parse(input)
 }
 
+fn parse_quarter_cycle(input: &'_ str) -> Res<'_, Cycle> {
+    let quarter = || preceded(char('Q'), parse_u32);
+    let (input, quarters) = alt((
+        value(Cycle::ForEach, tag("QQ")),
+        // Only cadences that divide the fiscal year evenly stay aligned to it.
+        map(
+            terminated(verify(parse_u32, |n| [1, 2, 4].contains(n)), char('Q')),
+            Cycle::NextNth,
+        ),
+        map(
+            delimited(char('['), separated_list1(char(','), quarter()), char(']')),
+            |qs| Cycle::Values(qs.into_iter().collect()),
+        ),
+        map(quarter(), |q| Cycle::Values(BTreeSet::from([q]))),
+    ))
+    .parse(input)?;
+    Ok((input, Cycle::Quarters(Box::new(quarters))))
+}
+
+fn parse_iso_week_cycle(input: &'_ str) -> Res<'_, Cycle> {
+    let (input, weeks) = alt((
+        value(Cycle::ForEach, tag("WW")),
+        map(
+            terminated(verify(parse_u32, |&n| n > 0), char('W')),
+            Cycle::NextNth,
+        ),
+        preceded(
+            char('W'),
+            alt((
+                map(
+                    delimited(char('['), separated_list1(char(','), parse_u32), char(']')),
+                    |ws| Cycle::Values(ws.into_iter().collect()),
+                ),
+                map(parse_u32, |w| Cycle::Values(BTreeSet::from([w]))),
+            )),
+        ),
+    ))
+    .parse(input)?;
+    Ok((input, Cycle::IsoWeeks(Box::new(weeks))))
+}
+
+// --- Spec Form Parsers ---
+
+fn parse_spec_form(input: &'_ str) -> Res<'_, (Cycle, DayCycle)> {
+    alt((
+        parse_easter_form,
+        parse_year_day_form,
+        parse_iso_week_form,
+        parse_month_day_form,
+    ))
+    .parse(input)
+}
+
+fn parse_month_day_form(input: &'_ str) -> Res<'_, (Cycle, DayCycle)> {
+    pair(parse_month_cycle, preceded(char('-'), parse_day_cycle)).parse(input)
+}
+
+fn parse_iso_week_form(input: &'_ str) -> Res<'_, (Cycle, DayCycle)> {
+    // Only plain weekdays (or every day, `DD`) are meaningful within a week.
+    pair(
+        parse_iso_week_cycle,
+        preceded(
+            char('-'),
+            alt((
+                parse_day_weekday_list,
+                parse_day_weekday_solo,
+                value(DayCycle::ForEach, tag("DD")),
+            )),
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_year_day_form(input: &'_ str) -> Res<'_, (Cycle, DayCycle)> {
+    let (input, days) = preceded(
+        char('D'),
+        alt((
+            delimited(char('['), separated_list1(char(','), parse_u32), char(']')),
+            map(parse_u32, |d| vec![d]),
+        )),
+    )
+    .parse(input)?;
+    Ok((
+        input,
+        (
+            Cycle::AsIs,
+            DayCycle::OnYearDays(days.into_iter().collect()),
+        ),
+    ))
+}
+
+fn parse_easter_form(input: &'_ str) -> Res<'_, (Cycle, DayCycle)> {
+    let (input, calendar) = alt((
+        value(EasterCalendar::Orthodox, tag("OEASTER")),
+        value(EasterCalendar::Western, tag("EASTER")),
+    ))
+    .parse(input)?;
+    let (input, offset) = opt(pair(
+        alt((value(1i32, char('+')), value(-1i32, char('-')))),
+        map_res(digit1, str::parse::<i32>),
+    ))
+    .parse(input)?;
+    let offset = offset.map_or(0, |(sign, n)| sign * n);
+    Ok((input, (Cycle::AsIs, DayCycle::OnEaster(calendar, offset))))
+}
+
+// --- Exclusion Parsers ---
+
+fn parse_exclusion_sep(input: &'_ str) -> Res<'_, &'_ str> {
+    alt((tag("!"), delimited(multispace1, tag("EXCEPT"), multispace1))).parse(input)
+}
+
+fn parse_exclusion(input: &'_ str) -> Res<'_, Spec> {
+    // A relative step has no fixed dates to match a candidate against.
+    let (input, (years, (months, days))) = pair(
+        parse_year_cycle,
+        preceded(
+            char('-'),
+            verify(parse_spec_form, |(_, days)| {
+                !matches!(days, DayCycle::NextNth(..))
+            }),
+        ),
+    )
+    .parse(input)?;
+    Ok((
+        input,
+        Spec {
+            years,
+            months,
+            days,
+            biz_day_adj: None,
+            exclusions: Vec::new(),
+        },
+    ))
+}
+
 // --- Day Cycle Parsers ---
 
 fn parse_weekday_enum(input: &'_ str) -> Res<'_, WeekdayStartingMonday> {
@@ -517,17 +1141,22 @@ fn parse_weekday_enum(input: &'_ str) -> Res<'_, WeekdayStartingMonday> {
     Ok((input, WeekdayStartingMonday(w)))
 }
 
-fn parse_day_weekday_list(input: &'_ str) -> Res<'_, DayCycle> {
+fn parse_weekday_set(input: &'_ str) -> Res<'_, BTreeSet<WeekdayStartingMonday>> {
     let (input, wds) = delimited(
         char('['),
         separated_list1(char(','), parse_weekday_enum),
         char(']'),
     )
     .parse(input)?;
+    Ok((input, wds.into_iter().collect()))
+}
+
+fn parse_day_weekday_list(input: &'_ str) -> Res<'_, DayCycle> {
+    let (input, weekdays) = parse_weekday_set(input)?;
     Ok((
         input,
         DayCycle::OnWeekDays {
-            weekdays: wds.into_iter().collect(),
+            weekdays,
             option: WeekdayOption::NA,
         },
     ))
@@ -557,7 +1186,13 @@ fn parse_day_weekday_solo(input: &'_ str) -> Res<'_, DayCycle> {
 }
 
 fn parse_day_weekday_complex(input: &'_ str) -> Res<'_, DayCycle> {
-    let (input, wd) = parse_weekday_enum(input)?;
+    // Either a single weekday (`WED#2`) or a bracketed list (`[MON,WED]#2`);
+    // the selector applies to every weekday in the set.
+    let (input, weekdays) = alt((
+        parse_weekday_set,
+        parse_weekday_enum.map(|wd| BTreeSet::from([wd])),
+    ))
+    .parse(input)?;
     let (input, _) = char('#').parse(input)?;
 
     let (input, opt_str) = recognize(pair(opt(digit1), opt(char('L')))).parse(input)?;
@@ -574,13 +1209,25 @@ fn parse_day_weekday_complex(input: &'_ str) -> Res<'_, DayCycle> {
         }
     };
 
-    Ok((
-        input,
-        DayCycle::OnWeekDays {
-            weekdays: BTreeSet::from([wd]),
-            option,
-        },
+    Ok((input, DayCycle::OnWeekDays { weekdays, option }))
+}
+
+fn parse_day_weekday_anchored(input: &'_ str) -> Res<'_, DayCycle> {
+    // A single weekday (`MON>=15`) or a bracketed list (`[MON,THU]<=L`); the
+    // anchor applies to every weekday in the set.
+    let (input, weekdays) = alt((
+        parse_weekday_set,
+        parse_weekday_enum.map(|wd| BTreeSet::from([wd])),
+    ))
+    .parse(input)?;
+    let anchor = || alt((value(None, char('L')), map(parse_u32, Some)));
+    let (input, option) = alt((
+        map(preceded(tag(">="), anchor()), WeekdayOption::OnOrAfter),
+        map(preceded(tag("<="), anchor()), WeekdayOption::OnOrBefore),
     ))
+    .parse(input)?;
+
+    Ok((input, DayCycle::OnWeekDays { weekdays, option }))
 }
 
 fn parse_day_next_nth(input: &'_ str) -> Res<'_, DayCycle> {
@@ -596,6 +1243,19 @@ fn parse_day_next_nth(input: &'_ str) -> Res<'_, DayCycle> {
     Ok((input, DayCycle::NextNth(num, opt)))
 }
 
+fn parse_day_biz_day_of_month(input: &'_ str) -> Res<'_, DayCycle> {
+    let (input, option) = alt((
+        value(BizDayOption::Ending(None), tag("LBD")),
+        map(terminated(parse_u32, tag("LBD")), |n| {
+            BizDayOption::Ending(Some(n))
+        }),
+        map(terminated(parse_u32, tag("BDM")), BizDayOption::Starting),
+        map(terminated(parse_u32, tag("BDA")), BizDayOption::Every),
+    ))
+    .parse(input)?;
+    Ok((input, DayCycle::OnBizDay(option)))
+}
+
 fn parse_day_single_complex(input: &'_ str) -> Res<'_, DayCycle> {
     let (input, num) = parse_u32(input)?;
     let (input, suffix) = opt(alt((tag("L"), tag("N"), tag("O")))).parse(input)?;
@@ -633,10 +1293,12 @@ fn parse_day_literals(input: &'_ str) -> Res<'_, DayCycle> {
 
 fn parse_day_cycle(input: &'_ str) -> Res<'_, DayCycle> {
     alt((
+        parse_day_weekday_complex, // WED#1 / [MON,WED]#1 before lists and solo, so '#' is consumed first
+        parse_day_weekday_anchored, // MON>=15 / FRI<=L, likewise before lists and solo
         parse_day_weekday_list,
         parse_day_int_list,
-        parse_day_weekday_complex, // WED#1 before solo, so '#' is consumed first
-        parse_day_weekday_solo,    // standalone MON/TUE/... without occurrence suffix
+        parse_day_weekday_solo, // standalone MON/TUE/... without occurrence suffix
+        parse_day_biz_day_of_month, // 5BDM / 5BDA / LBD / 3LBD before nBD, L and nL
         parse_day_next_nth,
         parse_day_literals,
         parse_day_single_complex,
@@ -715,6 +1377,118 @@ mod tests {
             assert_eq!(res.biz_day_adj, adj);
         }
     }
+
+    #[test]
+    fn test_invalid_iso_week_forms_are_parse_errors() {
+        for raw in ["YY-0W-MON", "YY-W05-MON#2"] {
+            assert!(parse_spec(raw).is_err(), "{raw} should be a parse error");
+        }
+    }
+
+    #[test]
+    fn test_invalid_exclusions_are_parse_errors() {
+        for raw in [
+            "YY-MM-DD!",
+            "YY-MM-DD!YY-MM-2D",    // relative step cannot be matched
+            "YY-MM-DD~NB!YY-MM-01", // adjustment comes after every exclusion
+            "YY-MM-DD EXCEPTYY-MM-01",
+        ] {
+            assert!(parse_spec(raw).is_err(), "{raw} should be a parse error");
+        }
+    }
+
+    #[test]
+    fn test_strict_validation_reports_component_and_span() {
+        use crate::SpecComponent::*;
+
+        let cases = [
+            ("YY-13-01", Month, 3..5),
+            ("YY-[01,13]-01", Month, 7..9),
+            ("YY-MM-[0,45]", Day, 7..8),
+            ("YY-MM-MON#6", Day, 10..11),
+            ("[2024,2024]-MM-01", Year, 6..10),
+            ("YY-02-30", Day, 6..8),
+            ("YY-MM-[MON,MON]", Day, 11..14),
+            ("0Y-MM-01", Year, 0..1),
+            ("YY-MM-0BD", Day, 6..7),
+            ("YY-W[01,1]-MON", IsoWeek, 8..9),
+            ("YY-[Q1,Q1]-L", Quarter, 7..9),
+            ("YY-MM-01 EXCEPT YY-[02,04]-31", Day, 27..29),
+            ("2025-D366", Day, 6..9),
+            ("[2025,2026]-D[1,366]", Day, 16..19),
+            ("YY-Q5-01", Quarter, 4..5),
+            ("YY-Q0-L", Quarter, 4..5),
+            ("YY-[Q1,Q5]-L", Quarter, 8..9),
+            ("YY-W54-MON", IsoWeek, 4..6),
+            ("YY-W00-MON", IsoWeek, 4..6),
+            ("YY-D0", Day, 4..5),
+            ("YY-D367", Day, 4..7),
+            ("YY-MM-MON>=32", Day, 11..13),
+            ("YY-MM-MON>=0", Day, 11..12),
+            ("YY-EASTER+400", Day, 10..13),
+            ("YY-OEASTER-367", Day, 11..14),
+            ("YY-MM-0BDM", Day, 6..7),
+            ("YY-MM-0LBD", Day, 6..7),
+            ("YY-MM-0BDA", Day, 6..7),
+            ("YY-MM-25LBD", Day, 6..8),
+            ("YY-MM-40BDM", Day, 6..8),
+            ("YY-QQ-70BDA", Day, 6..8),
+            ("YY-MM-01~0N", Adjustment, 9..10),
+        ];
+        for (raw, expected_component, expected_span) in cases {
+            // Lenient parsing accepts every one of these.
+            assert!(parse_spec(raw).is_ok(), "{raw} should parse");
+            match parse_spec_strict(raw) {
+                Err(Error::InvalidSpecComponent {
+                    component, span, ..
+                }) => {
+                    assert_eq!(component, expected_component, "{raw}");
+                    assert_eq!(span, expected_span, "{raw}");
+                }
+                other => panic!("{raw}: expected a component error, got {other:?}"),
+            }
+        }
+
+        let err = parse_spec_strict("YY-MM-MON#6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid day `6` at 10..11: expected a weekday occurrence, 1-5"
+        );
+        assert!(parse_spec_strict("YY-02-30L").is_ok());
+        assert!(parse_spec_strict("YY-[02,04]-30").is_ok());
+        assert!(parse_spec_strict("YY-D366").is_ok());
+        assert!(parse_spec_strict("[2024,2025]-D366").is_ok());
+        assert!(parse_spec_strict("YY-MM-23LBD").is_ok());
+        assert!(parse_spec_strict("YY-QQ-60BDA").is_ok());
+        assert!(parse_spec_strict("YY-EASTER-366").is_ok());
+        assert!(parse_spec_strict("YY-MM-01~2N").is_ok());
+        assert!(matches!(
+            parse_spec_strict("YY-MM-XX"),
+            Err(Error::InvalidDateSpec(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_weekday_anchors_are_parse_errors() {
+        for raw in ["YY-MM-FRI<=", "YY-MM-FRI=<L", "YY-MM-FRI>L"] {
+            assert!(parse_spec(raw).is_err(), "{raw} should be a parse error");
+        }
+    }
+
+    #[test]
+    fn test_invalid_easter_offsets_are_parse_errors() {
+        for raw in ["YY-EASTER+", "YY-EASTER1", "YY-MM-EASTER"] {
+            assert!(parse_spec(raw).is_err(), "{raw} should be a parse error");
+        }
+    }
+
+    #[test]
+    fn test_invalid_quarters_are_parse_errors() {
+        // 3Q / 5Q do not divide the fiscal year, so they cannot stay aligned to it.
+        for raw in ["YY-0Q-L", "YY-3Q-L", "YY-5Q-L"] {
+            assert!(parse_spec(raw).is_err(), "{raw} should be a parse error");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -754,6 +1528,7 @@ mod table_tests {
                         option: LastDayOption::LastDay,
                     },
                     biz_day_adj: Some(BizDayAdjustment::Weekday(Direction::Nearest)),
+                    exclusions: vec![],
                 },
             },
             // Case 2: "YY-1M-1WD"
@@ -766,6 +1541,7 @@ mod table_tests {
                     months: Cycle::NextNth(1),
                     days: DayCycle::NextNth(1, NextNthDayOption::WeekDay),
                     biz_day_adj: None,
+                    exclusions: vec![],
                 },
             },
             // Case 3: "2024-MM-31L~3P"
@@ -781,6 +1557,7 @@ mod table_tests {
                         option: LastDayOption::LastDay,
                     },
                     biz_day_adj: Some(BizDayAdjustment::Prev(3)),
+                    exclusions: vec![],
                 },
             },
             // Case 4: "2024-1M-TUE#2L~3P"
@@ -796,6 +1573,7 @@ mod table_tests {
                         option: WeekdayOption::Ending(Some(2)),
                     },
                     biz_day_adj: Some(BizDayAdjustment::Prev(3)),
+                    exclusions: vec![],
                 },
             },
             // Case 5: "[2024]-[01,02]-TUE#2L~3P"
@@ -812,6 +1590,7 @@ mod table_tests {
                         option: WeekdayOption::Ending(Some(2)),
                     },
                     biz_day_adj: Some(BizDayAdjustment::Prev(3)),
+                    exclusions: vec![],
                 },
             },
             // Case 6: "2024-[01,02]-[05,10,15]~3P"
@@ -827,6 +1606,7 @@ mod table_tests {
                         option: LastDayOption::NA,
                     },
                     biz_day_adj: Some(BizDayAdjustment::Prev(3)),
+                    exclusions: vec![],
                 },
             },
             // Case 7: "2024-[01,02]-[SAT,SUN]~3P"
@@ -845,6 +1625,7 @@ mod table_tests {
                         option: WeekdayOption::NA,
                     },
                     biz_day_adj: Some(BizDayAdjustment::Prev(3)),
+                    exclusions: vec![],
                 },
             },
             // Case 8: "[2023,2025]-MM-30BD"
@@ -858,6 +1639,7 @@ mod table_tests {
                     months: Cycle::ForEach,
                     days: DayCycle::NextNth(30, NextNthDayOption::BizDay),
                     biz_day_adj: None,
+                    exclusions: vec![],
                 },
             },
             // Case 9: "[2023,2025]-MM-L"
@@ -873,6 +1655,343 @@ mod table_tests {
                         option: LastDayOption::LastDay,
                     },
                     biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 10: "YY-1M-[MON,WED]#2"
+            TestCase {
+                name: "test_weekday_set_nth",
+                input: "YY-1M-[MON,WED]#2",
+                expected_str: "YY-1M-[MON,WED]#2",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::NextNth(1),
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![
+                            WeekdayStartingMonday(Weekday::Mon),
+                            WeekdayStartingMonday(Weekday::Wed),
+                        ]),
+                        option: WeekdayOption::Starting(Some(2)),
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 11: "YY-MM-[THU,TUE]#L~PB" (weekdays normalised to Mon-first order)
+            TestCase {
+                name: "test_weekday_set_last",
+                input: "YY-MM-[THU,TUE]#L~PB",
+                expected_str: "YY-MM-[TUE,THU]#L~PB",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::ForEach,
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![
+                            WeekdayStartingMonday(Weekday::Tue),
+                            WeekdayStartingMonday(Weekday::Thu),
+                        ]),
+                        option: WeekdayOption::Ending(None),
+                    },
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Prev)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 12: "YY-1M-[MON,FRI]#2L"
+            TestCase {
+                name: "test_weekday_set_nth_last",
+                input: "YY-1M-[MON,FRI]#2L",
+                expected_str: "YY-1M-[MON,FRI]#2L",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::NextNth(1),
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![
+                            WeekdayStartingMonday(Weekday::Mon),
+                            WeekdayStartingMonday(Weekday::Fri),
+                        ]),
+                        option: WeekdayOption::Ending(Some(2)),
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 13: "YY-1M-5BDM"
+            TestCase {
+                name: "test_nth_biz_day_of_month",
+                input: "YY-1M-5BDM",
+                expected_str: "YY-1M-5BDM",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::NextNth(1),
+                    days: DayCycle::OnBizDay(BizDayOption::Starting(5)),
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 14: "YY-MM-LBD"
+            TestCase {
+                name: "test_last_biz_day_of_month",
+                input: "YY-MM-LBD",
+                expected_str: "YY-MM-LBD",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::ForEach,
+                    days: DayCycle::OnBizDay(BizDayOption::Ending(None)),
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 15: "YY-[03,06]-3LBD~1P"
+            TestCase {
+                name: "test_nth_last_biz_day_of_month",
+                input: "YY-[03,06]-3LBD~1P",
+                expected_str: "YY-[03,06]-3LBD~1P",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::Values(set(vec![3, 6])),
+                    days: DayCycle::OnBizDay(BizDayOption::Ending(Some(3))),
+                    biz_day_adj: Some(BizDayAdjustment::Prev(1)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 16: "YY-W[01,27]-MON"
+            TestCase {
+                name: "test_iso_week_values",
+                input: "YY-W[01,27]-MON",
+                expected_str: "YY-W[01,27]-MON",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::IsoWeeks(Box::new(Cycle::Values(set(vec![1, 27])))),
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![WeekdayStartingMonday(Weekday::Mon)]),
+                        option: WeekdayOption::NA,
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 17: "2025-2W-[TUE,FRI]~NB"
+            TestCase {
+                name: "test_iso_week_next_nth",
+                input: "2025-2W-[TUE,FRI]~NB",
+                expected_str: "2025-2W-[TUE,FRI]~NB",
+                expected: Spec {
+                    years: Cycle::Values(set(vec![2025])),
+                    months: Cycle::IsoWeeks(Box::new(Cycle::NextNth(2))),
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![
+                            WeekdayStartingMonday(Weekday::Tue),
+                            WeekdayStartingMonday(Weekday::Fri),
+                        ]),
+                        option: WeekdayOption::NA,
+                    },
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Next)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 18: "YY-W5-DD" (week number normalised to two digits)
+            TestCase {
+                name: "test_iso_week_every_day",
+                input: "YY-W5-DD",
+                expected_str: "YY-W05-DD",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::IsoWeeks(Box::new(Cycle::Values(set(vec![5])))),
+                    days: DayCycle::ForEach,
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 19: "YY-D256"
+            TestCase {
+                name: "test_year_day_single",
+                input: "YY-D256",
+                expected_str: "YY-D256",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::AsIs,
+                    days: DayCycle::OnYearDays(set(vec![256])),
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 20: "2Y-D[182,001]~PB" (days sorted, leading zeros dropped)
+            TestCase {
+                name: "test_year_day_set",
+                input: "2Y-D[182,001]~PB",
+                expected_str: "2Y-D[1,182]~PB",
+                expected: Spec {
+                    years: Cycle::NextNth(2),
+                    months: Cycle::AsIs,
+                    days: DayCycle::OnYearDays(set(vec![1, 182])),
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Prev)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 21: "YY-QQ-LBD~PB"
+            TestCase {
+                name: "test_every_quarter",
+                input: "YY-QQ-LBD~PB",
+                expected_str: "YY-QQ-LBD~PB",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::Quarters(Box::new(Cycle::ForEach)),
+                    days: DayCycle::OnBizDay(BizDayOption::Ending(None)),
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Prev)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 22: "YY-[Q3,Q1]-15" (quarters sorted)
+            TestCase {
+                name: "test_quarter_set",
+                input: "YY-[Q3,Q1]-15",
+                expected_str: "YY-[Q1,Q3]-15",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::Quarters(Box::new(Cycle::Values(set(vec![1, 3])))),
+                    days: DayCycle::OnDays {
+                        days: set(vec![15]),
+                        option: LastDayOption::NA,
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 23: "2025-2Q-L"
+            TestCase {
+                name: "test_every_nth_quarter",
+                input: "2025-2Q-L",
+                expected_str: "2025-2Q-L",
+                expected: Spec {
+                    years: Cycle::Values(set(vec![2025])),
+                    months: Cycle::Quarters(Box::new(Cycle::NextNth(2))),
+                    days: DayCycle::OnDays {
+                        days: set(vec![]),
+                        option: LastDayOption::LastDay,
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 24: "YY-Q4-FRI#L"
+            TestCase {
+                name: "test_single_quarter",
+                input: "YY-Q4-FRI#L",
+                expected_str: "YY-Q4-FRI#L",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::Quarters(Box::new(Cycle::Values(set(vec![4])))),
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![WeekdayStartingMonday(Weekday::Fri)]),
+                        option: WeekdayOption::Ending(None),
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 25: "YY-MM-1WD EXCEPT YY-MM-01!YY-12-DD~NB" (EXCEPT normalised to '!')
+            TestCase {
+                name: "test_exclusions",
+                input: "YY-MM-1WD EXCEPT YY-MM-01!YY-12-DD~NB",
+                expected_str: "YY-MM-1WD!YY-MM-01!YY-12-DD~NB",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::ForEach,
+                    days: DayCycle::NextNth(1, NextNthDayOption::WeekDay),
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Next)),
+                    exclusions: vec![
+                        Spec {
+                            years: Cycle::ForEach,
+                            months: Cycle::ForEach,
+                            days: DayCycle::OnDays {
+                                days: set(vec![1]),
+                                option: LastDayOption::NA,
+                            },
+                            biz_day_adj: None,
+                            exclusions: vec![],
+                        },
+                        Spec {
+                            years: Cycle::ForEach,
+                            months: Cycle::Values(set(vec![12])),
+                            days: DayCycle::ForEach,
+                            biz_day_adj: None,
+                            exclusions: vec![],
+                        },
+                    ],
+                },
+            },
+            // Case 26: "YY-1M-5BDA~NB"
+            TestCase {
+                name: "test_anchored_biz_day_step",
+                input: "YY-1M-5BDA~NB",
+                expected_str: "YY-1M-5BDA~NB",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::NextNth(1),
+                    days: DayCycle::OnBizDay(BizDayOption::Every(5)),
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Next)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 27: "YY-EASTER-2~1P" (Good Friday, one business day earlier)
+            TestCase {
+                name: "test_western_easter_offset",
+                input: "YY-EASTER-2~1P",
+                expected_str: "YY-EASTER-2~1P",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::AsIs,
+                    days: DayCycle::OnEaster(EasterCalendar::Western, -2),
+                    biz_day_adj: Some(BizDayAdjustment::Prev(1)),
+                    exclusions: vec![],
+                },
+            },
+            // Case 28: "YY-OEASTER+0" (a zero offset is dropped)
+            TestCase {
+                name: "test_orthodox_easter",
+                input: "YY-OEASTER+0",
+                expected_str: "YY-OEASTER",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::AsIs,
+                    days: DayCycle::OnEaster(EasterCalendar::Orthodox, 0),
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 29: "YY-MM-MON>=15"
+            TestCase {
+                name: "test_weekday_on_or_after",
+                input: "YY-MM-MON>=15",
+                expected_str: "YY-MM-MON>=15",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::ForEach,
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![WeekdayStartingMonday(Weekday::Mon)]),
+                        option: WeekdayOption::OnOrAfter(Some(15)),
+                    },
+                    biz_day_adj: None,
+                    exclusions: vec![],
+                },
+            },
+            // Case 30: "YY-MM-[TUE,FRI]<=L~PB"
+            TestCase {
+                name: "test_weekdays_on_or_before_month_end",
+                input: "YY-MM-[TUE,FRI]<=L~PB",
+                expected_str: "YY-MM-[TUE,FRI]<=L~PB",
+                expected: Spec {
+                    years: Cycle::ForEach,
+                    months: Cycle::ForEach,
+                    days: DayCycle::OnWeekDays {
+                        weekdays: set(vec![
+                            WeekdayStartingMonday(Weekday::Tue),
+                            WeekdayStartingMonday(Weekday::Fri),
+                        ]),
+                        option: WeekdayOption::OnOrBefore(None),
+                    },
+                    biz_day_adj: Some(BizDayAdjustment::BizDay(Direction::Prev)),
+                    exclusions: vec![],
                 },
             },
         ];
@@ -900,6 +2019,13 @@ mod table_tests {
                 "String mismatch in '{}'",
                 case.name
             );
+
+            // 4. Every table case is also valid under strict validation
+            assert!(
+                parse_spec_strict(case.input).is_ok(),
+                "Strict validation rejected '{}'",
+                case.name
+            );
         }
     }
 }
@@ -528,6 +528,78 @@ fn test_weekday_specs() {
     ]);
 }
 
+/// Weekday specs whose period starts on the selected weekday: the 1st of the
+/// period is a candidate like any other day. `YY-01-MON#1` used to skip
+/// 2029-01-01 and `YY-[03,09,12]-MON` used to skip 1 Sep and 1 Dec 2025;
+/// periods that start on another weekday are unaffected.
+#[test]
+fn test_weekday_specs_from_period_start() {
+    let tz = chrono_tz::America::New_York;
+    let day = |y, m, d| Occurrence::Exact(tz.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap());
+    run_cases(vec![
+        // Jan 1 2029 is a Monday
+        TestCase {
+            spec: "YY-01-MON#1",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                day(2025, 1, 6),
+                day(2026, 1, 5),
+                day(2027, 1, 4),
+                day(2028, 1, 3),
+                day(2029, 1, 1),
+            ]),
+        },
+        // Sep 1 and Dec 1 2025 are Mondays
+        TestCase {
+            spec: "YY-[03,09,12]-MON",
+            take: 7,
+            start: tz.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                day(2025, 3, 31),
+                day(2025, 9, 1),
+                day(2025, 9, 8),
+                day(2025, 9, 15),
+                day(2025, 9, 22),
+                day(2025, 9, 29),
+                day(2025, 12, 1),
+            ]),
+        },
+        TestCase {
+            spec: "YY-[01,09]-MON#1",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(),
+            expected: Ok(vec![day(2025, 1, 6), day(2025, 9, 1), day(2026, 1, 5)]),
+        },
+        TestCase {
+            spec: "YY-[01,09]-MON>=1",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(),
+            expected: Ok(vec![day(2025, 1, 6), day(2025, 9, 1), day(2026, 1, 5)]),
+        },
+        // Unchanged: no period starts on a Monday here
+        TestCase {
+            spec: "YY-[03,10]-MON",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap(),
+            expected: Ok(vec![day(2025, 3, 31), day(2025, 10, 6), day(2025, 10, 13)]),
+        },
+        TestCase {
+            spec: "YY-01-MON#2",
+            take: 2,
+            start: tz.with_ymd_and_hms(2028, 1, 10, 0, 0, 0).unwrap(),
+            expected: Ok(vec![day(2028, 1, 10), day(2029, 1, 8)]),
+        },
+        // Unchanged: a monthly cadence already matched the 1st
+        TestCase {
+            spec: "YY-MM-MON#1",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 8, 4, 0, 0, 0).unwrap(),
+            expected: Ok(vec![day(2025, 8, 4), day(2025, 9, 1), day(2025, 10, 6)]),
+        },
+    ]);
+}
+
 // ---------------------------------------------------------------------------
 // Group 6: Enumerated days / months
 // ---------------------------------------------------------------------------
@@ -992,3 +1064,1085 @@ fn test_biz_day_adjustments() {
     ]);
 }
 
+// ---------------------------------------------------------------------------
+// Group 12: Multi-weekday occurrence selectors  ([WD,WD]#N, [WD,WD]#L)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_multi_weekday_selectors() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // 2nd Monday and 2nd Wednesday of every month
+        TestCase {
+            spec: "YY-1M-[MON,WED]#2",
+            take: 8,
+            start: tz.with_ymd_and_hms(2025, 1, 8, 0, 0, 0).unwrap(), // 2nd Wed
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 8, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 13, 0, 0, 0).unwrap()), // Mon
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap()), // Mon
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 12, 0, 0, 0).unwrap()), // Wed
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap()), // Mon
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 12, 0, 0, 0).unwrap()), // Wed
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 9, 0, 0, 0).unwrap()),  // Wed first
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 14, 0, 0, 0).unwrap()), // Mon
+            ]),
+        },
+        // Last Tuesday and last Thursday of every month
+        TestCase {
+            spec: "YY-1M-[TUE,THU]#L",
+            take: 8,
+            start: tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap(), // last Tue
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 27, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 27, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 24, 0, 0, 0).unwrap()), // Thu first
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 29, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 2nd-to-last Monday and 2nd-to-last Friday of every month
+        TestCase {
+            spec: "YY-1M-[MON,FRI]#2L",
+            take: 6,
+            start: tz.with_ymd_and_hms(2025, 1, 20, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 20, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 24, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 17, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 21, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 21, 0, 0, 0).unwrap()), // Fri first
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 24, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 1st Monday and 1st Friday of each quarter
+        TestCase {
+            spec: "YY-3M-[MON,FRI]#1",
+            take: 8,
+            start: tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 4, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 10, 3, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 10, 6, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+// ---------------------------------------------------------------------------
+// Group 13: Business day of month  (nBDM, LBD, nLBD)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_biz_day_of_month() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // 5th business day of every month (payroll)
+        TestCase {
+            spec: "YY-1M-5BDM",
+            take: 6,
+            start: tz.with_ymd_and_hms(2025, 1, 7, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 6, 0, 0, 0).unwrap()), // Jun 1 is Sun
+            ]),
+        },
+        // 1st business day of each quarter; the 1st itself qualifies when it is a weekday
+        TestCase {
+            spec: "YY-3M-1BDM",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Last business day of every month
+        TestCase {
+            spec: "YY-MM-LBD",
+            take: 6,
+            start: tz.with_ymd_and_hms(2025, 5, 30, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 30, 0, 0, 0).unwrap()), // May 31 is Sat
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 8, 29, 0, 0, 0).unwrap()), // Aug 31 is Sun
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 10, 31, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 2nd-to-last business day of March and September
+        TestCase {
+            spec: "YY-[03,09]-2LBD",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 3, 28, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 29, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 3, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 9, 29, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 3rd business day, then unconditionally 1 business day earlier
+        TestCase {
+            spec: "YY-1M-3BDM~1P",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap()),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2025, 2, 5, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2025, 2, 4, 0, 0, 0).unwrap(),
+                ),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2025, 3, 5, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2025, 3, 4, 0, 0, 0).unwrap(),
+                ),
+            ]),
+        },
+    ]);
+}
+
+#[test]
+fn test_biz_day_of_month_uses_processor_holidays() {
+    use crate::biz_day::{BizDayProcessor, Direction};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    /// Weekends plus New Year's Day and 2025-05-30 are non-business days.
+    #[derive(Debug, Clone)]
+    struct Holidays;
+
+    impl BizDayProcessor for Holidays {
+        fn is_biz_day(&self, dtm: &NaiveDateTime) -> Result<bool> {
+            let holidays = [
+                NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 5, 30).unwrap(),
+            ];
+            Ok(WeekendSkipper::new().is_biz_day(dtm)? && !holidays.contains(&dtm.date()))
+        }
+
+        fn find_biz_day(&self, dtm: &NaiveDateTime, direction: Direction) -> Result<NaiveDateTime> {
+            match direction {
+                Direction::Prev => self.sub(dtm, 1),
+                _ => self.add(dtm, 1),
+            }
+        }
+
+        fn add(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+            let mut cur = *dtm;
+            let mut n = 0;
+            while n < num {
+                cur += chrono::Duration::days(1);
+                if self.is_biz_day(&cur)? {
+                    n += 1;
+                }
+            }
+            Ok(cur)
+        }
+
+        fn sub(&self, dtm: &NaiveDateTime, num: u32) -> Result<NaiveDateTime> {
+            let mut cur = *dtm;
+            let mut n = 0;
+            while n < num {
+                cur -= chrono::Duration::days(1);
+                if self.is_biz_day(&cur)? {
+                    n += 1;
+                }
+            }
+            Ok(cur)
+        }
+    }
+
+    let tz = chrono_tz::America::New_York;
+    let first = SpecIteratorBuilder::new_after(
+        "YY-MM-1BDM",
+        Holidays,
+        tz.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap(),
+    )
+    .build()
+    .unwrap()
+    .take(1)
+    .collect::<Vec<_>>()
+    .unwrap();
+    assert_eq!(
+        first,
+        vec![Occurrence::Exact(
+            tz.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()
+        )]
+    );
+
+    let last = SpecIteratorBuilder::new_after(
+        "YY-MM-LBD",
+        Holidays,
+        tz.with_ymd_and_hms(2025, 4, 30, 0, 0, 0).unwrap(),
+    )
+    .build()
+    .unwrap()
+    .take(2)
+    .collect::<Vec<_>>()
+    .unwrap();
+    assert_eq!(
+        last,
+        vec![
+            Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 29, 0, 0, 0).unwrap()),
+            Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap()),
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 14: ISO week and day-of-year forms  (W[..]-WD, nW-WD, D[..])
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_iso_week_and_year_day_forms() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // Monday of ISO weeks 1 and 27; week 1 of 2025 starts on 2024-12-30
+        TestCase {
+            spec: "YY-W[01,27]-MON",
+            take: 4,
+            start: tz.with_ymd_and_hms(2024, 12, 30, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 12, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 29, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 6, 29, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Every 2nd ISO week on Friday, aligned to the start week
+        TestCase {
+            spec: "YY-2W-FRI",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 17, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 14, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Week 53 only exists in some ISO years (2026, then 2032); its Friday
+        // may fall in the next calendar year
+        TestCase {
+            spec: "YY-W53-[MON,FRI]",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 12, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2032, 12, 27, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 256th day of the year shifts by one in leap years
+        TestCase {
+            spec: "YY-D256",
+            take: 3,
+            start: tz.with_ymd_and_hms(2024, 9, 12, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 9, 12, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 13, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 9, 13, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Day 366 only in leap years; 2028-12-31 is a Sunday
+        TestCase {
+            spec: "YY-D366~PB",
+            take: 3,
+            start: tz.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2028, 12, 31, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2028, 12, 29, 0, 0, 0).unwrap(),
+                ),
+            ]),
+        },
+        // Finite year set terminates after its last day
+        TestCase {
+            spec: "2026-D[100,200]",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 4, 10, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 7, 19, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+// ---------------------------------------------------------------------------
+// Group 15: Fiscal quarters  (QQ, nQ, Qn, [Qn,Qn])
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_calendar_quarters() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // Last day of each quarter (end-anchored day rules use the quarter's last month)
+        TestCase {
+            spec: "YY-QQ-L",
+            take: 5,
+            start: tz.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 31, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 1st and 15th of the first month of Q1 and Q3
+        TestCase {
+            spec: "YY-[Q1,Q3]-[01,15]",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Half-yearly is aligned to the fiscal year, not to a mid-quarter start
+        TestCase {
+            spec: "YY-2Q-L",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 30, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+#[test]
+fn test_fiscal_quarters() {
+    let tz = chrono_tz::America::New_York;
+    let run = |spec: &str, fiscal_year_start: u32, take: usize| {
+        SpecIteratorBuilder::new_after(
+            spec,
+            WeekendSkipper::new(),
+            tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        )
+        .with_fiscal_year_start(fiscal_year_start)
+        .build()
+        .unwrap()
+        .take(take)
+        .map(|o| Ok(o.observed().date_naive().to_string()))
+        .collect::<Vec<_>>()
+        .unwrap()
+    };
+
+    // Fiscal year starting in April: quarters end in Jun, Sep, Dec and Mar
+    assert_eq!(
+        run("YY-QQ-LBD", 4, 5),
+        [
+            "2025-03-31",
+            "2025-06-30",
+            "2025-09-30",
+            "2025-12-31",
+            "2026-03-31"
+        ]
+    );
+    // Fiscal year starting in July: Q1 is Jul–Sep; 2025-09-30 is a Tuesday
+    assert_eq!(run("YY-Q1-LBD", 7, 2), ["2025-09-30", "2026-09-30"]);
+    // First Monday of each fiscal half (Q1 and Q3 of an April fiscal year)
+    assert_eq!(
+        run("YY-2Q-MON#1", 4, 3),
+        ["2025-04-07", "2025-10-06", "2026-04-06"]
+    );
+    // Last Friday of the fiscal year (Q4 ends in March)
+    assert_eq!(run("YY-Q4-FRI#L", 4, 2), ["2025-03-28", "2026-03-27"]);
+    // A year is always a calendar year, never a fiscal year
+    assert_eq!(run("2025-Q4-L", 4, 2), ["2025-03-31"]);
+    assert_eq!(run("2026-Q4-L", 4, 2), ["2026-03-31"]);
+    // Q1 of a November fiscal year spans New Year; each month matches 2025 alone
+    assert_eq!(run("2025-Q1-01", 11, 2), ["2025-11-01"]);
+    assert_eq!(run("2025-Q1-L", 11, 2), ["2025-01-31"]);
+
+    let err = SpecIteratorBuilder::new_after(
+        "YY-QQ-L",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+    )
+    .with_fiscal_year_start(13)
+    .build()
+    .unwrap_err();
+    assert!(matches!(err, Error::InvalidDateSpec(_)));
+}
+
+// ---------------------------------------------------------------------------
+// Group 16: Exclusion clauses  (!<spec>, EXCEPT <spec>)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_exclusions() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // Every weekday except the 1st of the month (Mon 2025-09-01 is skipped)
+        TestCase {
+            spec: "YY-MM-1WD!YY-MM-01",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 8, 28, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 8, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 8, 29, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 2, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 3, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Monthly on the 15th except December
+        TestCase {
+            spec: "YY-1M-15 EXCEPT YY-12-DD",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 10, 15, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 10, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 11, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 2, 15, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Exclusions are checked against the raw date, before adjustment:
+        // Saturdays are skipped outright, while Sun 2025-06-15 still rolls back
+        TestCase {
+            spec: "YY-1M-15!YY-MM-SAT~PB",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 15, 0, 0, 0).unwrap()),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2025, 6, 15, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2025, 6, 13, 0, 0, 0).unwrap(),
+                ),
+            ]),
+        },
+        // Cadences in an exclusion align to the iterator start: every quarter from January
+        TestCase {
+            spec: "YY-MM-15!YY-3M-15",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 15, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+#[test]
+fn test_exclusion_of_every_date_does_not_converge() {
+    let tz = chrono_tz::America::New_York;
+    let result = SpecIteratorBuilder::new_after(
+        "YY-MM-DD!YY-MM-DD",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+    )
+    .build()
+    .unwrap()
+    .next();
+    assert_eq!(result, Err(Error::IteratorNotConverged));
+}
+
+// ---------------------------------------------------------------------------
+// Group 17: Spec sets  (<spec>|<spec>|…)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_spec_set_merges_members_in_order() {
+    let tz = chrono_tz::America::New_York;
+    let items: Vec<_> = SpecIteratorBuilder::new_after(
+        "YY-MM-15|YY-MM-L|YY-[01,07]-MON#1",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+    )
+    .build_set()
+    .unwrap()
+    .take(6)
+    .collect()
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            (
+                2,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap())
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap())
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_spec_set_deduplicates_observed_dates() {
+    let tz = chrono_tz::America::New_York;
+
+    // Mon 2025-12-15 is produced by both members; the first member keeps it.
+    let items: Vec<_> = SpecIteratorBuilder::new_after(
+        "YY-MM-15|YY-MM-MON",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap(),
+    )
+    .build_set()
+    .unwrap()
+    .take(3)
+    .collect()
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 8, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 15, 0, 0, 0).unwrap())
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 22, 0, 0, 0).unwrap())
+            ),
+        ]
+    );
+
+    // Sat 2025-11-15 rolls to Mon 2025-11-17, which the second member also
+    // produces; the adjusted occurrence has the earlier actual date and wins.
+    let items: Vec<_> = SpecIteratorBuilder::new_after(
+        "YY-MM-17|YY-MM-15~NB",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap(),
+    )
+    .build_set()
+    .unwrap()
+    .take(2)
+    .collect()
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            (
+                1,
+                Occurrence::AdjustedLater(
+                    tz.with_ymd_and_hms(2025, 11, 15, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2025, 11, 17, 0, 0, 0).unwrap(),
+                )
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 12, 15, 0, 0, 0).unwrap())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_spec_set_shares_start_and_end() {
+    let tz = chrono_tz::America::New_York;
+    let items: Vec<_> = SpecIteratorBuilder::new_with_start(
+        "YY-MM-01|YY-MM-15",
+        WeekendSkipper::new(),
+        tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+    )
+    .with_end(tz.with_ymd_and_hms(2025, 2, 20, 0, 0, 0).unwrap())
+    .build_set()
+    .unwrap()
+    .collect()
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap())
+            ),
+            (
+                1,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap())
+            ),
+            (
+                0,
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 20, 0, 0, 0).unwrap())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_spec_set_parsing() {
+    use crate::date::SpecSet;
+
+    let set: SpecSet = "YY-MM-15 | YY-MM-L~PB|YY-[01,07]-MON#1".parse().unwrap();
+    assert_eq!(set.specs.len(), 3);
+    assert_eq!(set.to_string(), "YY-MM-15|YY-MM-L~PB|YY-[01,07]-MON#1");
+
+    assert!("YY-MM-15|".parse::<SpecSet>().is_err());
+    assert!("YY-MM-15||YY-MM-L".parse::<SpecSet>().is_err());
+    assert!(SpecIteratorBuilder::new_after(
+        "YY-MM-15|YY-MM-XX",
+        WeekendSkipper::new(),
+        chrono_tz::America::New_York
+            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+            .unwrap(),
+    )
+    .build_set()
+    .is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Group 18: Anchored business-day steps  (nBDA)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_anchored_biz_day_step() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // Every 5th business day, restarting each month (BD5, BD10, …)
+        TestCase {
+            spec: "YY-MM-5BDA",
+            take: 8,
+            start: tz.with_ymd_and_hms(2025, 1, 7, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 14, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 21, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap()),
+                // Jan has 23 business days; the count restarts on Feb 3
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 7, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 14, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 21, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Only the listed months; each restarts from its first business day
+        TestCase {
+            spec: "YY-[01,07]-10BDA",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 14, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 14, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 14, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 7, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 1, 14, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Quarters: the count runs across the quarter's three months
+        // (Jan has 23 business days, Feb 20) and restarts each quarter
+        TestCase {
+            spec: "YY-QQ-20BDA",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 26, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Every other quarter: Q1 and Q3 only
+        TestCase {
+            spec: "YY-2Q-30BDA",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 2, 11, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 11, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 8, 11, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 9, 22, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // 3-month periods aligned to the February start: Feb–Apr, May–Jul
+        TestCase {
+            spec: "YY-3M-20BDA",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 25, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 25, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+// ---------------------------------------------------------------------------
+// Group 19: Easter-relative dates  (EASTER±n, OEASTER±n)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_easter_relative_dates() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // Easter Monday
+        TestCase {
+            spec: "YY-EASTER+1",
+            take: 4,
+            start: tz.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 21, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 4, 6, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2027, 3, 29, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Good Friday, one business day earlier
+        TestCase {
+            spec: "YY-EASTER-2~1P",
+            take: 3,
+            start: tz.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap()),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2025, 4, 18, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2025, 4, 17, 0, 0, 0).unwrap(),
+                ),
+                Occurrence::AdjustedEarlier(
+                    tz.with_ymd_and_hms(2026, 4, 3, 0, 0, 0).unwrap(),
+                    tz.with_ymd_and_hms(2026, 4, 2, 0, 0, 0).unwrap(),
+                ),
+            ]),
+        },
+        // Orthodox Easter Sunday
+        TestCase {
+            spec: "YY-OEASTER",
+            take: 4,
+            start: tz.with_ymd_and_hms(2024, 5, 5, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 5, 5, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 20, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2026, 4, 12, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2027, 5, 2, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // The offset may cross into the previous year; it still belongs to 2025
+        TestCase {
+            spec: "2025-EASTER-100",
+            take: 2,
+            start: tz.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+#[test]
+fn test_easter_sunday_table() {
+    use crate::date::EasterCalendar;
+    use chrono::NaiveDate;
+
+    // (year, western, orthodox)
+    let table = [
+        (1961, (4, 2), (4, 9)),
+        (2000, (4, 23), (4, 30)),
+        (2008, (3, 23), (4, 27)),
+        (2019, (4, 21), (4, 28)),
+        (2038, (4, 25), (4, 25)),
+        (2100, (3, 28), (5, 2)),
+    ];
+    for (year, (wm, wd), (om, od)) in table {
+        assert_eq!(
+            EasterCalendar::Western.easter_sunday(year),
+            NaiveDate::from_ymd_opt(year, wm, wd),
+            "western {year}"
+        );
+        assert_eq!(
+            EasterCalendar::Orthodox.easter_sunday(year),
+            NaiveDate::from_ymd_opt(year, om, od),
+            "orthodox {year}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Group 20: Weekday on or after / on or before a day  (WD>=n, WD<=n, WD<=L)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_weekday_relative_to_day() {
+    let tz = chrono_tz::America::New_York;
+    run_cases(vec![
+        // First Monday on or after the 15th
+        TestCase {
+            spec: "YY-MM-MON>=15",
+            take: 5,
+            start: tz.with_ymd_and_hms(2025, 1, 20, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 20, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 17, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 21, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 19, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Friday on or before month end; Jan 31 and Feb 28 are themselves Fridays
+        TestCase {
+            spec: "YY-MM-FRI<=L",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 28, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 25, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // The anchor day itself qualifies
+        TestCase {
+            spec: "YY-MM-MON>=3",
+            take: 2,
+            start: tz.with_ymd_and_hms(2025, 2, 3, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 3, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Months without a Saturday on or after the 29th are skipped
+        TestCase {
+            spec: "YY-MM-SAT>=29",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 3, 29, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 3, 29, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 5, 31, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 8, 30, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 11, 29, 0, 0, 0).unwrap()),
+            ]),
+        },
+        // Each listed weekday on or before the 10th
+        TestCase {
+            spec: "YY-MM-[MON,THU]<=10",
+            take: 4,
+            start: tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(),
+            expected: Ok(vec![
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 1, 9, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 6, 0, 0, 0).unwrap()),
+                Occurrence::Exact(tz.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap()),
+            ]),
+        },
+    ]);
+}
+
+// ---------------------------------------------------------------------------
+// Group 21: Reverse iteration  (new_before)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_reverse_iteration() {
+    let tz = chrono_tz::America::New_York;
+    let bdp = WeekendSkipper::new();
+    let dtm = tz.with_ymd_and_hms(2025, 6, 10, 0, 0, 0).unwrap();
+    let run = |spec: &str, take: usize| -> Vec<Occurrence<DateTime<_>>> {
+        SpecIteratorBuilder::new_before(spec, bdp.clone(), dtm)
+            .build()
+            .unwrap()
+            .take(take)
+            .collect()
+            .unwrap()
+    };
+    let day = |y, m, d| tz.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+
+    // Business day adjustment is reported as going forwards
+    assert_eq!(
+        run("YY-1M-L~PW", 3),
+        vec![
+            Occurrence::AdjustedEarlier(day(2025, 5, 31), day(2025, 5, 30)),
+            Occurrence::Exact(day(2025, 4, 30)),
+            Occurrence::Exact(day(2025, 3, 31)),
+        ]
+    );
+
+    // Cadences count back from the cursor: forward from it gives 2025-06-15
+    assert_eq!(
+        run("YY-3M-15~NW", 3),
+        vec![
+            Occurrence::AdjustedLater(day(2025, 3, 15), day(2025, 3, 17)),
+            Occurrence::AdjustedLater(day(2024, 12, 15), day(2024, 12, 16)),
+            Occurrence::AdjustedLater(day(2024, 9, 15), day(2024, 9, 16)),
+        ]
+    );
+    assert_eq!(
+        run("YY-MM-7D", 2),
+        vec![
+            Occurrence::Exact(day(2025, 6, 3)),
+            Occurrence::Exact(day(2025, 5, 27))
+        ]
+    );
+    assert_eq!(
+        run("YY-2W-FRI", 2),
+        vec![
+            Occurrence::Exact(day(2025, 5, 30)),
+            Occurrence::Exact(day(2025, 5, 16))
+        ]
+    );
+
+    // A fixed date is found however far back it is; a future one never is
+    assert_eq!(
+        run("2024-02-29", 3),
+        vec![Occurrence::Exact(day(2024, 2, 29))]
+    );
+    assert_eq!(run("2030-01-01", 3), vec![]);
+}
+
+#[test]
+fn test_reverse_matches_forward() {
+    let tz = chrono_tz::America::New_York;
+    let bdp = WeekendSkipper::new();
+    let dtm = tz.with_ymd_and_hms(2025, 6, 10, 0, 0, 0).unwrap();
+    for spec in ["YY-MM-MON#2", "YY-MM-LBD", "YY-1M-15~NB", "YY-[01,07]-L~PW"] {
+        let mut reverse: Vec<Occurrence<DateTime<_>>> =
+            SpecIteratorBuilder::new_before(spec, bdp.clone(), dtm)
+                .build()
+                .unwrap()
+                .take(8)
+                .collect()
+                .unwrap();
+        reverse.reverse();
+
+        let from = *reverse[0].actual() - chrono::Duration::days(1);
+        let forward: Vec<Occurrence<DateTime<_>>> =
+            SpecIteratorBuilder::new_after(spec, bdp.clone(), from)
+                .build()
+                .unwrap()
+                .take(8)
+                .collect()
+                .unwrap();
+        assert_eq!(reverse, forward, "spec: {}", spec);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Group 22: Strict parsing  (strict)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_strict_build() {
+    use crate::SpecComponent;
+    use chrono::Utc;
+    let bdp = WeekendSkipper::new();
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let span_of = |result: Result<()>| match result {
+        Err(Error::InvalidSpecComponent {
+            component, span, ..
+        }) => (component, span),
+        other => panic!("expected a component error, got {other:?}"),
+    };
+
+    // Lenient builders accept a spec that never matches.
+    assert!(
+        SpecIteratorBuilder::new_after("YY-13-01", bdp.clone(), start)
+            .build()
+            .is_ok()
+    );
+    assert_eq!(
+        span_of(
+            SpecIteratorBuilder::new_after("YY-13-01", bdp.clone(), start)
+                .strict()
+                .build()
+                .map(drop)
+        ),
+        (SpecComponent::Month, 3..5)
+    );
+    assert_eq!(
+        span_of(
+            SpecIteratorBuilder::new_before("2025-D366", bdp.clone(), start)
+                .strict()
+                .build()
+                .map(drop)
+        ),
+        (SpecComponent::Day, 6..9)
+    );
+
+    // Set members report spans within the whole set string.
+    assert_eq!(
+        span_of(
+            SpecIteratorBuilder::new_after("YY-MM-01 | YY-02-30", bdp.clone(), start)
+                .strict()
+                .build_set()
+                .map(drop)
+        ),
+        (SpecComponent::Day, 17..19)
+    );
+
+    // The end spec is checked too, with spans within it.
+    assert_eq!(
+        span_of(
+            SpecIteratorBuilder::new_with_start("YY-MM-01", bdp.clone(), start)
+                .strict()
+                .with_end_spec("YY-MM-MON#6")
+                .build()
+                .map(drop)
+        ),
+        (SpecComponent::Day, 10..11)
+    );
+
+    let dates: Vec<Occurrence<DateTime<Utc>>> =
+        SpecIteratorBuilder::new_with_start("YY-MM-01", bdp, start)
+            .strict()
+            .build()
+            .unwrap()
+            .take(2)
+            .collect()
+            .unwrap();
+    assert_eq!(dates.len(), 2);
+}
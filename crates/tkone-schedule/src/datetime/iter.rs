@@ -1,11 +1,14 @@
 use super::spec::Spec;
 use crate::biz_day::BizDayProcessor;
-use crate::date::NaiveSpecIterator as DateNaiveSpecIterator;
+use crate::biz_hours::BusinessHours;
+use crate::date::{NaiveSpecIterator as DateNaiveSpecIterator, Period};
+use crate::jitter::{jitter_occurrence, Jitter};
 use crate::prelude::*;
-use crate::time::{Cycle as TimeCycle, Spec as TimeSpec};
-use crate::utils::next_result_to_tz;
+use crate::reverse::{NaiveReverse, Rewind};
+use crate::time::{apply_time_spec, is_single_time, spec_delta, Spec as TimeSpec};
+use crate::utils::{local_in, next_result_in_zone};
 use crate::{DstPolicy, Occurrence};
-use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use fallible_iterator::FallibleIterator;
 use std::marker::PhantomData;
 use std::str::FromStr;
@@ -13,6 +16,7 @@ use std::str::FromStr;
 // --- Builder type-state markers (same pattern as date/time) ---
 pub struct StartDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct NoStart;
+pub struct Before;
 pub struct EndDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct NoEnd;
 pub struct Sealed;
@@ -27,8 +31,10 @@ pub struct NotSealed;
 /// | Constructor | First result | Use when… |
 /// |-------------|-------------|-----------|
 /// | `new(spec, bdp, tz)` | First occurrence after `Utc::now()` | open-ended schedule from now |
+/// | `from_spec(spec, bdp)` | First occurrence after `Utc::now()`, in the spec's `@` zone | spec strings loaded from config |
 /// | `new_after(spec, bdp, dtm)` | First occurrence **after** `dtm` | schedule from a known cursor |
 /// | `new_with_start(spec, bdp, start)` | `start` itself is the first item | anchor to a fixed start datetime |
+/// | `new_before(spec, bdp, dtm)` | Latest occurrence **before** `dtm`, then earlier ones | back-fills and reconciliation |
 ///
 /// # Examples
 ///
@@ -62,6 +68,10 @@ pub struct SpecIteratorBuilder<Tz: TimeZone, BDP: BizDayProcessor, START, END, S
     end: END,
     timezone: Tz,
     dst_policy: DstPolicy,
+    business_hours: Option<BusinessHours>,
+    jitter: Option<Jitter>,
+    session_close: bool,
+    strict: bool,
     marker_sealed: PhantomData<S>,
 }
 
@@ -76,6 +86,104 @@ impl<Tz: TimeZone, BDP: BizDayProcessor, START, END, S>
         self.dst_policy = policy;
         self
     }
+
+    /// Only yield occurrences whose observed local time falls inside `hours`.
+    ///
+    /// Applied after business day adjustment; occurrences outside the
+    /// windows, including a `new_with_start` start, are skipped.
+    pub fn with_business_hours(mut self, hours: BusinessHours) -> Self {
+        self.business_hours = Some(hours);
+        self
+    }
+
+    /// Shift every occurrence's observed time by the stable offset of `jitter`.
+    ///
+    /// `actual()` keeps the nominal, unjittered time; the occurrence becomes
+    /// `AdjustedLater` or `AdjustedEarlier` according to where the shifted
+    /// time lands. The cadence, end bound and business hours all work on the
+    /// nominal times. A [`Jitter::splay`] offset falls within the step of the
+    /// time spec's driving component or, when every time is pinned
+    /// (`T09:00:00`), within the step of the date spec (a day for `YY-MM-DD`,
+    /// four weeks for `YY-MM-01`).
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// Move occurrences later than the day's session close back to the close,
+    /// as for a cut-off job that must run before an early close.
+    ///
+    /// The close comes from [`BizDayProcessor::session`] on the observed date;
+    /// an occurrence moved to it becomes `AdjustedEarlier`, with `actual()`
+    /// keeping the nominal time. Only applies when every time in the spec is
+    /// a single `At` time of day, so that a sub-daily cadence is never
+    /// collapsed onto the close. Applied before business hours and jitter.
+    ///
+    /// Session times are read on the wall clock the spec runs on: the zone of
+    /// its `@` suffix, or else the timezone of the builder. An occurrence
+    /// moved back to or before the start of iteration, or to the time of an
+    /// occurrence already yielded, is dropped.
+    ///
+    /// ```rust
+    /// use tkone_schedule::calendar::markets;
+    /// use tkone_schedule::datetime::SpecIteratorBuilder;
+    /// use tkone_schedule::Occurrence;
+    /// use chrono::TimeZone;
+    /// use chrono_tz::America::New_York;
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// // A 15:00 New York job on NYSE trading days, 2025-12-23 onwards
+    /// let spec = "YY-MM-DD~NBT15:00:00@America/New_York";
+    /// let start = New_York.with_ymd_and_hms(2025, 12, 23, 0, 0, 0).unwrap();
+    /// let iter = SpecIteratorBuilder::new_after(spec, markets::nyse(), start)
+    ///     .with_session_close()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let runs: Vec<_> = iter.take(3).collect().unwrap();
+    /// let at = |d, h| New_York.with_ymd_and_hms(2025, 12, d, h, 0, 0).unwrap();
+    /// assert_eq!(runs[0], Occurrence::Exact(at(23, 15)));
+    /// // Christmas Eve closes at 13:00
+    /// assert_eq!(runs[1], Occurrence::AdjustedEarlier(at(24, 15), at(24, 13)));
+    /// // Christmas Day is a holiday
+    /// assert_eq!(runs[2], Occurrence::AdjustedLater(at(25, 15), at(26, 15)));
+    /// ```
+    pub fn with_session_close(mut self) -> Self {
+        self.session_close = true;
+        self
+    }
+
+    /// Parse the spec with [`Spec::parse_strict`] when building, so that a
+    /// date half that can never match, such as `2025-D366`, fails `build()`
+    /// with [`Error::InvalidSpecComponent`] instead of yielding nothing.
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::WeekendSkipper;
+    /// use tkone_schedule::datetime::SpecIteratorBuilder;
+    /// use tkone_schedule::{Error, SpecComponent};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let iter = SpecIteratorBuilder::new_after("2025-D366T09:00:00", WeekendSkipper::new(), start)
+    ///     .strict()
+    ///     .build();
+    /// assert!(matches!(
+    ///     iter,
+    ///     Err(Error::InvalidSpecComponent { component: SpecComponent::Day, .. })
+    /// ));
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    fn parse_spec(&self) -> Result<Spec> {
+        if self.strict {
+            Spec::parse_strict(&self.spec)
+        } else {
+            Spec::from_str(&self.spec)
+        }
+    }
 }
 
 // --- no-start, no-end ---
@@ -96,21 +204,120 @@ impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, NoStart, N
             bd_processor: bdp,
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
+            session_close: false,
+            strict: false,
             marker_sealed: PhantomData,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
-        let spec = Spec::from_str(&self.spec)?;
+        let spec = self.parse_spec()?;
         Ok(SpecIterator {
             tz: self.dtm.timezone(),
+            zone: spec.tz,
             dst_policy: self.dst_policy,
             naive_spec_iter: NaiveSpecIterator::new_after(
-                &spec.date_spec,
-                &spec.time_spec,
+                &spec,
                 self.bd_processor,
-                self.dtm.naive_local(),
-            )?,
+                local_in(&self.dtm, spec.tz),
+            )
+            .with_business_hours(self.business_hours)
+            .with_jitter(self.jitter)
+            .with_session_close(self.session_close),
+        })
+    }
+}
+
+// --- zone from the spec, no-start, no-end ---
+impl<BDP: BizDayProcessor> SpecIteratorBuilder<chrono_tz::Tz, BDP, NoStart, NoEnd, NotSealed> {
+    /// Create an iterator from `Utc::now()` in the zone named by the spec's
+    /// `@<IANA zone>` suffix, or in UTC when it has none. The current instant
+    /// is excluded; the first result is strictly after now.
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::WeekendSkipper;
+    /// use tkone_schedule::datetime::SpecIteratorBuilder;
+    /// use chrono::Timelike;
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// let spec = "YY-MM-DDT09:00:00@Europe/London"; // e.g. read from a config file
+    /// let mut iter = SpecIteratorBuilder::from_spec(spec, WeekendSkipper::new())
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let next = iter.next().unwrap().unwrap();
+    /// assert_eq!(next.observed().timezone(), chrono_tz::Europe::London);
+    /// assert_eq!(next.observed().hour(), 9);
+    /// ```
+    pub fn from_spec(spec: &str, bdp: BDP) -> Result<Self> {
+        let zone = Spec::from_str(spec)?.tz.unwrap_or(chrono_tz::UTC);
+        Ok(Self::new(spec, bdp, zone))
+    }
+}
+
+// --- backwards from a cursor ---
+impl<Tz: TimeZone, BDP: BizDayProcessor> SpecIteratorBuilder<Tz, BDP, Before, NoEnd, NotSealed> {
+    /// Create an iterator that walks **backwards** from `dtm`, yielding
+    /// occurrences strictly before it in descending order of their actual
+    /// (unadjusted) datetime.
+    ///
+    /// Each occurrence is exactly the one a forward iterator would yield:
+    /// business day adjustment, business hours, jitter and the DST policy all
+    /// apply unchanged. Iteration ends once no occurrence is found within
+    /// 1000 years of `dtm`.
+    ///
+    /// ```rust
+    /// use tkone_schedule::biz_day::WeekendSkipper;
+    /// use tkone_schedule::datetime::SpecIteratorBuilder;
+    /// use tkone_schedule::Occurrence;
+    /// use chrono::{TimeZone, Utc};
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// // The last two 09:00 and 17:00 runs before Monday 2024-06-17 10:00
+    /// let dtm = Utc.with_ymd_and_hms(2024, 6, 17, 10, 0, 0).unwrap();
+    /// let iter = SpecIteratorBuilder::new_before("YY-MM-DDT[09:00:00,17:00:00]", WeekendSkipper::new(), dtm)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let runs: Vec<_> = iter.take(3).collect().unwrap();
+    /// let at = |d, h| Occurrence::Exact(Utc.with_ymd_and_hms(2024, 6, d, h, 0, 0).unwrap());
+    /// assert_eq!(runs, vec![at(17, 9), at(16, 17), at(16, 9)]);
+    /// ```
+    pub fn new_before(spec: &str, bdp: BDP, dtm: DateTime<Tz>) -> Self {
+        SpecIteratorBuilder {
+            timezone: dtm.timezone(),
+            dtm: dtm.clone(),
+            start: Before,
+            spec: spec.to_string(),
+            bd_processor: bdp,
+            end: NoEnd,
+            dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
+            session_close: false,
+            strict: false,
+            marker_sealed: PhantomData,
+        }
+    }
+
+    pub fn build(self) -> Result<ReverseSpecIterator<Tz, BDP>> {
+        let spec = self.parse_spec()?;
+        let zone = spec.tz;
+        let rewind = DateTimeRewind {
+            period: Period::of(&spec.date_spec),
+            spec,
+            bdp: self.bd_processor,
+            business_hours: self.business_hours,
+            jitter: self.jitter,
+            session_close: self.session_close,
+        };
+        Ok(ReverseSpecIterator {
+            tz: self.dtm.timezone(),
+            zone,
+            dst_policy: self.dst_policy,
+            naive_spec_iter: NaiveReverse::new_before(rewind, local_in(&self.dtm, zone)),
         })
     }
 }
@@ -155,6 +362,10 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
             bd_processor: bdp,
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
+            session_close: false,
+            strict: false,
             marker_sealed: PhantomData,
         }
     }
@@ -172,22 +383,29 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
             bd_processor: self.bd_processor,
             end: EndDateTime(end),
             dst_policy: self.dst_policy,
+            business_hours: self.business_hours,
+            jitter: self.jitter,
+            session_close: self.session_close,
+            strict: self.strict,
             marker_sealed: PhantomData,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
-        let spec = Spec::from_str(&self.spec)?;
+        let spec = self.parse_spec()?;
         let start = self.start.0;
         Ok(SpecIterator {
             tz: start.timezone(),
+            zone: spec.tz,
             dst_policy: self.dst_policy,
             naive_spec_iter: NaiveSpecIterator::new_with_start(
-                &spec.date_spec,
-                &spec.time_spec,
+                &spec,
                 self.bd_processor,
-                start.naive_local(),
-            )?,
+                local_in(&start, spec.tz),
+            )
+            .with_business_hours(self.business_hours)
+            .with_jitter(self.jitter)
+            .with_session_close(self.session_close),
         })
     }
 }
@@ -197,18 +415,21 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
     SpecIteratorBuilder<Tz, BDP, StartDateTime<Tz>, EndDateTime<Tz>, Sealed>
 {
     pub fn build(self) -> Result<SpecIterator<Tz, BDP>> {
-        let spec = Spec::from_str(&self.spec)?;
+        let spec = self.parse_spec()?;
         let start = self.start.0;
         Ok(SpecIterator {
             tz: start.timezone(),
+            zone: spec.tz,
             dst_policy: self.dst_policy,
             naive_spec_iter: NaiveSpecIterator::new_with_end(
-                &spec.date_spec,
-                &spec.time_spec,
+                &spec,
                 self.bd_processor,
-                start.naive_local(),
-                self.end.0.naive_local(),
-            )?,
+                local_in(&start, spec.tz),
+                local_in(&self.end.0, spec.tz),
+            )
+            .with_business_hours(self.business_hours)
+            .with_jitter(self.jitter)
+            .with_session_close(self.session_close),
         })
     }
 }
@@ -219,10 +440,17 @@ impl<Tz: TimeZone, BDP: BizDayProcessor>
 
 /// Timezone-aware datetime recurrence iterator combining a date spec and a time spec.
 ///
+/// Occurrences come in order of their observed datetime: one that a business
+/// day adjustment or cut-off moves later is held back until the occurrences
+/// observed before it have been yielded.
+///
 /// Use [`SpecIteratorBuilder`] to construct one.
 #[derive(Debug)]
 pub struct SpecIterator<Tz: TimeZone, BDP: BizDayProcessor> {
     tz: Tz,
+    /// The zone named by the spec's `@` suffix, whose wall clock the naive
+    /// iterator runs on.
+    zone: Option<chrono_tz::Tz>,
     dst_policy: DstPolicy,
     naive_spec_iter: NaiveSpecIterator<BDP>,
 }
@@ -236,7 +464,81 @@ impl<Tz: TimeZone, BDP: BizDayProcessor + Clone> FallibleIterator for SpecIterat
         let Some(next) = next else {
             return Ok(None);
         };
-        Ok(Some(next_result_to_tz(&self.tz, next, self.dst_policy)?))
+        Ok(Some(next_result_in_zone(
+            &self.tz,
+            self.zone,
+            next,
+            self.dst_policy,
+        )?))
+    }
+}
+
+/// Timezone-aware datetime recurrence iterator that walks backwards, yielding
+/// occurrences in descending order of their actual datetime.
+///
+/// Use [`SpecIteratorBuilder::new_before`] to construct one.
+#[derive(Debug)]
+pub struct ReverseSpecIterator<Tz: TimeZone, BDP: BizDayProcessor> {
+    tz: Tz,
+    /// The zone named by the spec's `@` suffix, whose wall clock the naive
+    /// iterator runs on.
+    zone: Option<chrono_tz::Tz>,
+    dst_policy: DstPolicy,
+    naive_spec_iter: NaiveReverse<DateTimeRewind<BDP>>,
+}
+
+impl<Tz: TimeZone, BDP: BizDayProcessor> FallibleIterator for ReverseSpecIterator<Tz, BDP> {
+    type Item = Occurrence<DateTime<Tz>>;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        let next = self.naive_spec_iter.next()?;
+        let Some(next) = next else {
+            return Ok(None);
+        };
+        Ok(Some(next_result_in_zone(
+            &self.tz,
+            self.zone,
+            next,
+            self.dst_policy,
+        )?))
+    }
+}
+
+/// A datetime spec scanned backwards by [`NaiveReverse`], in windows that
+/// start at midnight so that every date is entered from its first time.
+#[derive(Debug, Clone)]
+struct DateTimeRewind<BDP: BizDayProcessor> {
+    spec: Spec,
+    bdp: BDP,
+    business_hours: Option<BusinessHours>,
+    jitter: Option<Jitter>,
+    session_close: bool,
+    period: Period,
+}
+
+impl<BDP: BizDayProcessor> Rewind for DateTimeRewind<BDP> {
+    type Item = Occurrence<NaiveDateTime>;
+    type Iter = NaiveSpecIterator<BDP>;
+
+    fn window_start(&self, dtm: NaiveDateTime, periods: u32) -> Result<Option<NaiveDateTime>> {
+        let midnight = dtm.date().and_time(NaiveTime::MIN);
+        let start = self.period.before(&self.bdp, midnight, periods)?;
+        Ok(start.map(|start| start.date().and_time(NaiveTime::MIN)))
+    }
+
+    fn iter_from(&self, start: NaiveDateTime, _scanned: NaiveDateTime) -> Result<Self::Iter> {
+        Ok(
+            NaiveSpecIterator::new_from_date(&self.spec, self.bdp.clone(), start.date())
+                .with_business_hours(self.business_hours.clone())
+                .with_jitter(self.jitter.clone())
+                .with_session_close(self.session_close)
+                .in_actual_order(),
+        )
+    }
+
+    fn key(item: &Self::Item) -> NaiveDateTime {
+        *item.actual()
     }
 }
 
@@ -258,7 +560,13 @@ impl<Tz: TimeZone, BDP: BizDayProcessor + Clone> FallibleIterator for SpecIterat
 #[derive(Debug, Clone)]
 pub struct NaiveSpecIterator<BDP: BizDayProcessor> {
     date_iter: DateNaiveSpecIterator<BDP>,
-    time_spec: TimeSpec,
+    time_specs: Vec<TimeSpec>,
+    /// Next time of each slot in `time_specs` within the current date window;
+    /// `None` once that slot has no more times today.
+    pending: Vec<Option<NaiveDateTime>>,
+    /// The adjusted date occurrence just entered; its adjustment is carried by
+    /// every time emitted on that date.
+    date_adjustment: Option<Occurrence<NaiveDateTime>>,
     /// Exclusive end of the current date window (next midnight after the date
     /// we are currently emitting times for).  `None` until the first date is
     /// entered.
@@ -283,22 +591,38 @@ pub struct NaiveSpecIterator<BDP: BizDayProcessor> {
     start: Option<NaiveDateTime>,
     end: Option<NaiveDateTime>,
     index: usize,
+    business_hours: Option<BusinessHours>,
+    /// Offset added to every observed time; zero without a jitter.
+    jitter: Duration,
+    /// Whether late occurrences are moved back to the session close.
+    session_close: bool,
+    /// Observed time of the last occurrence yielded, or the cursor of
+    /// [`new_after`](Self::new_after); an occurrence moved back to the session
+    /// close at or before it is dropped.
+    clamp_floor: Option<NaiveDateTime>,
+    /// Whether occurrences are yielded in order of observed rather than
+    /// actual time.
+    observed_order: bool,
+    /// Occurrences already produced but held back until no earlier observed
+    /// time can follow, sorted by observed then actual time.
+    deferred: Vec<Occurrence<NaiveDateTime>>,
 }
 
 impl<BDP: BizDayProcessor + Clone> NaiveSpecIterator<BDP> {
     /// Iterate occurrences strictly after `dtm`.
-    pub(crate) fn new_after(
-        date_spec: &str,
-        time_spec: &str,
-        bdp: BDP,
-        dtm: NaiveDateTime,
-    ) -> Result<Self> {
+    pub(crate) fn new_after(spec: &Spec, bdp: BDP, dtm: NaiveDateTime) -> Self {
         // Position the date iterator just before today so that today is a
         // candidate on the first `date_iter.next()` call.
         let before_today = dtm.date().and_hms_opt(0, 0, 0).unwrap() - Duration::seconds(1);
-        Ok(Self {
-            date_iter: DateNaiveSpecIterator::new_after(date_spec, bdp, before_today)?,
-            time_spec: time_spec.parse()?,
+        Self {
+            date_iter: DateNaiveSpecIterator::from_spec_after(
+                spec.date_spec.clone(),
+                bdp,
+                before_today,
+            ),
+            time_specs: spec.time_specs.clone(),
+            pending: Vec::new(),
+            date_adjustment: None,
             current_date_end: None,
             date_iter_started: false,
             next_period_cursor: None,
@@ -307,20 +631,27 @@ impl<BDP: BizDayProcessor + Clone> NaiveSpecIterator<BDP> {
             start: None,
             end: None,
             index: 0,
-        })
+            business_hours: None,
+            jitter: Duration::zero(),
+            session_close: false,
+            clamp_floor: Some(dtm),
+            observed_order: true,
+            deferred: Vec::new(),
+        }
     }
 
     /// Include `start` as the first result, then iterate forward.
-    pub(crate) fn new_with_start(
-        date_spec: &str,
-        time_spec: &str,
-        bdp: BDP,
-        start: NaiveDateTime,
-    ) -> Result<Self> {
+    pub(crate) fn new_with_start(spec: &Spec, bdp: BDP, start: NaiveDateTime) -> Self {
         let before_today = start.date().and_hms_opt(0, 0, 0).unwrap() - Duration::seconds(1);
-        Ok(Self {
-            date_iter: DateNaiveSpecIterator::new_after(date_spec, bdp, before_today)?,
-            time_spec: time_spec.parse()?,
+        Self {
+            date_iter: DateNaiveSpecIterator::from_spec_after(
+                spec.date_spec.clone(),
+                bdp,
+                before_today,
+            ),
+            time_specs: spec.time_specs.clone(),
+            pending: Vec::new(),
+            date_adjustment: None,
             current_date_end: None,
             date_iter_started: false,
             next_period_cursor: None,
@@ -329,20 +660,67 @@ impl<BDP: BizDayProcessor + Clone> NaiveSpecIterator<BDP> {
             start: Some(start),
             end: None,
             index: 0,
-        })
+            business_hours: None,
+            jitter: Duration::zero(),
+            session_close: false,
+            clamp_floor: None,
+            observed_order: true,
+            deferred: Vec::new(),
+        }
+    }
+
+    /// Iterate every occurrence from the start of `date`, including those at
+    /// midnight, as if iteration had begun on an earlier day.
+    fn new_from_date(spec: &Spec, bdp: BDP, date: NaiveDate) -> Self {
+        let midnight = date.and_time(NaiveTime::MIN);
+        let mut s = Self::new_after(spec, bdp, midnight);
+        // A known window end marks `date` as already entered, so its first
+        // time is not held to being strictly after the cursor.
+        s.current_date_end = Some(midnight);
+        s
     }
 
     /// Like [`new_with_start`] but stop at `end` (inclusive).
     pub(crate) fn new_with_end(
-        date_spec: &str,
-        time_spec: &str,
+        spec: &Spec,
         bdp: BDP,
         start: NaiveDateTime,
         end: NaiveDateTime,
-    ) -> Result<Self> {
-        let mut s = Self::new_with_start(date_spec, time_spec, bdp, start)?;
+    ) -> Self {
+        let mut s = Self::new_with_start(spec, bdp, start);
         s.end = Some(end);
-        Ok(s)
+        s
+    }
+
+    pub(crate) fn with_business_hours(mut self, hours: Option<BusinessHours>) -> Self {
+        self.business_hours = hours;
+        self
+    }
+
+    pub(crate) fn with_jitter(mut self, jitter: Option<Jitter>) -> Self {
+        if let Some(jitter) = jitter {
+            // Pinned times repeat with the dates, so they splay over the
+            // date cadence rather than the one second `spec_delta` gives them.
+            let period = self
+                .time_specs
+                .iter()
+                .filter(|slot| !is_single_time(slot))
+                .map(spec_delta)
+                .min();
+            self.jitter = jitter.offset(period.unwrap_or_else(|| self.date_iter.cadence()));
+        }
+        self
+    }
+
+    pub(crate) fn with_session_close(mut self, session_close: bool) -> Self {
+        self.session_close = session_close && self.time_specs.iter().all(is_single_time);
+        self
+    }
+
+    /// Yield occurrences in order of actual time, as a reverse scan needs.
+    fn in_actual_order(mut self) -> Self {
+        self.observed_order = false;
+        self
     }
 }
 
@@ -351,6 +729,73 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
     type Error = Error;
 
     fn next(&mut self) -> Result<Option<Self::Item>> {
+        let next = match self.business_hours.take() {
+            None => self.next_clamped()?,
+            Some(hours) => {
+                let next = hours.first_open(|| self.next_clamped(), |occ| *occ.observed());
+                self.business_hours = Some(hours);
+                next?
+            }
+        };
+        Ok(next.map(|occ| jitter_occurrence(occ, self.jitter)))
+    }
+}
+
+impl<BDP: BizDayProcessor + Clone> NaiveSpecIterator<BDP> {
+    /// The next occurrence, moved back to the session close when it is later.
+    ///
+    /// The start of a [`new_with_start`](Self::new_with_start) iterator is
+    /// yielded as given.
+    fn next_clamped(&mut self) -> Result<Option<Occurrence<NaiveDateTime>>> {
+        loop {
+            let next = self.next_ordered()?;
+            let Some(mut occ) = next else {
+                return Ok(None);
+            };
+            let observed = *occ.observed();
+            if self.session_close && Some(observed) != self.start {
+                if let Some(close) = self.date_iter.session(observed.date())?.close() {
+                    let close = observed.date().and_time(close);
+                    if observed > close {
+                        if self.clamp_floor.is_some_and(|floor| close <= floor) {
+                            continue;
+                        }
+                        // Shift the observed time as a jitter would, keeping `actual`.
+                        occ = jitter_occurrence(occ, close - observed);
+                    }
+                }
+            }
+            self.clamp_floor = Some(*occ.observed());
+            return Ok(Some(occ));
+        }
+    }
+
+    /// The next occurrence in order of observed time.
+    ///
+    /// The date and time specs produce occurrences in order of actual time. One
+    /// that a business day adjustment moves later is held back until an
+    /// occurrence with a later actual time, which no forward adjustment can
+    /// observe any earlier, has been produced.
+    fn next_ordered(&mut self) -> Result<Option<Occurrence<NaiveDateTime>>> {
+        if !self.observed_order {
+            return self.next_unfiltered();
+        }
+        let key = |occ: &Occurrence<NaiveDateTime>| (*occ.observed(), *occ.actual());
+        loop {
+            let next = self.next_unfiltered()?;
+            let Some(occ) = next else {
+                return Ok((!self.deferred.is_empty()).then(|| self.deferred.remove(0)));
+            };
+            let bound = *occ.actual().min(occ.observed());
+            let at = self.deferred.partition_point(|held| key(held) <= key(&occ));
+            self.deferred.insert(at, occ);
+            if *self.deferred[0].observed() <= bound {
+                return Ok(Some(self.deferred.remove(0)));
+            }
+        }
+    }
+
+    fn next_unfiltered(&mut self) -> Result<Option<Occurrence<NaiveDateTime>>> {
         // ── global end guard ──────────────────────────────────────────────
         if let Some(end) = self.end {
             if self.dtm >= end {
@@ -364,7 +809,14 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
                 if self.dtm <= start {
                     self.dtm = start;
                     self.index += 1;
-                    self.current_date_end = Some(midnight_next(start.date()));
+                    let date_end = midnight_next(start.date());
+                    self.current_date_end = Some(date_end);
+                    self.date_adjustment = None;
+                    self.pending = self
+                        .time_specs
+                        .iter()
+                        .map(|slot| next_slot_time(slot, start, date_end))
+                        .collect();
                     // Prime the date iterator so that when the time loop for
                     // this day exhausts, update_cursor advances past start's
                     // date rather than re-emitting it from midnight.
@@ -376,18 +828,13 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
         }
 
         // ── try next time within the current date window ──────────────────
-        if let Some(date_end) = self.current_date_end {
-            let candidate = apply_time_spec(&self.time_spec, self.dtm);
-            if candidate > self.dtm && candidate < date_end {
-                if let Some(end) = self.end {
-                    if candidate > end {
-                        return Ok(None);
-                    }
+        if let Some(candidate) = self.pending.iter().flatten().min().copied() {
+            if let Some(end) = self.end {
+                if candidate > end {
+                    return Ok(None);
                 }
-                self.dtm = candidate;
-                self.index += 1;
-                return Ok(Some(Occurrence::Exact(candidate)));
             }
+            return self.emit(candidate).map(Some);
         }
 
         // ── advance to the next valid date ────────────────────────────────
@@ -417,6 +864,13 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             let Some(next_date) = next_date else {
                 return Ok(None);
             };
+            // Resolve the date's adjustment at midnight rather than at the
+            // date iterator's cursor time, so a processor with an intra-day
+            // cut-off only moves the times that are actually late (see `emit`).
+            let next_date = self
+                .date_iter
+                .adjusted_at(NaiveTime::MIN)?
+                .unwrap_or(next_date);
             self.date_iter_started = true;
 
             let observed_date = next_date.observed().date();
@@ -441,34 +895,42 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
             // (e.g. `1H:00:00` → back 1 h → 23:00 → +1 h → 00:00).  If the
             // candidate falls before midnight (e.g. an `At`-only spec such as
             // `11:00:00` gives `11:00` of the previous day), fall back to
-            // applying the spec from midnight itself.
-            let first_time = if is_initial_day {
-                apply_time_spec(&self.time_spec, self.initial_dtm)
-            } else {
-                let delta = spec_delta(&self.time_spec);
-                let candidate = apply_time_spec(&self.time_spec, date_midnight - delta);
-                if candidate >= date_midnight {
-                    candidate
-                } else {
-                    apply_time_spec(&self.time_spec, date_midnight)
-                }
-            };
-
-            let is_valid = if is_initial_day {
-                // Must be strictly after the initial cursor and within today
-                first_time > self.initial_dtm && first_time < date_end
-            } else {
-                // Must be within the day window (>= midnight is guaranteed;
-                // only fail if Every-cycle pushes past midnight)
-                first_time < date_end
-            };
-
-            if !is_valid {
+            // applying the spec from midnight itself.  A day-long interval
+            // (`24H:00:00`) also lands on midnight, so the cadence restarts on
+            // every date rather than carrying over from the previous one.
+            let pending: Vec<_> = self
+                .time_specs
+                .iter()
+                .map(|slot| {
+                    let first_time = if is_initial_day {
+                        apply_time_spec(slot, self.initial_dtm)
+                    } else {
+                        let candidate = date_midnight
+                            .checked_sub_signed(spec_delta(slot))
+                            .map(|from| apply_time_spec(slot, from));
+                        match candidate {
+                            Some(candidate) if candidate >= date_midnight => candidate,
+                            _ => apply_time_spec(slot, date_midnight),
+                        }
+                    };
+                    let is_valid = if is_initial_day {
+                        // Must be strictly after the initial cursor and within today
+                        first_time > self.initial_dtm && first_time < date_end
+                    } else {
+                        // Must be within the day window (>= midnight is guaranteed;
+                        // only fail if Every-cycle pushes past midnight)
+                        first_time < date_end
+                    };
+                    is_valid.then_some(first_time)
+                })
+                .collect();
+
+            let Some(first_time) = pending.iter().flatten().min().copied() else {
                 // No eligible time on this date — skip it and try the next.
                 self.current_date_end = Some(date_end);
                 self.date_iter.update_cursor(next_period_cursor);
                 continue;
-            }
+            };
 
             // End-of-range check
             if let Some(end) = self.end {
@@ -479,23 +941,44 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
 
             self.current_date_end = Some(date_end);
             self.next_period_cursor = Some(next_period_cursor);
-            self.dtm = first_time;
-            self.index += 1;
-
-            // Propagate business-day adjustment info from the date result.
-            let result = match next_date {
-                Occurrence::Exact(_) => Occurrence::Exact(first_time),
-                Occurrence::AdjustedEarlier(actual, _) => Occurrence::AdjustedEarlier(
-                    actual.date().and_time(first_time.time()),
-                    first_time,
-                ),
-                Occurrence::AdjustedLater(actual, _) => {
-                    Occurrence::AdjustedLater(actual.date().and_time(first_time.time()), first_time)
-                }
-            };
+            self.date_adjustment = Some(next_date);
+            self.pending = pending;
+            return self.emit(first_time).map(Some);
+        }
+    }
 
-            return Ok(Some(result));
+    /// Emit `time`, the earliest pending slot time: advance every slot due at
+    /// `time` and, on an adjusted date, carry the adjustment.
+    ///
+    /// A time that the business day processor settles on another date once
+    /// its time of day is known (an intra-day cut-off) is reported with that
+    /// date instead.
+    fn emit(&mut self, time: NaiveDateTime) -> Result<Occurrence<NaiveDateTime>> {
+        let date_end = self.current_date_end.unwrap_or(NaiveDateTime::MAX);
+        for (slot, pending) in self.time_specs.iter().zip(&mut self.pending) {
+            if *pending == Some(time) {
+                *pending = next_slot_time(slot, time, date_end);
+            }
         }
+        self.dtm = time;
+        self.index += 1;
+
+        if let Some(at_time) = self.date_iter.adjusted_at(time.time())? {
+            if at_time.observed().date() != time.date() {
+                return Ok(at_time);
+            }
+        }
+
+        // Propagate business-day adjustment info from the date result.
+        Ok(match &self.date_adjustment {
+            Some(Occurrence::AdjustedEarlier(actual, _)) => {
+                Occurrence::AdjustedEarlier(actual.date().and_time(time.time()), time)
+            }
+            Some(Occurrence::AdjustedLater(actual, _)) => {
+                Occurrence::AdjustedLater(actual.date().and_time(time.time()), time)
+            }
+            _ => Occurrence::Exact(time),
+        })
     }
 }
 
@@ -503,77 +986,17 @@ impl<BDP: BizDayProcessor + Clone> FallibleIterator for NaiveSpecIterator<BDP> {
 // Helpers
 // ---------------------------------------------------------------------------
 
-/// Apply a time spec to a cursor datetime, mirroring the time iterator logic:
-/// seconds → minutes → hours, each either `At(n)` (set absolute) or `Every(n)` (add delta).
-/// `ForEach` on the finest non-At component acts as `Every(1)` when no explicit `Every` exists.
-/// `AsIs` is a true no-op — it always carries the current value.
-fn apply_time_spec(spec: &TimeSpec, cursor: NaiveDateTime) -> NaiveDateTime {
-    let has_any_every = matches!(spec.seconds, TimeCycle::Every(_))
-        || matches!(spec.minutes, TimeCycle::Every(_))
-        || matches!(spec.hours, TimeCycle::Every(_));
-    let seconds_is_foreach = matches!(spec.seconds, TimeCycle::ForEach);
-    let minutes_is_foreach = matches!(spec.minutes, TimeCycle::ForEach);
-
-    let next = cursor;
-    let next = match &spec.seconds {
-        TimeCycle::At(s) => next.with_second(*s as u32).unwrap(),
-        TimeCycle::Every(s) => next + Duration::seconds(*s as i64),
-        TimeCycle::ForEach if !has_any_every => next + Duration::seconds(1),
-        TimeCycle::ForEach | TimeCycle::AsIs => next,
-    };
-    let next = match &spec.minutes {
-        TimeCycle::At(m) => next.with_minute(*m as u32).unwrap(),
-        TimeCycle::Every(m) => next + Duration::minutes(*m as i64),
-        TimeCycle::ForEach if !has_any_every && !seconds_is_foreach => next + Duration::minutes(1),
-        TimeCycle::ForEach | TimeCycle::AsIs => next,
-    };
-    match &spec.hours {
-        TimeCycle::At(h) => next.with_hour(*h as u32).unwrap(),
-        TimeCycle::Every(h) => next + Duration::hours(*h as i64),
-        TimeCycle::ForEach if !has_any_every && !seconds_is_foreach && !minutes_is_foreach => {
-            next + Duration::hours(1)
-        }
-        TimeCycle::ForEach | TimeCycle::AsIs => next,
-    }
-}
-
-/// Return the natural step size of the driving component of a time spec.
-///
-/// Used by the new-day first-tick calculation: stepping back one period from
-/// midnight and applying the spec once produces midnight itself whenever
-/// midnight is a natural boundary of the cycle.
-///
-/// Rules (coarsest-to-finest, first match wins):
-/// - `Every(n)` on seconds  → `n` seconds
-/// - `ForEach` on seconds (and no `Every` anywhere) → 1 second
-/// - `Every(n)` on minutes  → `n` minutes
-/// - `ForEach` on minutes (and no `Every` anywhere) → 1 minute
-/// - `Every(n)` on hours    → `n` hours
-/// - `ForEach` on hours (and no `Every` anywhere) → 1 hour
-/// - All `At` / `AsIs`      → 1 second (safe fallback; result will be < midnight,
-///                             triggering the `apply_time_spec(midnight)` fallback)
-fn spec_delta(spec: &TimeSpec) -> Duration {
-    let has_any_every = matches!(spec.seconds, TimeCycle::Every(_))
-        || matches!(spec.minutes, TimeCycle::Every(_))
-        || matches!(spec.hours, TimeCycle::Every(_));
-
-    match &spec.seconds {
-        TimeCycle::Every(n) => Duration::seconds(*n as i64),
-        TimeCycle::ForEach if !has_any_every => Duration::seconds(1),
-        _ => match &spec.minutes {
-            TimeCycle::Every(n) => Duration::minutes(*n as i64),
-            TimeCycle::ForEach if !has_any_every => Duration::minutes(1),
-            _ => match &spec.hours {
-                TimeCycle::Every(n) => Duration::hours(*n as i64),
-                TimeCycle::ForEach if !has_any_every => Duration::hours(1),
-                _ => Duration::seconds(1),
-            },
-        },
-    }
+/// The next time of `slot` strictly after `dtm` and before `date_end`.
+fn next_slot_time(
+    slot: &TimeSpec,
+    dtm: NaiveDateTime,
+    date_end: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let candidate = apply_time_spec(slot, dtm);
+    (candidate > dtm && candidate < date_end).then_some(candidate)
 }
 
 /// Returns midnight at the start of the *next* day after `date`.
 fn midnight_next(date: NaiveDate) -> NaiveDateTime {
     (date + Duration::days(1)).and_hms_opt(0, 0, 0).unwrap()
 }
-
@@ -8,12 +8,17 @@
 //! ## Spec format
 //!
 //! ```text
-//! <date_spec>T<time_spec>
+//! <date_spec>T<time_spec>[@<IANA zone>]
 //! ```
 //!
 //! The `T` separator is recognised by the pattern that **immediately follows**
-//! it (`HH:`, `nH:`, or a two-digit clock hour `dd:`), so weekday tokens such
-//! as `TUE` and `THU` in the date part are never misidentified as separators.
+//! it (`HH:`, `nH:`, a two-digit clock hour `dd:`, an hour list `[dd,..]:` or
+//! an hour range `dd-dd:`), so weekday tokens such as `TUE` and `THU` in the
+//! date part are never misidentified as separators. An optional `@` suffix such
+//! as `@Europe/London` names the timezone the schedule runs in.
+//!
+//! Several time slots can share one date spec as a bracketed list,
+//! `T[09:00:00,16:30:00]`; every slot fires on each valid date, in time order.
 //!
 //! ## Examples
 //!
@@ -23,6 +28,8 @@
 //! | `"YY-MM-DDTHH:30M:00"` | Every day, every 30 minutes |
 //! | `"YY-MM-FRI#LT16:30:00"` | Last Friday of each month at 16:30 |
 //! | `"YY-MM-THUT09:30:00"` | Every Thursday at 09:30 |
+//! | `"YY-MM-DDT09:00:00@Europe/London"` | Every day at 09:00 London time |
+//! | `"YY-MM-DD~NBT[09:00:00,16:30:00]"` | Every business day at 09:00 and 16:30 |
 //!
 //! ## Quick start
 //!
@@ -54,5 +61,5 @@ mod spec;
 #[cfg(test)]
 mod tests;
 
-pub use iter::{NaiveSpecIterator, SpecIterator, SpecIteratorBuilder};
+pub use iter::{NaiveSpecIterator, ReverseSpecIterator, SpecIterator, SpecIteratorBuilder};
 pub use spec::Spec;
@@ -1,46 +1,243 @@
 use regex::Regex;
+use std::fmt;
+use std::ops::Range;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
+use crate::date::{validate_strict, Spec as DateSpec};
 use crate::prelude::*;
+use crate::time::{steps_elapsed, Spec as TimeSpec};
+use crate::utils::split_zone;
 
 /// Matches the `T` separator between a date spec and a time spec.
-/// The time spec always starts with: `HH:`, `<n>H:`, or a two-digit hour `<dd>:`.
+/// The time spec always starts with: `HH:`, `<n>H:`, a two-digit hour `<dd>:`, an hour list
+/// `[<dd>,...]:` or an hour range `<dd>-<dd>:`, optionally behind the `[` of a slot list.
 /// This deliberately does NOT match `T` inside weekday names like `TUE` or `THU`.
-static DATE_TIME_SEP: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"T(?:HH|_|[0-9]{1,2}H|[0-2]?[0-9]):").unwrap());
+static DATE_TIME_SEP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"T\[?(?:HH|_|[0-9]{1,2}H|[0-2]?[0-9]|\[[0-9,]+\]|[0-2]?[0-9]-[0-2]?[0-9]):")
+        .unwrap()
+});
 
 /// Combined date + time recurrence specification.
 ///
-/// Format: `<date_spec>T<time_spec>`
+/// Format: `<date_spec>T<time_spec>[@<IANA zone>]`, where `<time_spec>` may be a
+/// bracketed list of slots, `[<time_spec>,<time_spec>,…]`, all fired on each date.
+///
+/// Both halves are parsed when the spec is, so a malformed spec fails at
+/// `parse()` time with [`Error::InvalidSpecComponent`] naming the half and its
+/// byte span. [`Display`](fmt::Display) writes the canonical form, which
+/// parses back to an equal spec.
 ///
 /// ## Examples
 /// - `"YY-1M-31L~WT11:00:00"` — last day of each month adjusted to nearest weekday at 11:00
 /// - `"YY-MM-MONT1H:00:00"` — every Monday, every hour
 /// - `"YY-MM-FRI#LT16:30:00"` — last Friday of each month at 16:30
-#[derive(Debug, Clone)]
+/// - `"YY-MM-DDT09:00:00@Europe/London"` — every day at 09:00 London time
+/// - `"YY-MM-DD~NBT[09:00:00,16:30:00]"` — every business day at 09:00 and 16:30
+///
+/// ```rust
+/// use tkone_schedule::datetime::Spec;
+/// use tkone_schedule::{Error, SpecComponent};
+/// use std::collections::HashSet;
+///
+/// let spec: Spec = "YY-MM-FRI#LT16:30:00".parse().unwrap();
+/// assert_eq!(spec.time_specs[0].to_string(), "16:30:00");
+/// assert_eq!(spec.to_string(), "YY-MM-FRI#LT16:30:00");
+///
+/// // Equal specs de-duplicate
+/// let specs: HashSet<Spec> = ["YY-MM-1WD EXCEPT YY-MM-01T09:00:00", "YY-MM-1WD!YY-MM-01T09:00:00"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// assert_eq!(specs.len(), 1);
+///
+/// match "YY-MM-DDT09:XX:00".parse::<Spec>() {
+///     Err(Error::InvalidSpecComponent { component, span, .. }) => {
+///         assert_eq!(component, SpecComponent::TimeSpec);
+///         assert_eq!(span, 9..17);
+///     }
+///     other => panic!("unexpected {other:?}"),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Spec {
-    pub date_spec: String,
-    pub time_spec: String,
+    pub date_spec: DateSpec,
+    /// The time-of-day slots, sorted into a canonical order without
+    /// duplicates; never empty. Their own [`tz`](TimeSpec::tz) is always
+    /// `None`.
+    pub time_specs: Vec<TimeSpec>,
+    /// The zone named by an `@<IANA zone>` suffix; see [`TimeSpec::tz`].
+    pub tz: Option<chrono_tz::Tz>,
 }
 
-impl FromStr for Spec {
-    type Err = Error;
+impl Spec {
+    /// Parse a datetime spec, also rejecting a date half that is well-formed
+    /// but can never match, as [`parse_spec_strict`] does for date specs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSpecComponent`] naming the offending date
+    /// component, with its byte span within `s`, or the errors of
+    /// [`from_str`](FromStr::from_str).
+    ///
+    /// ```rust
+    /// use tkone_schedule::datetime::Spec;
+    /// use tkone_schedule::{Error, SpecComponent};
+    ///
+    /// assert!("2025-D366T09:00:00".parse::<Spec>().is_ok());
+    /// match Spec::parse_strict("2025-D366T09:00:00") {
+    ///     Err(Error::InvalidSpecComponent { component, span, .. }) => {
+    ///         assert_eq!(component, SpecComponent::Day);
+    ///         assert_eq!(span, 6..9);
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    /// ```
+    ///
+    /// [`parse_spec_strict`]: crate::date::parse_spec_strict
+    pub fn parse_strict(s: &str) -> Result<Self> {
+        Self::parse(s, true)
+    }
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn parse(s: &str, strict: bool) -> Result<Self> {
+        let (s, tz) = split_zone(s)?;
         let sep = DATE_TIME_SEP
             .find(s)
             .ok_or_else(|| Error::InvalidDateTimeSpec(format!("missing T separator: {s}")))?;
-        let date_spec = s[..sep.start()].to_string();
         // +1 to skip the 'T' itself; the rest is the time spec
-        let time_spec = s[sep.start() + 1..].to_string();
+        let (date_span, time_span) = (0..sep.start(), sep.start() + 1..s.len());
+        let date_spec = s[date_span.clone()]
+            .parse::<DateSpec>()
+            .map_err(|err| invalid_half(err, SpecComponent::DateSpec, s, date_span.clone()))?;
+        if strict {
+            validate_strict(&s[date_span.clone()], date_span.start)?;
+        }
+        let mut time_specs = slot_spans(s, time_span)
+            .into_iter()
+            .map(|span| {
+                let slot = s[span.clone()]
+                    .parse::<TimeSpec>()
+                    .map_err(|err| invalid_half(err, SpecComponent::TimeSpec, s, span.clone()))?;
+                // Every date restarts the time cadence on its wall clock, so an
+                // interval longer than its unit cannot run in elapsed time as
+                // it does in a time spec.
+                if steps_elapsed(&slot) {
+                    return Err(Error::InvalidSpecComponent {
+                        component: SpecComponent::TimeSpec,
+                        value: s[span.clone()].to_string(),
+                        span,
+                        expected:
+                            "an interval within its unit (at most `24H`, `60M`, `60S`, `1000MS`)"
+                                .to_string(),
+                    });
+                }
+                Ok(slot)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Slot order has no effect on iteration, so equal slot sets compare
+        // and hash equal.
+        time_specs.sort_by_cached_key(|slot| slot.to_string());
+        time_specs.dedup();
         Ok(Spec {
             date_spec,
-            time_spec,
+            time_specs,
+            tz,
         })
     }
 }
 
+impl FromStr for Spec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, false)
+    }
+}
+
+/// Splits the time half of `s` at `span` into the spans of its slots: the
+/// entries of a `[<time>,<time>,…]` list, or the whole half otherwise.
+///
+/// A leading `[` only opens a slot list when its matching `]` ends the half,
+/// so an hour list such as `[09,13]:00:00` stays a single slot.
+fn slot_spans(s: &str, span: Range<usize>) -> Vec<Range<usize>> {
+    let text = &s[span.clone()];
+    let mut depth = 0;
+    let mut close = None;
+    let mut commas = Vec::new();
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 && close.is_none() {
+                    close = Some(i);
+                }
+            }
+            ',' if depth == 1 => commas.push(i),
+            _ => {}
+        }
+    }
+    if !text.starts_with('[') || close != Some(text.len() - 1) {
+        return vec![span];
+    }
+    let bounds = std::iter::once(0).chain(commas.iter().copied());
+    let ends = commas
+        .iter()
+        .copied()
+        .chain(std::iter::once(text.len() - 1));
+    bounds
+        .zip(ends)
+        .map(|(from, to)| span.start + from + 1..span.start + to)
+        .collect()
+}
+
+/// The error for a date or time half of `s`, at `span`, that failed to parse
+/// with `err`.
+///
+/// An inner [`Error::InvalidSpecComponent`] keeps its component, with its span
+/// shifted to be relative to `s`; any other error names the whole half.
+fn invalid_half(err: Error, component: SpecComponent, s: &str, span: Range<usize>) -> Error {
+    if let Error::InvalidSpecComponent {
+        component,
+        span: inner,
+        value,
+        expected,
+    } = err
+    {
+        return Error::InvalidSpecComponent {
+            component,
+            span: span.start + inner.start..span.start + inner.end,
+            value,
+            expected,
+        };
+    }
+    let expected = match component {
+        SpecComponent::TimeSpec => "a time spec such as `HH:MM:SS`",
+        _ => "a date spec such as `YY-MM-DD`",
+    };
+    Error::InvalidSpecComponent {
+        component,
+        value: s[span.clone()].to_string(),
+        span,
+        expected: expected.to_string(),
+    }
+}
+
+impl fmt::Display for Spec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T", self.date_spec)?;
+        let slots: Vec<String> = self.time_specs.iter().map(|t| t.to_string()).collect();
+        match slots.as_slice() {
+            [slot] => write!(f, "{slot}")?,
+            _ => write!(f, "[{}]", slots.join(","))?,
+        }
+        if let Some(tz) = self.tz {
+            write!(f, "@{}", tz.name())?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,33 +245,177 @@ mod tests {
     #[test]
     fn test_compact_at_time() {
         let spec = "YY-1M-31L~WT11:00:00".parse::<Spec>().unwrap();
-        assert_eq!(spec.date_spec, "YY-1M-31L~W");
-        assert_eq!(spec.time_spec, "11:00:00");
+        assert_eq!(spec.date_spec.to_string(), "YY-1M-31L~W");
+        assert_eq!(spec.time_specs[0].to_string(), "11:00:00");
     }
 
     #[test]
     fn test_every_hour() {
         let spec = "YY-MM-DDT1H:00:00".parse::<Spec>().unwrap();
-        assert_eq!(spec.date_spec, "YY-MM-DD");
-        assert_eq!(spec.time_spec, "1H:00:00");
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-DD");
+        assert_eq!(spec.time_specs[0].to_string(), "1H:00:00");
     }
 
     #[test]
     fn test_weekday_not_confused_with_separator() {
         // THU in day spec should not be confused with the T separator
         let spec = "YY-MM-THUT11:00:00".parse::<Spec>().unwrap();
-        assert_eq!(spec.date_spec, "YY-MM-THU");
-        assert_eq!(spec.time_spec, "11:00:00");
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-THU");
+        assert_eq!(spec.time_specs[0].to_string(), "11:00:00");
+
+        let spec = "YY-MM-TUET11:00:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-TUE");
+        assert_eq!(spec.time_specs[0].to_string(), "11:00:00");
+    }
+
+    #[test]
+    fn test_hour_list_and_range_time_spec() {
+        let spec = "YY-MM-DDT[09,13,17]:00:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-DD");
+        assert_eq!(spec.time_specs[0].to_string(), "[09,13,17]:00:00");
+
+        let spec = "YY-MM-[MON,FRI]T09-17:30M:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-[MON,FRI]");
+        assert_eq!(spec.time_specs[0].to_string(), "09-17:30M:00");
+    }
+
+    #[test]
+    fn test_time_slots() {
+        let spec = "YY-MM-DD~NBT[09:00:00,16:30:00]".parse::<Spec>().unwrap();
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-DD~NB");
+        let slots: Vec<String> = spec.time_specs.iter().map(|t| t.to_string()).collect();
+        assert_eq!(slots, ["09:00:00", "16:30:00"]);
+
+        // Slots may use lists of their own; an hour list alone is one slot
+        let spec = "YY-MM-DDT[[09,13]:00:00,HH:30M:00.[000,500]]"
+            .parse::<Spec>()
+            .unwrap();
+        let slots: Vec<String> = spec.time_specs.iter().map(|t| t.to_string()).collect();
+        assert_eq!(slots, ["HH:30M:00.[000,500]", "[09,13]:00:00"]);
+        let spec = "YY-MM-DDT[09,13]:00:[00,30]".parse::<Spec>().unwrap();
+        assert_eq!(spec.time_specs.len(), 1);
+
+        // Slot order and repeats do not matter
+        let spec = "YY-MM-DDT[16:00:00,09:00:00,16:00:00]"
+            .parse::<Spec>()
+            .unwrap();
+        assert_eq!(
+            spec,
+            "YY-MM-DDT[09:00:00,16:00:00]".parse::<Spec>().unwrap()
+        );
+        assert_eq!(spec.to_string(), "YY-MM-DDT[09:00:00,16:00:00]");
+
+        let err = "YY-MM-DDT[09:00:00,16:XX:00]".parse::<Spec>().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidSpecComponent { component: SpecComponent::TimeSpec, ref span, .. }
+                if *span == (19..27)
+        ));
+    }
+
+    #[test]
+    fn test_zone_suffix() {
+        let spec = "YY-MM-DDT09:00:00@Europe/London".parse::<Spec>().unwrap();
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-DD");
+        assert_eq!(spec.time_specs[0].to_string(), "09:00:00");
+        assert_eq!(spec.tz, Some(chrono_tz::Europe::London));
 
         let spec = "YY-MM-TUET11:00:00".parse::<Spec>().unwrap();
-        assert_eq!(spec.date_spec, "YY-MM-TUE");
-        assert_eq!(spec.time_spec, "11:00:00");
+        assert_eq!(spec.tz, None);
+
+        assert!(matches!(
+            "YY-MM-DDT09:00:00@Europe/Nowhere".parse::<Spec>(),
+            Err(Error::InvalidTimezone(_))
+        ));
     }
 
     #[test]
     fn test_hh_time_spec() {
         let spec = "YY-MM-DDTHH:30M:00".parse::<Spec>().unwrap();
-        assert_eq!(spec.date_spec, "YY-MM-DD");
-        assert_eq!(spec.time_spec, "HH:30M:00");
+        assert_eq!(spec.date_spec.to_string(), "YY-MM-DD");
+        assert_eq!(spec.time_specs[0].to_string(), "HH:30M:00");
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for s in [
+            "YY-1M-31L~WT11:00:00",
+            "YY-MM-FRI#LT16:30:00",
+            "YY-MM-[MON,FRI]T09-17:30M:00",
+            "YY-MM-1WD!YY-MM-01T[09,13]:00:00.500",
+            "YY-MM-DDT09:00:00@Europe/London",
+            "YY-MM-DD~NBT[09:00:00,16:30:00]@America/New_York",
+        ] {
+            let spec = s.parse::<Spec>().unwrap();
+            assert_eq!(spec.to_string(), s);
+            assert_eq!(spec.to_string().parse::<Spec>().unwrap(), spec);
+        }
+
+        let spec = "YY-MM-1WD EXCEPT YY-MM-01T09:00:00"
+            .parse::<Spec>()
+            .unwrap();
+        assert_eq!(spec.to_string(), "YY-MM-1WD!YY-MM-01T09:00:00");
+    }
+
+    #[test]
+    fn test_invalid_halves_report_span() {
+        let err = "YY-MM-XXT09:00:00".parse::<Spec>().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidSpecComponent { component: SpecComponent::DateSpec, ref span, ref value, .. }
+                if *span == (0..8) && value == "YY-MM-XX"
+        ));
+
+        let err = "YY-MM-DDT09:XX:00@Europe/London"
+            .parse::<Spec>()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidSpecComponent { component: SpecComponent::TimeSpec, ref span, ref value, .. }
+                if *span == (9..17) && value == "09:XX:00"
+        ));
+
+        assert!(matches!(
+            "YY-MM-DD".parse::<Spec>(),
+            Err(Error::InvalidDateTimeSpec(_))
+        ));
+    }
+
+    #[test]
+    fn test_intervals_longer_than_their_unit_are_parse_errors() {
+        for (s, slot) in [
+            ("YY-MM-DDT36H:00:00", 9..18),
+            ("YY-MM-DDTHH:90M:00", 9..18),
+            ("YY-MM-DDT[09:00:00,HH:MM:61S]", 19..28),
+        ] {
+            let err = s.parse::<Spec>().unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    Error::InvalidSpecComponent { component: SpecComponent::TimeSpec, ref span, .. }
+                        if *span == slot
+                ),
+                "{s}: {err:?}"
+            );
+        }
+        for s in [
+            "YY-MM-DDT24H:00:00",
+            "YY-MM-DDTHH:60M:00",
+            "YY-MM-DDTHH:MM:SS.1000MS",
+        ] {
+            assert!(s.parse::<Spec>().is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_inner_span_is_offset_by_half() {
+        let s = "YY-MM-DDT[09:00:00,YY-13-01]";
+        let inner = crate::date::parse_spec_strict("YY-13-01").unwrap_err();
+        let err = invalid_half(inner, SpecComponent::TimeSpec, s, 19..27);
+        assert!(matches!(
+            err,
+            Error::InvalidSpecComponent { component: SpecComponent::Month, ref span, ref value, .. }
+                if *span == (22..24) && value == "13" && &s[span.clone()] == "13"
+        ));
     }
 }
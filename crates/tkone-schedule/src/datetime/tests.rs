@@ -1,7 +1,9 @@
-use crate::biz_day::WeekendSkipper;
+use crate::biz_day::{CutOff, WeekendSkipper};
 use crate::datetime::SpecIteratorBuilder;
 use crate::Occurrence;
-use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Timelike, Utc, Weekday,
+};
 use fallible_iterator::FallibleIterator;
 
 /// Convenience alias — reduces line noise in assertions.
@@ -454,12 +456,12 @@ fn test_31n_overflow_fixed_time() {
     assert_eq!(results[3].observed().hour(), 11);
 }
 
-/// Spec 12: `AdjustedLater` appears on the first intra-day tick only
+/// Spec 12: `AdjustedLater` appears on every intra-day tick
 ///
-/// When May 31 (Sat) adjusts to Jun 2 (Mon), only the 00:00 tick on Jun 2 is
-/// `AdjustedLater`; all subsequent 30-minute ticks on Jun 2 are `Single`.
+/// When May 31 (Sat) adjusts to Jun 2 (Mon), every 30-minute tick on Jun 2 is
+/// `AdjustedLater`, its actual time being the same time on May 31.
 #[test]
-fn test_adjusted_later_only_on_first_intraday_tick() {
+fn test_adjusted_later_on_every_intraday_tick() {
     let tz = Utc;
     // Apr 30 → last biz day of Apr (Single); May 31 Sat → Jun 2 Mon (AdjustedLater)
     let start = tz.with_ymd_and_hms(2025, 4, 30, 11, 0, 0).unwrap();
@@ -481,22 +483,21 @@ fn test_adjusted_later_only_on_first_intraday_tick() {
         .collect();
     assert!(!jun2.is_empty(), "expected ticks on Jun 2");
 
-    // First tick on Jun 2 is AdjustedLater (actual = May, observed = Jun 2)
-    assert!(
-        matches!(jun2[0], Occurrence::AdjustedLater(_, _)),
-        "first tick on Jun 2 must be AdjustedLater"
-    );
-    assert_eq!(jun2[0].actual().month(), 5, "actual month should be May");
     assert_eq!(jun2[0].observed().hour(), 0);
 
-    // All subsequent ticks on Jun 2 are Single
-    for tick in jun2.iter().skip(1) {
+    // Every tick on Jun 2 is AdjustedLater (actual = May 31, observed = Jun 2)
+    for tick in &jun2 {
         assert!(
-            matches!(tick, Occurrence::Exact(_)),
-            "subsequent ticks on Jun 2 must be Single, got {:?} at {:?}",
+            matches!(tick, Occurrence::AdjustedLater(_, _)),
+            "ticks on Jun 2 must be AdjustedLater, got {:?} at {:?}",
             tick,
             tick.observed().time()
         );
+        assert_eq!(
+            tick.actual().date_naive(),
+            NaiveDate::from_ymd_opt(2025, 5, 31).unwrap()
+        );
+        assert_eq!(tick.actual().time(), tick.observed().time());
     }
 
     // Consecutive Jun 2 ticks are 30 min apart
@@ -816,3 +817,605 @@ fn test_ny_timezone_last_biz_day_11am() {
         assert_eq!(r.observed().hour(), 11, "all ticks should be at 11:00 NY time");
     }
 }
+
+// ---------------------------------------------------------------------------
+// Group 10: Business-hours filter
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DDT[09,12,18]:00:00` on weekday hours with a lunch break: 12:00 is a
+/// window edge and kept, 18:00 is after closing, and weekends are closed.
+#[test]
+fn test_business_hours_filter() {
+    use crate::biz_hours::BusinessHours;
+    let tz = Utc;
+    let hours = BusinessHours::weekdays("08:30-17:30")
+        .unwrap()
+        .with_break("12:00-13:00")
+        .unwrap();
+    let start = tz.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(); // Friday
+    let iter =
+        SpecIteratorBuilder::new_after("YY-MM-DDT[09,12,18]:00:00", WeekendSkipper::new(), start)
+            .with_business_hours(hours)
+            .build()
+            .unwrap();
+    let results: Vec<_> = iter
+        .take(4)
+        .map(|occ: NR<_>| Ok(*occ.observed()))
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 3, 9, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 12, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap(), // Monday
+            tz.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap(),
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 11: Millisecond time component
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DDT23:59:59.[000,500]` — two ticks in the last second of each day,
+/// the first day's ticks starting from the caller's sub-second cursor.
+#[test]
+fn test_millisecond_time_spec() {
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 23, 59, 59).unwrap() + Duration::milliseconds(250);
+    let iter =
+        SpecIteratorBuilder::new_after("YY-MM-DDT23:59:59.[000,500]", WeekendSkipper::new(), start)
+            .build()
+            .unwrap();
+    let results: Vec<_> = iter
+        .take(3)
+        .map(|occ: NR<_>| Ok(*occ.observed()))
+        .collect()
+        .unwrap();
+    let last_second = |day| tz.with_ymd_and_hms(2025, 1, day, 23, 59, 59).unwrap();
+    assert_eq!(
+        results,
+        vec![
+            last_second(1) + Duration::milliseconds(500),
+            last_second(2),
+            last_second(2) + Duration::milliseconds(500),
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 12: Intervals of a day or more
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DDT24H:00:00` — the time cadence restarts on every date, so a
+/// day-long interval yields one tick per date, at midnight. Longer intervals
+/// (`36H`) are rejected.
+#[test]
+fn test_every_longer_than_a_day() {
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(); // Monday
+    let err =
+        SpecIteratorBuilder::new_with_start("YY-MM-DDT36H:00:00", WeekendSkipper::new(), start)
+            .build()
+            .unwrap_err();
+    assert!(matches!(err, crate::Error::InvalidSpecComponent { .. }));
+
+    let iter =
+        SpecIteratorBuilder::new_with_start("YY-MM-DDT24H:00:00", WeekendSkipper::new(), start)
+            .build()
+            .unwrap();
+    let results: Vec<_> = iter
+        .take(3)
+        .map(|occ: NR<_>| Ok(*occ.observed()))
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 7, 0, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 8, 0, 0, 0).unwrap(),
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 13: Jitter
+// ---------------------------------------------------------------------------
+
+/// A jitter shifts `observed()` only; `actual()` keeps the nominal time, also
+/// on top of a business day adjustment.
+#[test]
+fn test_jitter_keeps_actual_nominal() {
+    use crate::jitter::Jitter;
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    let iter =
+        SpecIteratorBuilder::new_after("YY-1M-31L~NBT11:00:00", WeekendSkipper::new(), start)
+            .with_jitter(Jitter::fixed(-Duration::seconds(45)))
+            .build()
+            .unwrap();
+    let results: Vec<NR<_>> = iter.take(2).collect().unwrap();
+
+    // May 31 2025 is a Saturday → adjusted to Monday Jun 2, still later.
+    let may = tz.with_ymd_and_hms(2025, 5, 31, 11, 0, 0).unwrap();
+    let jun2 = tz.with_ymd_and_hms(2025, 6, 2, 10, 59, 15).unwrap();
+    assert_eq!(results[0], Occurrence::AdjustedLater(may, jun2));
+
+    // Jun 30 2025 is a Monday → only the jitter moves it.
+    let jun = tz.with_ymd_and_hms(2025, 6, 30, 11, 0, 0).unwrap();
+    assert_eq!(
+        results[1],
+        Occurrence::AdjustedEarlier(jun, jun - Duration::seconds(45))
+    );
+}
+
+/// Splay spreads pinned times over the date cadence, and intraday cadences
+/// over their own step.
+#[test]
+fn test_splay_uses_date_cadence() {
+    use crate::jitter::Jitter;
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    for (spec, period) in [
+        ("YY-MM-DDT09:00:00", Duration::days(1)),
+        ("YY-MM-DDT[09:00:00,16:30:00]", Duration::days(1)),
+        ("YY-MM-MONT09:00:00", Duration::days(7)),
+        ("YY-MM-01T09:00:00", Duration::days(28)),
+        ("YY-MM-DDT1H:00:00", Duration::hours(1)),
+    ] {
+        let offsets: Vec<Duration> = (0..20)
+            .map(|i| {
+                let occ = SpecIteratorBuilder::new_after(spec, WeekendSkipper::new(), start)
+                    .with_jitter(Jitter::splay(format!("pod-{i}")))
+                    .build()
+                    .unwrap()
+                    .next()
+                    .unwrap()
+                    .unwrap();
+                *occ.observed() - *occ.actual()
+            })
+            .collect();
+        assert!(
+            offsets
+                .iter()
+                .all(|o| *o >= Duration::zero() && *o < period),
+            "{spec}: {offsets:?}"
+        );
+        assert!(
+            offsets.iter().any(|o| *o > period / 2),
+            "{spec}: {offsets:?}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Group 14: Zone suffix — `@<IANA zone>`
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DDT09:00:00@America/New_York` built from a UTC start: 09:00 New
+/// York time each day, reported in UTC across the spring-forward change.
+#[test]
+fn test_zone_suffix() {
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 3, 7, 0, 0, 0).unwrap();
+    let iter = SpecIteratorBuilder::new_after(
+        "YY-MM-DDT09:00:00@America/New_York",
+        WeekendSkipper::new(),
+        start,
+    )
+    .build()
+    .unwrap();
+    let results: Vec<_> = iter
+        .take(3)
+        .map(|occ: NR<_>| Ok(*occ.observed()))
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 3, 7, 14, 0, 0).unwrap(), // EST
+            tz.with_ymd_and_hms(2025, 3, 8, 14, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 3, 9, 13, 0, 0).unwrap(), // EDT
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 15: Multiple time slots — `T[<time>,<time>,…]`
+// ---------------------------------------------------------------------------
+
+/// `YY-1M-31L~NBT[16:30:00,09:00:00]` — both slots on the last business day
+/// of each month, in chronological order whatever the spec order. Every time
+/// on an adjusted date is `AdjustedLater`.
+#[test]
+fn test_time_slots_per_date() {
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    let iter = SpecIteratorBuilder::new_after(
+        "YY-1M-31L~NBT[16:30:00,09:00:00]",
+        WeekendSkipper::new(),
+        start,
+    )
+    .build()
+    .unwrap();
+    let results: Vec<NR<_>> = iter.take(4).collect().unwrap();
+
+    // May 31 2025 is a Saturday → Monday Jun 2
+    assert_eq!(
+        results,
+        vec![
+            Occurrence::AdjustedLater(
+                tz.with_ymd_and_hms(2025, 5, 31, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 6, 2, 9, 0, 0).unwrap(),
+            ),
+            Occurrence::AdjustedLater(
+                tz.with_ymd_and_hms(2025, 5, 31, 16, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 6, 2, 16, 30, 0).unwrap(),
+            ),
+            Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 9, 0, 0).unwrap()),
+            Occurrence::Exact(tz.with_ymd_and_hms(2025, 6, 30, 16, 30, 0).unwrap()),
+        ]
+    );
+}
+
+/// Each slot keeps its own cadence: `2H:00:00` keeps to even hours around a
+/// `09:15:00` slot, and a time produced by two slots is emitted once.
+#[test]
+fn test_time_slots_interleave() {
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 7, 30, 0).unwrap();
+    let iter = SpecIteratorBuilder::new_after(
+        "YY-MM-DDT[2H:00:00,09:15:00,11:00:00]",
+        WeekendSkipper::new(),
+        start,
+    )
+    .build()
+    .unwrap();
+    let results: Vec<_> = iter
+        .take(5)
+        .map(|occ: NR<_>| Ok(occ.observed().time()))
+        .collect()
+        .unwrap();
+    let at = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    assert_eq!(
+        results,
+        vec![at(9, 0), at(9, 15), at(11, 0), at(13, 0), at(15, 0)]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 16: Intra-day cut-off — `CutOff`
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DD~NBT[09:00:00,18:00:00]` with a 17:00 cut-off: the 18:00 slot is
+/// too late to settle that day, so `~NB` moves it to the next business day —
+/// Friday for Thursday, Monday for Friday. On the weekend the time does not
+/// matter, so 18:00 settles on Monday like 09:00. Occurrences come out in
+/// observed order.
+#[test]
+fn test_cut_off_moves_late_slot() {
+    let tz = Utc;
+    let cut_off = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+    let bdp = CutOff::new(WeekendSkipper::new(), cut_off);
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let spec = "YY-MM-DD~NBT[09:00:00,18:00:00]";
+    let iter = SpecIteratorBuilder::new_after(spec, bdp, start)
+        .build()
+        .unwrap();
+    let results: Vec<NR<_>> = iter.take(14).collect().unwrap();
+    let at = |d, h| tz.with_ymd_and_hms(2025, 1, d, h, 0, 0).unwrap();
+    assert_eq!(
+        results,
+        vec![
+            Occurrence::Exact(at(1, 9)),
+            Occurrence::Exact(at(2, 9)),
+            Occurrence::AdjustedLater(at(1, 18), at(2, 18)),
+            Occurrence::Exact(at(3, 9)),
+            Occurrence::AdjustedLater(at(2, 18), at(3, 18)),
+            Occurrence::AdjustedLater(at(4, 9), at(6, 9)),
+            Occurrence::AdjustedLater(at(5, 9), at(6, 9)),
+            Occurrence::Exact(at(6, 9)),
+            Occurrence::AdjustedLater(at(3, 18), at(6, 18)),
+            Occurrence::AdjustedLater(at(4, 18), at(6, 18)),
+            Occurrence::AdjustedLater(at(5, 18), at(6, 18)),
+            Occurrence::Exact(at(7, 9)),
+            Occurrence::AdjustedLater(at(6, 18), at(7, 18)),
+            Occurrence::Exact(at(8, 9)),
+        ]
+    );
+    assert!(results
+        .windows(2)
+        .all(|w| w[0].observed() <= w[1].observed()));
+}
+
+/// Without an adjustment in the spec the cut-off has no effect, and a time
+/// exactly at the cut-off still settles the same day.
+#[test]
+fn test_cut_off_needs_adjustment() {
+    let tz = Utc;
+    let cut_off = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+    let bdp = CutOff::new(WeekendSkipper::new(), cut_off);
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    for spec in ["YY-MM-THUT18:00:00", "YY-MM-THU~NBT17:00:00"] {
+        let iter = SpecIteratorBuilder::new_after(spec, bdp.clone(), start)
+            .build()
+            .unwrap();
+        let results: Vec<NR<_>> = iter.take(1).collect().unwrap();
+        assert!(
+            matches!(results[0], Occurrence::Exact(_)),
+            "{spec}: {results:?}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Group 17: Reverse iteration — `new_before`
+// ---------------------------------------------------------------------------
+
+/// Walking back from 2025-06-03 over `YY-1M-31L~NBTHH:30M:00` meets Jun 2
+/// first, the adjusted month end of May: every tick of the day carries the
+/// adjustment, as it does when iterating forwards.
+#[test]
+fn test_reverse_keeps_adjustment() {
+    let tz = Utc;
+    let dtm = tz.with_ymd_and_hms(2025, 6, 3, 0, 0, 0).unwrap();
+    let iter =
+        SpecIteratorBuilder::new_before("YY-1M-31L~NBTHH:30M:00", WeekendSkipper::new(), dtm)
+            .build()
+            .unwrap();
+    let results: Vec<NR<_>> = iter.take(49).collect().unwrap();
+
+    let adjusted = |h, m| {
+        Occurrence::AdjustedLater(
+            tz.with_ymd_and_hms(2025, 5, 31, h, m, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 6, 2, h, m, 0).unwrap(),
+        )
+    };
+    assert_eq!(results[0], adjusted(23, 30));
+    assert_eq!(results[46], adjusted(0, 30));
+    assert_eq!(results[47], adjusted(0, 0));
+    assert_eq!(
+        results[48],
+        Occurrence::Exact(tz.with_ymd_and_hms(2025, 4, 30, 23, 30, 0).unwrap())
+    );
+}
+
+/// A reverse scan finds the occurrences a forward scan from its earliest
+/// occurrence yields, for slot lists, cadences, cut-offs and zones alike,
+/// in order of actual rather than observed time. The daily specs reach back
+/// over a weekend of adjusted dates.
+#[test]
+fn test_reverse_matches_forward() {
+    let cut_off = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+    let bdp = CutOff::new(WeekendSkipper::new(), cut_off);
+    let dtm = Utc.with_ymd_and_hms(2025, 3, 12, 12, 0, 0).unwrap();
+    for (spec, n) in [
+        ("YY-MM-[THU,FRI]~NBT[09:00:00,18:00:00]", 12),
+        ("YY-1M-L~PWT[09:00:00,4H:00:00]", 12),
+        ("YY-MM-MON#2T[09,17]:30:00@America/New_York", 12),
+        ("YY-2W-FRIT1H:00:00", 12),
+        ("YY-MM-DD~NBT[09:00:00,16:30:00]", 12),
+        ("YY-MM-DD~NBT1H:00:00", 120),
+    ] {
+        let mut reverse: Vec<NR<_>> = SpecIteratorBuilder::new_before(spec, bdp.clone(), dtm)
+            .build()
+            .unwrap()
+            .take(n)
+            .collect()
+            .unwrap();
+        assert!(
+            reverse.iter().all(|occ| occ.actual() < &dtm),
+            "spec: {}",
+            spec
+        );
+        assert!(
+            reverse.windows(2).all(|w| w[0].actual() > w[1].actual()),
+            "spec: {}",
+            spec
+        );
+        reverse.reverse();
+
+        // Start forwards at the end of the previous day, then keep the ticks
+        // from the earliest one found going backwards up to `dtm`. None of
+        // them is observed more than a week after it.
+        let first = *reverse[0].actual();
+        let from =
+            first.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc() - Duration::seconds(1);
+        let mut forward: Vec<NR<_>> = SpecIteratorBuilder::new_after(spec, bdp.clone(), from)
+            .build()
+            .unwrap()
+            .take_while(|occ| Ok(occ.observed() < &(dtm + Duration::days(7))))
+            .filter(|occ| Ok(occ.actual() >= &first && occ.actual() < &dtm))
+            .collect()
+            .unwrap();
+        forward.sort_by_key(|occ| *occ.actual());
+        assert_eq!(reverse, forward, "spec: {}", spec);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Group 18: Session close — `with_session_close`
+// ---------------------------------------------------------------------------
+
+/// `YY-MM-DDT[09:00:00,15:00:00]` on a calendar closing at 13:00 on
+/// Christmas Eve: the 15:00 slot moves back to the close that day only,
+/// going forwards and backwards alike.
+#[test]
+fn test_session_close_clamps_late_slot() {
+    use crate::calendar::{HolidayCalendar, HolidayRule};
+    let tz = Utc;
+    let close = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+    let cal = HolidayCalendar::new().with_early_close_rule(HolidayRule::fixed(12, 24), close);
+    let spec = "YY-MM-DDT[09:00:00,15:00:00]";
+    let at = |d, h| tz.with_ymd_and_hms(2025, 12, d, h, 0, 0).unwrap();
+    let expected = vec![
+        Occurrence::Exact(at(23, 9)),
+        Occurrence::Exact(at(23, 15)),
+        Occurrence::Exact(at(24, 9)),
+        Occurrence::AdjustedEarlier(at(24, 15), at(24, 13)),
+    ];
+
+    let forward: Vec<NR<_>> = SpecIteratorBuilder::new_after(spec, cal.clone(), at(23, 0))
+        .with_session_close()
+        .build()
+        .unwrap()
+        .take(4)
+        .collect()
+        .unwrap();
+    assert_eq!(forward, expected);
+
+    let mut reverse: Vec<NR<_>> = SpecIteratorBuilder::new_before(spec, cal.clone(), at(24, 16))
+        .with_session_close()
+        .build()
+        .unwrap()
+        .take(4)
+        .collect()
+        .unwrap();
+    reverse.reverse();
+    assert_eq!(reverse, expected);
+
+    // Without the option the calendar's session is ignored.
+    let plain: Vec<NR<_>> = SpecIteratorBuilder::new_after(spec, cal, at(24, 12))
+        .build()
+        .unwrap()
+        .take(1)
+        .collect()
+        .unwrap();
+    assert_eq!(plain, vec![Occurrence::Exact(at(24, 15))]);
+}
+
+/// A sub-daily cadence is not clamped, so its ticks are never collapsed onto
+/// the close.
+#[test]
+fn test_session_close_skips_cadence() {
+    use crate::calendar::HolidayCalendar;
+    let tz = Utc;
+    let close = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+    let christmas_eve = NaiveDate::from_ymd_opt(2025, 12, 24).unwrap();
+    let cal = HolidayCalendar::new().with_early_close(christmas_eve, close);
+    let start = tz.with_ymd_and_hms(2025, 12, 24, 13, 30, 0).unwrap();
+    let results: Vec<NR<_>> = SpecIteratorBuilder::new_after("YY-MM-DDT1H:00:00", cal, start)
+        .with_session_close()
+        .build()
+        .unwrap()
+        .take(2)
+        .collect()
+        .unwrap();
+    assert!(
+        results
+            .iter()
+            .all(|occ| matches!(occ, Occurrence::Exact(_))),
+        "{results:?}"
+    );
+}
+
+/// Occurrences clamped back to the start of iteration or onto one another
+/// are dropped: on Christmas Eve NYSE closes at 13:00, so from 14:00 the
+/// 15:00 slot is already past, and the 14:00 and 15:00 slots run once.
+/// Christmas Day itself is a holiday, moved to the 26th by `~NB`.
+#[test]
+fn test_session_close_drops_repeats() {
+    use crate::calendar::markets;
+    let tz = Utc;
+    let at = |d, h| tz.with_ymd_and_hms(2025, 12, d, h, 0, 0).unwrap();
+
+    let results: Vec<NR<_>> =
+        SpecIteratorBuilder::new_after("YY-MM-DD~NBT15:00:00", markets::nyse(), at(24, 14))
+            .with_session_close()
+            .build()
+            .unwrap()
+            .take(2)
+            .collect()
+            .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            Occurrence::AdjustedLater(at(25, 15), at(26, 15)),
+            Occurrence::Exact(at(26, 15)),
+        ]
+    );
+
+    let spec = "YY-MM-DD~NBT[14:00:00,15:00:00]";
+    let expected = vec![
+        Occurrence::Exact(at(23, 14)),
+        Occurrence::Exact(at(23, 15)),
+        Occurrence::AdjustedEarlier(at(24, 14), at(24, 13)),
+    ];
+    let forward: Vec<NR<_>> = SpecIteratorBuilder::new_after(spec, markets::nyse(), at(23, 0))
+        .with_session_close()
+        .build()
+        .unwrap()
+        .take(3)
+        .collect()
+        .unwrap();
+    assert_eq!(forward, expected);
+
+    let mut reverse: Vec<NR<_>> = SpecIteratorBuilder::new_before(spec, markets::nyse(), at(25, 0))
+        .with_session_close()
+        .build()
+        .unwrap()
+        .take(3)
+        .collect()
+        .unwrap();
+    reverse.reverse();
+    assert_eq!(reverse, expected);
+}
+
+// ---------------------------------------------------------------------------
+// Group 19: Strict parsing — `strict`
+// ---------------------------------------------------------------------------
+
+/// `2025-D366T09:00:00` never matches, as 2025 is not a leap year: a strict
+/// builder rejects it from every constructor, pointing at the day of the year.
+#[test]
+fn test_strict_rejects_date_that_never_matches() {
+    use crate::SpecComponent;
+    let tz = Utc;
+    let bdp = WeekendSkipper::new();
+    let from = tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let to = tz.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let spec = "2025-D366T09:00:00@Europe/London";
+    let is_day_366 = |err: crate::Error| {
+        matches!(
+            err,
+            crate::Error::InvalidSpecComponent { component: SpecComponent::Day, span, .. }
+                if span == (6..9)
+        )
+    };
+
+    let lenient = SpecIteratorBuilder::new_after(spec, bdp.clone(), from)
+        .build()
+        .unwrap();
+    assert_eq!(lenient.take(1).count().unwrap(), 0);
+
+    let after = SpecIteratorBuilder::new_after(spec, bdp.clone(), from)
+        .strict()
+        .build();
+    assert!(is_day_366(after.unwrap_err()));
+    let before = SpecIteratorBuilder::new_before(spec, bdp.clone(), to)
+        .strict()
+        .build();
+    assert!(is_day_366(before.unwrap_err()));
+    let with_start = SpecIteratorBuilder::new_with_start(spec, bdp.clone(), from)
+        .strict()
+        .build();
+    assert!(is_day_366(with_start.unwrap_err()));
+    let with_end = SpecIteratorBuilder::new_with_start(spec, bdp.clone(), from)
+        .strict()
+        .with_end(to)
+        .build();
+    assert!(is_day_366(with_end.unwrap_err()));
+
+    // A leap year has a day 366.
+    let leap: Vec<NR<_>> =
+        SpecIteratorBuilder::new_after("2024-D366T09:00:00", bdp, from - Duration::days(366))
+            .strict()
+            .build()
+            .unwrap()
+            .collect()
+            .unwrap();
+    let new_years_eve = tz.with_ymd_and_hms(2024, 12, 31, 9, 0, 0).unwrap();
+    assert_eq!(leap, vec![Occurrence::Exact(new_years_eve)]);
+}
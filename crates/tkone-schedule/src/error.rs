@@ -1,12 +1,33 @@
+use std::fmt;
+use std::ops::Range;
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("invalid date spec: {0}")]
     InvalidDateSpec(String),
+    /// A well-formed spec component holds a value that can never match,
+    /// reported by [`crate::date::parse_spec_strict`], or one half of a
+    /// date-time spec fails to parse.
+    ///
+    /// `span` is the byte range of the offending value within the spec string.
+    #[error("invalid {component} `{value}` at {span:?}: expected {expected}")]
+    InvalidSpecComponent {
+        component: SpecComponent,
+        span: Range<usize>,
+        value: String,
+        expected: String,
+    },
     #[error("invalid time spec: {0}")]
     InvalidTimeSpec(String),
     #[error("invalid date-time spec: {0}")]
     InvalidDateTimeSpec(String),
+    #[error("invalid timezone: {0}")]
+    InvalidTimezone(String),
+    #[error("invalid business hours: {0}")]
+    InvalidBusinessHours(String),
+    #[error("invalid holiday calendar: {0}")]
+    InvalidHolidayCalendar(String),
     #[error("invalid end spec")]
     InvalidEndSpec,
     #[error("schedule iterator did not converge")]
@@ -17,4 +38,37 @@ pub enum Error {
     Custom(String),
 }
 
+/// The component of a spec named by [`Error::InvalidSpecComponent`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SpecComponent {
+    Year,
+    Month,
+    Quarter,
+    IsoWeek,
+    Day,
+    /// The date half of a date-time spec.
+    DateSpec,
+    /// The time half of a date-time spec.
+    TimeSpec,
+    /// The business day adjustment suffix (`~nN`, `~nP`, …).
+    Adjustment,
+}
+
+impl fmt::Display for SpecComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SpecComponent::Year => "year",
+            SpecComponent::Month => "month",
+            SpecComponent::Quarter => "quarter",
+            SpecComponent::IsoWeek => "ISO week",
+            SpecComponent::Day => "day",
+            SpecComponent::DateSpec => "date spec",
+            SpecComponent::TimeSpec => "time spec",
+            SpecComponent::Adjustment => "adjustment",
+        };
+        write!(f, "{name}")
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,120 @@
+//! Deterministic jitter for spreading identical schedules apart.
+//!
+//! When many processes run the same spec (say `1H:00:00`), they all fire at
+//! the same instant. A [`Jitter`] shifts every occurrence of one iterator by a
+//! constant offset derived from a caller-supplied key, such as a pod or host
+//! name, so each instance keeps its cadence but fires at its own stable point.
+//! Apply it with
+//! [`time::SpecIteratorBuilder::with_jitter`](crate::time::SpecIteratorBuilder::with_jitter)
+//! or
+//! [`datetime::SpecIteratorBuilder::with_jitter`](crate::datetime::SpecIteratorBuilder::with_jitter).
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::Occurrence;
+
+/// A stable offset added to every occurrence of a schedule.
+///
+/// | Constructor | Offset |
+/// |-------------|--------|
+/// | [`Jitter::fixed`] | Exactly the given duration (may be negative) |
+/// | [`Jitter::keyed`] | Derived from `key`, within `-max..=max` |
+/// | [`Jitter::splay`] | Derived from `key`, within `0..period` of the spec's driving component |
+///
+/// Keyed offsets come from a fixed hash of the key with millisecond
+/// resolution, so the same key always gets the same offset — across
+/// restarts, machines and crate versions.
+///
+/// # Examples
+///
+/// ```rust
+/// use tkone_schedule::jitter::Jitter;
+/// use chrono::Duration;
+///
+/// let jitter = Jitter::keyed("pod-17", Duration::seconds(30));
+/// let offset = jitter.offset(Duration::hours(1));
+/// assert!(offset.abs() <= Duration::seconds(30));
+/// assert_eq!(offset, Jitter::keyed("pod-17", Duration::seconds(30)).offset(Duration::hours(1)));
+///
+/// // Splay spreads instances over the whole period of `1H:00:00`.
+/// let offset = Jitter::splay("pod-17").offset(Duration::hours(1));
+/// assert!(offset >= Duration::zero() && offset < Duration::hours(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jitter(Kind);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    Fixed(Duration),
+    Keyed(u64, Duration),
+    Splay(u64),
+}
+
+impl Jitter {
+    /// Shift every occurrence by exactly `offset`.
+    pub fn fixed(offset: Duration) -> Self {
+        Self(Kind::Fixed(offset))
+    }
+
+    /// Shift every occurrence by an offset within `-max..=max` chosen by `key`.
+    pub fn keyed(key: impl AsRef<str>, max: Duration) -> Self {
+        Self(Kind::Keyed(fnv1a(key.as_ref()), max.abs()))
+    }
+
+    /// Shift every occurrence by an offset within `0..period` chosen by `key`,
+    /// where `period` is the step of the spec's driving component (1 hour for
+    /// `1H:00:00`, 30 minutes for `HH:30M:00`).
+    ///
+    /// A datetime spec whose times are all pinned (`YY-MM-DDT09:00:00`) uses
+    /// the step of its date spec instead: a day for `YY-MM-DD`, a week for
+    /// `YY-MM-MON`. A time spec without a driving component (all `At` /
+    /// `AsIs` times) has a period of one second; use [`Jitter::keyed`] with
+    /// an explicit bound for those.
+    pub fn splay(key: impl AsRef<str>) -> Self {
+        Self(Kind::Splay(fnv1a(key.as_ref())))
+    }
+
+    /// The offset applied to a schedule whose driving period is `period`.
+    pub fn offset(&self, period: Duration) -> Duration {
+        match self.0 {
+            Kind::Fixed(offset) => offset,
+            Kind::Keyed(hash, max) => {
+                // In i128, as `2 * max + 1` overflows an i64 for huge bounds.
+                let max = max.num_milliseconds() as i128;
+                Duration::milliseconds((hash as i128 % (max * 2 + 1) - max) as i64)
+            }
+            Kind::Splay(hash) => {
+                let period = period.num_milliseconds().max(1) as u64;
+                Duration::milliseconds((hash % period) as i64)
+            }
+        }
+    }
+}
+
+/// Shifts the observed time of `occ` by `offset`, keeping `actual()` at the
+/// nominal time. The variant is chosen by where the shifted time lands
+/// relative to the nominal one.
+pub(crate) fn jitter_occurrence(
+    occ: Occurrence<NaiveDateTime>,
+    offset: Duration,
+) -> Occurrence<NaiveDateTime> {
+    if offset.is_zero() {
+        return occ;
+    }
+    let actual = *occ.actual();
+    let observed = *occ.observed() + offset;
+    if observed > actual {
+        Occurrence::AdjustedLater(actual, observed)
+    } else if observed < actual {
+        Occurrence::AdjustedEarlier(actual, observed)
+    } else {
+        Occurrence::Exact(actual)
+    }
+}
+
+/// 64-bit FNV-1a; stable across platforms and releases, unlike `std`'s hasher.
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
@@ -87,6 +87,23 @@
 //! ```
 //! *Run `cargo run -p tkone-schedule --example time_recurrence` for the full program.*
 //!
+//! ### 4 — Walking backwards
+//!
+//! ```rust
+//! # use tkone_schedule::biz_day::WeekendSkipper;
+//! # use tkone_schedule::date::SpecIteratorBuilder;
+//! # use chrono::{TimeZone, Utc};
+//! # use fallible_iterator::FallibleIterator;
+//! # let bdp = WeekendSkipper::new();
+//! # let now = Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap();
+//! // The last 3 month-end business days before now, latest first
+//! let iter = SpecIteratorBuilder::new_before("YY-MM-LBD", bdp, now).build().unwrap();
+//! # let _: Vec<_> = iter.take(3).collect().unwrap();
+//! // → 2024-05-31, 2024-04-30, 2024-03-29
+//! ```
+//! Every builder has `new_before`; business day adjustments, business hours,
+//! jitter and the DST policy apply exactly as when iterating forwards.
+//!
 //! ## Running the Bundled Examples
 //!
 //! The crate ships runnable examples under `examples/`. Run any of them with:
@@ -120,6 +137,16 @@
 //! | `nM`  | Every *n* months, aligned to the iterator start date |
 //! | `03`  | March only |
 //! | `[01,06,12]` | January, June, or December |
+//! | `QQ`  | Every quarter |
+//! | `Q1` / `[Q1,Q3]` | Specific quarter(s) |
+//! | `2Q` / `4Q` | Every 2nd / 4th quarter from the start of the fiscal year |
+//!
+//! Quarters follow the calendar year unless a fiscal year start month is set
+//! with `SpecIteratorBuilder::with_fiscal_year_start`. End-anchored day rules
+//! (`L`, `LBD`, `FRI#L`, …) fall in the last month of the quarter, so
+//! `YY-QQ-L` is the last day of every quarter; other fixed days fall in the
+//! first month. The year component is always the calendar year of the date, so
+//! with a fiscal year starting in April, `2025-Q4-L` is 2025-03-31.
 //!
 //! #### Days
 //!
@@ -135,11 +162,68 @@
 //! | `nD`  | Advance *n* calendar days |
 //! | `nBD` | Advance *n* business days |
 //! | `nWD` | Advance *n* weekdays (Mon–Fri) |
+//! | `5BDM` | 5th business day of the month |
+//! | `LBD` | Last business day of the month |
+//! | `3LBD` | 3rd-to-last business day of the month |
+//! | `5BDA` | Every 5th business day, counted from the start of each month |
 //! | `MON` / `TUE` / … | Every occurrence of that weekday in the month |
 //! | `[MON,FRI]` | Every Monday and Friday |
 //! | `WED#2` | 2nd Wednesday of the month |
 //! | `FRI#L` | Last Friday of the month |
 //! | `THU#2L` | 2nd-to-last Thursday of the month |
+//! | `[MON,WED]#2` | 2nd Monday and 2nd Wednesday of the month |
+//! | `[TUE,THU]#L` | Last Tuesday and last Thursday of the month |
+//! | `MON>=15` | First Monday on or after the 15th |
+//! | `FRI<=L` | Last Friday on or before the end of the month |
+//!
+//! #### ISO Week and Day-of-Year Forms
+//!
+//! Two alternative layouts replace the month and day components. In the week
+//! form the year refers to the ISO week-numbering year.
+//!
+//! | Spec | Meaning |
+//! |------|---------|
+//! | `YY-W[01,27]-MON` | Monday of ISO weeks 1 and 27 |
+//! | `YY-WW-[MON,THU]` | Every Monday and Thursday, week by week |
+//! | `YY-2W-FRI` | Friday of every 2nd ISO week, aligned to the iterator start |
+//! | `YY-W05-DD` | Every day of ISO week 5 |
+//! | `YY-D256` | 256th day of the year |
+//! | `YY-D[1,182]` | 1st and 182nd day of the year |
+//!
+//! #### Easter Form
+//!
+//! `EASTER` (Western) or `OEASTER` (Orthodox) replaces the month and day
+//! components, with an optional signed offset in calendar days (up to ±366).
+//! The year component selects the year of Easter Sunday itself.
+//!
+//! | Spec | Meaning |
+//! |------|---------|
+//! | `YY-EASTER` | Easter Sunday |
+//! | `YY-EASTER+1` | Easter Monday |
+//! | `YY-EASTER-2~1P` | One business day before Good Friday |
+//! | `YY-OEASTER` | Orthodox Easter Sunday |
+//!
+//! `EasterCalendar::easter_sunday(year)` exposes the same computation for
+//! `BizDayProcessor` implementations that observe Easter-relative holidays.
+//!
+//! #### Exclusions (`!` / `EXCEPT`)
+//!
+//! One or more sub-specs, written after the days and before any adjustment,
+//! whose dates are skipped before the adjustment is applied.
+//!
+//! | Spec | Meaning |
+//! |------|---------|
+//! | `YY-MM-1WD!YY-MM-01` | Every weekday except the 1st of the month |
+//! | `YY-1M-15 EXCEPT YY-12-DD` | The 15th of every month except December |
+//! | `YY-MM-FRI!YY-MM-FRI#L~PB` | Every Friday except the last one of the month |
+//!
+//! #### Spec Sets (`|`)
+//!
+//! Several specs joined with `|` form a [`date::SpecSet`], e.g.
+//! `YY-MM-15|YY-MM-L|YY-[01,07]-MON#1`. Build it with
+//! `SpecIteratorBuilder::build_set()` to get one chronologically ordered,
+//! de-duplicated stream of `(member_index, Occurrence)` items that shares the
+//! builder's start, end and DST options.
 //!
 //! #### Business Day Adjustment (`~`)
 //!
@@ -159,7 +243,47 @@
 //! | `~3P` | 3 business days earlier (unconditional) |
 //! | `~2N` | 2 business days later (unconditional) |
 //!
-//! ### Time Spec — `<hours>:<minutes>:<seconds>`
+//! Wrap a processor in `biz_day::CutOff` to give business days a cut-off time:
+//! in a datetime spec, a slot after the cut-off (say `18:00:00` with a 17:00
+//! cut-off) counts as the next business day, so `~NB` settles it there.
+//! Occurrences moved later come out in order of their observed time.
+//!
+//! `calendar::HolidayCalendar` is a processor with its own weekend (Friday and
+//! Saturday for Gulf markets, say) and holidays, given as fixed dates or as
+//! yearly date-spec rules such as `YY-12-25~NB` or `YY-EASTER-2`. Exchange
+//! calendars published as iCalendar (`.ics`) or `date,name` CSV files load with
+//! `with_ics` / `with_csv`, multi-day closures included. `calendar::JointCalendar`
+//! (open in every member, e.g. London *and* New York) and `calendar::AnyCalendar`
+//! (open in any member) combine processors for cross-border settlement.
+//! `calendar::markets` provides reference calendars (`us_federal`, `nyse`,
+//! `uk_england_wales`, `target2`) built from `calendar::HolidayRule`s.
+//! A calendar can also carry opening hours and early closes (`with_session`,
+//! `with_early_close`), reported by `BizDayProcessor::session`; datetime iterators
+//! built `with_session_close` move a fixed-time occurrence back to the close on
+//! early-close days.
+//!
+//! #### Strict Validation
+//!
+//! `parse_spec` only checks the grammar, so `YY-13-01` or `YY-MM-MON#6` parse
+//! and then never match. `date::parse_spec_strict` also rejects out-of-range
+//! and repeated values, returning `Error::InvalidSpecComponent` with the
+//! component, the byte span of the offending value and the expected range —
+//! useful for rejecting bad specs when loading configuration. The date and
+//! datetime builders do the same when built `.strict()`, and
+//! `datetime::Spec::parse_strict` checks the date half of a datetime spec.
+//!
+//! ```rust
+//! use tkone_schedule::{date::parse_spec_strict, Error, SpecComponent};
+//!
+//! let err = parse_spec_strict("YY-MM-MON#6").unwrap_err();
+//! assert!(matches!(
+//!     err,
+//!     Error::InvalidSpecComponent { component: SpecComponent::Day, .. }
+//! ));
+//! assert_eq!(err.to_string(), "invalid day `6` at 10..11: expected a weekday occurrence, 1-5");
+//! ```
+//!
+//! ### Time Spec — `<hours>:<minutes>:<seconds>[.<millis>][@<zone>]`
 //!
 //! | Token | Meaning |
 //! |-------|---------|
@@ -175,16 +299,71 @@
 //! | `_`   | AsIs second |
 //! | `nS`  | Every *n* seconds |
 //! | `00`  | At second 0 |
+//! | `.MS` | ForEach millisecond (optional component) |
+//! | `._`  | AsIs millisecond — the default when omitted |
+//! | `.250MS` | Every *n* milliseconds |
+//! | `.000` | At millisecond 0 (three-digit) |
+//! | `[09,13,17]` | Values — any listed value (any component) |
+//! | `09-17` | Range — any value from 09 through 17 inclusive (any component) |
 //!
 //! **ForEach driving rule**: when no `Every` component exists, the finest
 //! `ForEach` field becomes `Every(1)` for its unit; coarser `ForEach` fields
-//! carry their current value unchanged.
+//! carry their current value unchanged. Lists and ranges take part in this
+//! election and step to their next allowed value; next to an `Every` they only
+//! filter its ticks, so `09-17:30M:00` runs every 30 minutes from 09:00 to 17:30.
+//! An `Every` interval may exceed its unit (`36H`, `90M`); it carries into the
+//! following hours and days and is measured in elapsed time across DST changes.
+//! Datetime specs, whose time cadence restarts on every date, reject such
+//! intervals.
+//!
+//! #### Business Hours
+//!
+//! [`biz_hours::BusinessHours`] restricts a time or datetime schedule to
+//! per-weekday windows, with optional daily breaks. Windows include both ends;
+//! occurrences outside them are dropped without re-anchoring the cadence.
+//!
+//! ```rust
+//! # use tkone_schedule::biz_hours::BusinessHours;
+//! # use tkone_schedule::time::SpecIteratorBuilder;
+//! # use chrono::{TimeZone, Utc};
+//! # use fallible_iterator::FallibleIterator;
+//! let hours = BusinessHours::weekdays("08:30-17:30").unwrap()
+//!     .with_break("12:00-13:00").unwrap();
+//! let start = Utc.with_ymd_and_hms(2025, 1, 3, 16, 45, 0).unwrap(); // Friday
+//! let times: Vec<_> = SpecIteratorBuilder::new_with_start("HH:[00,15,30,45]:00", start)
+//!     .with_business_hours(hours)
+//!     .build().unwrap().take(4).collect().unwrap();
+//! // → Fri 16:45, 17:00, 17:15, 17:30
+//! assert_eq!(times[3], Utc.with_ymd_and_hms(2025, 1, 3, 17, 30, 0).unwrap());
+//! ```
+//!
+//! #### Jitter
 //!
-//! ### DateTime Spec — `<date_spec>T<time_spec>`
+//! [`jitter::Jitter`] spreads identical schedules apart: every occurrence of one
+//! iterator is shifted by a stable offset derived from a caller-supplied key, so
+//! 200 pods running `1H:00:00` no longer fire at the same instant. Datetime
+//! occurrences keep the nominal time in `actual()` and the shifted one in
+//! `observed()`.
+//!
+//! ```rust
+//! # use tkone_schedule::jitter::Jitter;
+//! # use tkone_schedule::time::SpecIteratorBuilder;
+//! # use chrono::{Duration, TimeZone, Utc};
+//! # use fallible_iterator::FallibleIterator;
+//! let start = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+//! let times: Vec<_> = SpecIteratorBuilder::new_after("1H:00:00", start)
+//!     .with_jitter(Jitter::keyed("pod-17", Duration::seconds(30)))
+//!     .build().unwrap().take(2).collect().unwrap();
+//! // → 10:00 ± 30s, 11:00 ± 30s — the same offset on every run for "pod-17"
+//! assert_eq!(times[1] - times[0], Duration::hours(1));
+//! ```
+//!
+//! ### DateTime Spec — `<date_spec>T<time_spec>[@<zone>]`
 //!
 //! The `T` separator is detected by the pattern that follows it (`HH:`, `nH:`,
-//! or a two-digit clock hour `dd:`), so weekday tokens like `TUE` and `THU`
-//! in the date spec are not confused with the separator.
+//! a two-digit clock hour `dd:`, an hour list `[dd,..]:` or an hour range
+//! `dd-dd:`), so weekday tokens like `TUE` and `THU` in the date spec are not
+//! confused with the separator.
 //!
 //! ```text
 //! "YY-1M-31L~NBT11:00:00"  →  date="YY-1M-31L~NB"   time="11:00:00"
@@ -192,6 +371,23 @@
 //! "YY-MM-THUT09:30:00"     →  date="YY-MM-THU"         time="09:30:00"
 //! ```
 //!
+//! An optional `@<IANA zone>` suffix keeps the timezone with the spec string:
+//! `YY-MM-DDT09:00:00@Europe/London` fires at 09:00 London time whatever the
+//! timezone of the datetime the iterator is built from, and results are reported
+//! in that datetime's timezone. `SpecIteratorBuilder::from_spec(spec, bdp)` takes
+//! the zone from the string itself (UTC without a suffix). Time specs accept the
+//! same suffix.
+//!
+//! Parsing a `datetime::Spec` parses both halves, so a bad half fails straight
+//! away with `Error::InvalidSpecComponent` naming `SpecComponent::DateSpec` or
+//! `SpecComponent::TimeSpec` and its byte span. The parsed spec displays in
+//! canonical form, round-trips through `parse()`, and implements `Eq` and `Hash`
+//! for de-duplicating stored schedules.
+//!
+//! A bracketed list of time slots fires every slot on each valid date:
+//! `YY-MM-DD~NBT[09:00:00,16:30:00]` runs at 09:00 and 16:30 every business day.
+//! Each slot keeps its own cadence, and times are emitted in chronological order.
+//!
 //! ## `Occurrence` and Business Day Adjustments
 //!
 //! Date and datetime iterators yield [`Occurrence<T>`] rather than plain `T`.
@@ -208,21 +404,31 @@
 /// The `biz_day` module contains the [`biz_day::BizDayProcessor`] trait and
 /// built-in implementations for business day calculations.
 pub mod biz_day;
+/// The `biz_hours` module contains [`biz_hours::BusinessHours`], per-weekday
+/// time windows that filter time and datetime schedules.
+pub mod biz_hours;
+/// The `calendar` module contains [`calendar::HolidayCalendar`], a business
+/// day processor with a configurable weekend and holiday list.
+pub mod calendar;
 /// The `date` module provides calendar-day recurrence via [`date::Spec`] and
 /// [`date::SpecIteratorBuilder`].
 pub mod date;
 /// The `datetime` module combines a date spec and a time spec into a single
 /// recurrence schedule via [`datetime::Spec`] and [`datetime::SpecIteratorBuilder`].
 pub mod datetime;
+/// The `jitter` module contains [`jitter::Jitter`], a stable per-key offset
+/// that spreads identical schedules apart.
+pub mod jitter;
 /// The `time` module provides intra-day time recurrence via [`time::Spec`] and
 /// [`time::SpecIteratorBuilder`].
 pub mod time;
 
 mod error;
 mod prelude;
+mod reverse;
 mod utils;
 
-pub use error::{Error, Result};
+pub use error::{Error, Result, SpecComponent};
 
 /// Controls how timezone-aware iterators resolve local datetimes that fall in
 /// a DST transition window.
@@ -0,0 +1,124 @@
+//! Backward iteration built on the forward iterators.
+//!
+//! A forward iterator resolves each occurrence from the previous one, so it
+//! cannot simply be run backwards. [`NaiveReverse`] instead scans windows that
+//! end at the cursor: it runs a forward iterator from a window start a whole
+//! number of spec periods earlier, then hands back what it found latest first
+//! before moving on to the window below. Keeping window starts aligned to the
+//! spec's period makes cadences such as `1H`, `3M` or `2W` count from the same
+//! epoch as a forward iterator started at the cursor, and business day
+//! adjustment, business hours and jitter are applied by the forward iterator
+//! exactly as they are when iterating forwards.
+
+use chrono::{Duration, NaiveDateTime};
+use fallible_iterator::FallibleIterator;
+
+use crate::prelude::*;
+
+/// How far back a reverse iterator searches before concluding that the spec
+/// has no earlier occurrences.
+const MAX_LOOKBACK_DAYS: i64 = 366 * 1000;
+
+/// A forward schedule that [`NaiveReverse`] can scan window by window.
+pub(crate) trait Rewind {
+    type Item;
+    type Iter: FallibleIterator<Item = Self::Item, Error = Error>;
+
+    /// The start of the window `periods` spec periods before `dtm`, or `None`
+    /// when it would fall before the start of the calendar.
+    fn window_start(&self, dtm: NaiveDateTime, periods: u32) -> Result<Option<NaiveDateTime>>;
+
+    /// A forward iterator over the occurrences from `start` on.
+    ///
+    /// Occurrences after `scanned` have been looked for already, so the
+    /// iterator may end once it is past it.
+    fn iter_from(&self, start: NaiveDateTime, scanned: NaiveDateTime) -> Result<Self::Iter>;
+
+    /// The nominal datetime that orders `item`.
+    fn key(item: &Self::Item) -> NaiveDateTime;
+
+    /// Whether the schedule has at most one occurrence whatever the cursor,
+    /// as with an all-`At` time spec.
+    fn is_single(&self) -> bool {
+        false
+    }
+}
+
+/// Yields the occurrences of a [`Rewind`] source strictly before a cursor, in
+/// descending order of their nominal datetime.
+#[derive(Debug, Clone)]
+pub(crate) struct NaiveReverse<R: Rewind> {
+    source: R,
+    dtm: NaiveDateTime,
+    /// Every occurrence at or after `floor` has already been found.
+    floor: NaiveDateTime,
+    /// Periods between `dtm` and the start of the last window scanned.
+    periods: u32,
+    /// The start of the last window scanned.
+    scanned: NaiveDateTime,
+    /// Length of the next window in periods; doubled after an empty window.
+    step: u32,
+    /// Occurrences of the last window not yet yielded, in ascending order.
+    found: Vec<R::Item>,
+    done: bool,
+}
+
+impl<R: Rewind> NaiveReverse<R> {
+    /// Iterate the occurrences of `source` strictly before `dtm`.
+    pub(crate) fn new_before(source: R, dtm: NaiveDateTime) -> Self {
+        Self {
+            source,
+            dtm,
+            floor: dtm,
+            periods: 0,
+            scanned: dtm,
+            step: 1,
+            found: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+impl<R: Rewind> FallibleIterator for NaiveReverse<R> {
+    type Item = R::Item;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.found.pop() {
+                return Ok(Some(item));
+            }
+            if self.done {
+                return Ok(None);
+            }
+
+            self.periods = self.periods.saturating_add(self.step);
+            let start = self
+                .source
+                .window_start(self.dtm, self.periods)?
+                .filter(|start| self.dtm - *start <= Duration::days(MAX_LOOKBACK_DAYS));
+            let Some(start) = start else {
+                self.done = true;
+                continue;
+            };
+
+            let mut iter = self.source.iter_from(start, self.scanned)?;
+            self.scanned = start;
+            while let Some(item) = iter.next()? {
+                if R::key(&item) >= self.floor {
+                    break;
+                }
+                self.found.push(item);
+            }
+
+            match self.found.first() {
+                Some(first) => self.floor = R::key(first),
+                None => self.step = self.step.saturating_mul(2),
+            }
+            if self.source.is_single() && !self.found.is_empty() {
+                self.found.drain(..self.found.len() - 1);
+                self.done = true;
+            }
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use std::marker::PhantomData;
 
-use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
 
 use fallible_iterator::FallibleIterator;
 
 use super::spec::{Cycle, Spec};
-use crate::utils::resolve_local;
+use crate::biz_hours::{BusinessHours, MAX_CLOSED_DAYS};
+use crate::jitter::Jitter;
+use crate::reverse::{NaiveReverse, Rewind};
+use crate::utils::{local_in, resolve_in_zone};
 use crate::{prelude::*, DstPolicy};
 
 pub struct StartDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct NoStart;
+pub struct Before;
 pub struct EndDateTime<Tz: TimeZone>(DateTime<Tz>);
 pub struct EndSpec(String);
 pub struct NoEnd;
@@ -24,8 +28,10 @@ pub struct NotSealed;
 /// | Constructor | First result |
 /// |-------------|-------------|
 /// | `new(spec, tz)` | First occurrence after `Utc::now()` |
+/// | `from_spec(spec)` | First occurrence after `Utc::now()`, in the spec's `@` zone |
 /// | `new_after(spec, dtm)` | First occurrence strictly **after** `dtm` |
 /// | `new_with_start(spec, start)` | `start` itself is the first item |
+/// | `new_before(spec, dtm)` | Latest occurrence strictly **before** `dtm`, then earlier ones |
 ///
 /// After `new_with_start` you may optionally call:
 /// - `.with_end(end)` — bound by an explicit datetime
@@ -37,6 +43,8 @@ pub struct SpecIteratorBuilder<Tz: TimeZone, START, END, S> {
     spec: String,
     end: END,
     dst_policy: DstPolicy,
+    business_hours: Option<BusinessHours>,
+    jitter: Option<Jitter>,
     marker_sealed: PhantomData<S>,
 }
 
@@ -49,6 +57,25 @@ impl<Tz: TimeZone, START, END, S> SpecIteratorBuilder<Tz, START, END, S> {
         self.dst_policy = policy;
         self
     }
+
+    /// Only yield occurrences whose local time falls inside `hours`.
+    ///
+    /// Occurrences outside the windows, including a `new_with_start` start,
+    /// are skipped; the spec's cadence is not re-anchored at window openings.
+    pub fn with_business_hours(mut self, hours: BusinessHours) -> Self {
+        self.business_hours = Some(hours);
+        self
+    }
+
+    /// Shift every occurrence by the stable offset of `jitter`.
+    ///
+    /// The cadence, end bound and business hours all work on the nominal
+    /// times; only the yielded values are shifted. A [`Jitter::splay`] offset
+    /// falls within the step of the spec's driving component.
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
 }
 
 impl<Tz: TimeZone> SpecIteratorBuilder<Tz, NoStart, NoEnd, NotSealed> {
@@ -69,12 +96,99 @@ impl<Tz: TimeZone> SpecIteratorBuilder<Tz, NoStart, NoEnd, NotSealed> {
             spec: spec.to_string(),
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
             marker_sealed: PhantomData,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz>> {
-        SpecIterator::new_after(&self.spec, self.dtm, self.dst_policy)
+        Ok(
+            SpecIterator::new_after(&self.spec, self.dtm, self.dst_policy)?
+                .with_business_hours(self.business_hours)
+                .with_jitter(self.jitter),
+        )
+    }
+}
+
+impl SpecIteratorBuilder<chrono_tz::Tz, NoStart, NoEnd, NotSealed> {
+    /// Create an iterator from `Utc::now()` in the zone named by the spec's
+    /// `@<IANA zone>` suffix, or in UTC when it has none.
+    ///
+    /// ```rust
+    /// use tkone_schedule::time::SpecIteratorBuilder;
+    /// use chrono::Timelike;
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// let mut iter = SpecIteratorBuilder::from_spec("09:00:00@Europe/London")
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let next = iter.next().unwrap().unwrap();
+    /// assert_eq!(next.timezone(), chrono_tz::Europe::London);
+    /// assert_eq!(next.hour(), 9);
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let zone = spec.parse::<Spec>()?.tz.unwrap_or(chrono_tz::UTC);
+        Ok(Self::new(spec, zone))
+    }
+}
+
+impl<Tz: TimeZone> SpecIteratorBuilder<Tz, Before, NoEnd, NotSealed> {
+    /// Create an iterator that walks **backwards** from `dtm`, yielding
+    /// occurrences strictly before it in descending order.
+    ///
+    /// Intervals count back from `dtm` as `new_after` counts forward from it,
+    /// and business hours, jitter and the DST policy apply exactly as they do
+    /// going forwards. A spec whose hour or minute is pinned (`05:MM:00`,
+    /// `HH:30:SS`) repeats in every day or hour, running from the pinned value
+    /// within it. A spec without a driving component (all `At` / `AsIs`)
+    /// yields at most its latest occurrence. Iteration ends once no occurrence
+    /// is found within 1000 years of `dtm`.
+    ///
+    /// ```rust
+    /// use tkone_schedule::time::SpecIteratorBuilder;
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use fallible_iterator::FallibleIterator;
+    ///
+    /// let dtm = Utc.with_ymd_and_hms(2024, 3, 31, 10, 30, 0).unwrap();
+    /// let iter = SpecIteratorBuilder::new_before("1H:00:00", dtm).build().unwrap();
+    /// let occurrences = iter.take(3).collect::<Vec<DateTime<_>>>().unwrap();
+    ///
+    /// let at = |h| Utc.with_ymd_and_hms(2024, 3, 31, h, 0, 0).unwrap();
+    /// assert_eq!(occurrences, vec![at(10), at(9), at(8)]);
+    /// ```
+    pub fn new_before(spec: &str, dtm: DateTime<Tz>) -> Self {
+        Self {
+            timezone: dtm.timezone(),
+            dtm,
+            start: Before,
+            spec: spec.to_string(),
+            end: NoEnd,
+            dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
+            marker_sealed: PhantomData,
+        }
+    }
+
+    pub fn build(self) -> Result<ReverseSpecIterator<Tz>> {
+        let spec: Spec = self.spec.parse()?;
+        let zone = spec.tz;
+        let jitter = self
+            .jitter
+            .map_or_else(Duration::zero, |jitter| jitter.offset(spec_delta(&spec)));
+        let rewind = TimeRewind {
+            spec,
+            business_hours: self.business_hours,
+        };
+        Ok(ReverseSpecIterator {
+            tz: self.dtm.timezone(),
+            zone,
+            dst_policy: self.dst_policy,
+            jitter,
+            naive_spec_iter: NaiveReverse::new_before(rewind, local_in(&self.dtm, zone)),
+        })
     }
 }
 
@@ -92,6 +206,8 @@ impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, NoEnd, NotSealed>
             spec: spec.to_string(),
             end: NoEnd,
             dst_policy: DstPolicy::default(),
+            business_hours: None,
+            jitter: None,
             marker_sealed: PhantomData,
         }
     }
@@ -110,6 +226,8 @@ impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, NoEnd, NotSealed>
             spec: self.spec,
             end: EndDateTime(end),
             dst_policy: self.dst_policy,
+            business_hours: self.business_hours,
+            jitter: self.jitter,
             marker_sealed: PhantomData,
         }
     }
@@ -129,24 +247,38 @@ impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, NoEnd, NotSealed>
             spec: self.spec,
             end: EndSpec(end_spec.into()),
             dst_policy: self.dst_policy,
+            business_hours: self.business_hours,
+            jitter: self.jitter,
             marker_sealed: PhantomData,
         }
     }
 
     pub fn build(self) -> Result<SpecIterator<Tz>> {
-        SpecIterator::new_with_start(&self.spec, self.dtm, self.dst_policy)
+        Ok(
+            SpecIterator::new_with_start(&self.spec, self.dtm, self.dst_policy)?
+                .with_business_hours(self.business_hours)
+                .with_jitter(self.jitter),
+        )
     }
 }
 
 impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, EndDateTime<Tz>, Sealed> {
     pub fn build(self) -> Result<SpecIterator<Tz>> {
-        SpecIterator::new_with_end(&self.spec, self.dtm, self.end.0, self.dst_policy)
+        Ok(
+            SpecIterator::new_with_end(&self.spec, self.dtm, self.end.0, self.dst_policy)?
+                .with_business_hours(self.business_hours)
+                .with_jitter(self.jitter),
+        )
     }
 }
 
 impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, EndSpec, Sealed> {
     pub fn build(self) -> Result<SpecIterator<Tz>> {
-        SpecIterator::new_with_end_spec(&self.spec, self.dtm, &self.end.0, self.dst_policy)
+        Ok(
+            SpecIterator::new_with_end_spec(&self.spec, self.dtm, &self.end.0, self.dst_policy)?
+                .with_business_hours(self.business_hours)
+                .with_jitter(self.jitter),
+        )
     }
 }
 
@@ -179,54 +311,265 @@ impl<Tz: TimeZone> SpecIteratorBuilder<Tz, StartDateTime<Tz>, EndSpec, Sealed> {
 #[derive(Debug, Clone)]
 pub struct SpecIterator<Tz: TimeZone> {
     tz: Tz,
+    /// The zone named by the spec's `@` suffix, whose wall clock the naive
+    /// iterator runs on.
+    zone: Option<chrono_tz::Tz>,
     dst_policy: DstPolicy,
     naive_spec_iter: NaiveSpecIterator,
 }
 
 impl<Tz: TimeZone> SpecIterator<Tz> {
     fn new_after(spec: &str, dtm: DateTime<Tz>, dst_policy: DstPolicy) -> Result<Self> {
+        let zone = spec.parse::<Spec>()?.tz;
         Ok(Self {
             tz: dtm.timezone(),
+            zone,
             dst_policy,
-            naive_spec_iter: NaiveSpecIterator::new_after(spec, dtm.naive_local())?,
-        })
+            naive_spec_iter: NaiveSpecIterator::new_after(spec, local_in(&dtm, zone))?,
+        }
+        .with_clock(&dtm))
     }
 
     fn new_with_start(spec: &str, start: DateTime<Tz>, dst_policy: DstPolicy) -> Result<Self> {
+        let zone = spec.parse::<Spec>()?.tz;
         Ok(Self {
             tz: start.timezone(),
+            zone,
             dst_policy,
-            naive_spec_iter: NaiveSpecIterator::new_with_start(spec, start.naive_local())?,
-        })
+            naive_spec_iter: NaiveSpecIterator::new_with_start(spec, local_in(&start, zone))?,
+        }
+        .with_clock(&start))
     }
 
     fn new_with_end(spec: &str, start: DateTime<Tz>, end: DateTime<Tz>, dst_policy: DstPolicy) -> Result<Self> {
+        let zone = spec.parse::<Spec>()?.tz;
         Ok(Self {
             tz: start.timezone(),
+            zone,
             dst_policy,
             naive_spec_iter: NaiveSpecIterator::new_with_end(
                 spec,
-                start.naive_local(),
-                end.naive_local(),
+                local_in(&start, zone),
+                local_in(&end, zone),
             )?,
-        })
+        }
+        .with_clock(&start))
     }
 
     fn new_with_end_spec(spec: &str, start: DateTime<Tz>, end_spec: &str, dst_policy: DstPolicy) -> Result<Self> {
+        let zone = spec.parse::<Spec>()?.tz;
         Ok(Self {
             tz: start.timezone(),
+            zone,
             dst_policy,
             naive_spec_iter: NaiveSpecIterator::new_with_end_spec(
                 spec,
-                start.naive_local(),
+                local_in(&start, zone),
                 end_spec,
             )?,
-        })
+        }
+        .with_clock(&start))
+    }
+
+    fn with_business_hours(mut self, hours: Option<BusinessHours>) -> Self {
+        self.naive_spec_iter.business_hours = hours;
+        self
+    }
+
+    fn with_jitter(mut self, jitter: Option<Jitter>) -> Self {
+        if let Some(jitter) = jitter {
+            self.naive_spec_iter = self.naive_spec_iter.with_jitter(jitter);
+        }
+        self
+    }
+
+    /// Record the UTC offset at `cursor` when the spec steps in elapsed time,
+    /// so that each step can follow the offset changes of the wall clock.
+    fn with_clock(mut self, cursor: &DateTime<Tz>) -> Self {
+        self.naive_spec_iter.utc_offset = self.clock_offset(cursor);
+        self
+    }
+
+    fn clock_offset(&self, cursor: &DateTime<Tz>) -> Option<Duration> {
+        steps_elapsed(&self.naive_spec_iter.spec)
+            .then(|| local_in(cursor, self.zone) - cursor.naive_utc())
     }
 
     #[allow(dead_code)]
     pub(crate) fn update_cursor(&mut self, dtm: DateTime<Tz>) {
-        self.naive_spec_iter.update_cursor(dtm.naive_local());
+        self.naive_spec_iter
+            .update_cursor(local_in(&dtm, self.zone));
+        self.naive_spec_iter.utc_offset = self.clock_offset(&dtm);
+    }
+}
+
+/// ## ReverseSpecIterator
+/// An iterator that walks backwards through the occurrences of a time spec,
+/// latest first. Construct via
+/// [`SpecIteratorBuilder::new_before`](crate::time::SpecIteratorBuilder::new_before).
+#[derive(Debug, Clone)]
+pub struct ReverseSpecIterator<Tz: TimeZone> {
+    tz: Tz,
+    /// The zone named by the spec's `@` suffix, whose wall clock the naive
+    /// iterator runs on.
+    zone: Option<chrono_tz::Tz>,
+    dst_policy: DstPolicy,
+    /// Offset added to every yielded value; the scan itself runs on the
+    /// nominal times.
+    jitter: Duration,
+    naive_spec_iter: NaiveReverse<TimeRewind>,
+}
+
+impl<Tz: TimeZone> FallibleIterator for ReverseSpecIterator<Tz> {
+    type Item = DateTime<Tz>;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        let Some(next) = self.naive_spec_iter.next()? else {
+            return Ok(None);
+        };
+        let next = next + self.jitter;
+        Ok(Some(resolve_in_zone(
+            &self.tz,
+            self.zone,
+            next,
+            self.dst_policy,
+        )?))
+    }
+}
+
+/// A time spec scanned backwards by [`NaiveReverse`], in windows of the
+/// spec's driving period or of its [`pinned_period`].
+#[derive(Debug, Clone)]
+struct TimeRewind {
+    spec: Spec,
+    business_hours: Option<BusinessHours>,
+}
+
+impl Rewind for TimeRewind {
+    type Item = NaiveDateTime;
+    type Iter = RewindIter;
+
+    fn window_start(&self, dtm: NaiveDateTime, periods: u32) -> Result<Option<NaiveDateTime>> {
+        Ok(i32::try_from(periods)
+            .ok()
+            .and_then(|periods| rewind_period(&self.spec).checked_mul(periods))
+            .and_then(|span| dtm.checked_sub_signed(span)))
+    }
+
+    fn iter_from(&self, start: NaiveDateTime, scanned: NaiveDateTime) -> Result<Self::Iter> {
+        let pinned = pinned_period(&self.spec).map(|period| {
+            // Periods divide a day, so truncating the time of day aligns
+            // `start` to the start of its period.
+            let secs = i64::from(start.num_seconds_from_midnight());
+            let midnight = start.date().and_time(NaiveTime::MIN);
+            let aligned = midnight + Duration::seconds(secs - secs % period.num_seconds());
+            (aligned, period)
+        });
+        let iter = match pinned {
+            Some(_) => None,
+            None => {
+                Some(self.forward(NaiveSpecIterator::from_spec_after(self.spec.clone(), start)))
+            }
+        };
+        Ok(RewindIter {
+            rewind: self.clone(),
+            after: start,
+            scanned,
+            closed_since: None,
+            pinned,
+            iter,
+        })
+    }
+
+    fn key(item: &Self::Item) -> NaiveDateTime {
+        *item
+    }
+
+    fn is_single(&self) -> bool {
+        is_single_time(&self.spec)
+    }
+}
+
+impl TimeRewind {
+    fn forward(&self, mut iter: NaiveSpecIterator) -> NaiveSpecIterator {
+        iter.business_hours = self.business_hours.clone();
+        iter
+    }
+
+    /// A forward iterator over the occurrences within the pinned period
+    /// starting at `period`, from the first value of its `At` components.
+    fn within(&self, period: NaiveDateTime) -> NaiveSpecIterator {
+        let units = [
+            (&self.spec.hours, Duration::hours(1)),
+            (&self.spec.minutes, Duration::minutes(1)),
+            (&self.spec.seconds, Duration::seconds(1)),
+            (&self.spec.millis, Duration::milliseconds(1)),
+        ];
+        let offset = units
+            .into_iter()
+            .filter_map(|(cycle, unit)| match cycle {
+                Cycle::At(n) => Some(unit * i32::from(*n)),
+                _ => None,
+            })
+            .fold(Duration::zero(), |sum, offset| sum + offset);
+        let first = align_to_values(&self.spec, period + offset);
+        let mut iter = NaiveSpecIterator::from_spec_after(self.spec.clone(), first);
+        iter.start = Some(first);
+        self.forward(iter)
+    }
+}
+
+/// The forward occurrences of a [`TimeRewind`] after a window start.
+///
+/// Going forwards, a spec pinned by [`pinned_period`] stops at the end of
+/// the period it starts in, so the scan restarts from the first value of
+/// each period in turn, up to the one holding the end of the window.
+#[derive(Debug, Clone)]
+struct RewindIter {
+    rewind: TimeRewind,
+    after: NaiveDateTime,
+    /// No period starting after this is scanned.
+    scanned: NaiveDateTime,
+    /// The first period scanned since an occurrence was last yielded.
+    closed_since: Option<NaiveDateTime>,
+    /// The start of the next period to scan and the period length, for a
+    /// pinned spec.
+    pinned: Option<(NaiveDateTime, Duration)>,
+    iter: Option<NaiveSpecIterator>,
+}
+
+impl FallibleIterator for RewindIter {
+    type Item = NaiveDateTime;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        loop {
+            if let Some(iter) = &mut self.iter {
+                while let Some(next) = iter.next()? {
+                    if next > self.after {
+                        self.closed_since = None;
+                        return Ok(Some(next));
+                    }
+                }
+            }
+            let Some((period, length)) = self.pinned else {
+                return Ok(None);
+            };
+            if period > self.scanned {
+                return Ok(None);
+            }
+            // As going forwards, a year of occurrences outside business hours
+            // means they never open for this spec.
+            let since = *self.closed_since.get_or_insert(period);
+            if self.rewind.business_hours.is_some()
+                && period - since > Duration::days(MAX_CLOSED_DAYS)
+            {
+                return Err(Error::IteratorNotConverged);
+            }
+            self.pinned = period.checked_add_signed(length).map(|next| (next, length));
+            self.iter = Some(self.rewind.within(period));
+        }
     }
 }
 
@@ -253,18 +596,31 @@ pub struct NaiveSpecIterator {
     dtm: NaiveDateTime,
     start: Option<NaiveDateTime>,
     index: usize,
+    business_hours: Option<BusinessHours>,
+    /// Offset added to every yielded value; zero without a jitter.
+    jitter: Duration,
+    /// UTC offset of the wall clock at the cursor, known when a zoned
+    /// iterator drives a spec that steps in elapsed time.
+    utc_offset: Option<Duration>,
 }
 
 impl NaiveSpecIterator {
     pub fn new_after(spec: &str, dtm: NaiveDateTime) -> Result<Self> {
-        let spec = spec.parse()?;
-        Ok(Self {
+        Ok(Self::from_spec_after(spec.parse()?, dtm))
+    }
+
+    /// Like [`Self::new_after`] for an already parsed spec.
+    fn from_spec_after(spec: Spec, dtm: NaiveDateTime) -> Self {
+        Self {
             dtm,
             spec,
             end: None,
             start: None,
             index: 0,
-        })
+            business_hours: None,
+            jitter: Duration::zero(),
+            utc_offset: None,
+        }
     }
 
     pub fn new_with_start(spec: &str, start: NaiveDateTime) -> Result<Self> {
@@ -275,6 +631,9 @@ impl NaiveSpecIterator {
             end: None,
             start: Some(start),
             index: 0,
+            business_hours: None,
+            jitter: Duration::zero(),
+            utc_offset: None,
         })
     }
 
@@ -286,6 +645,9 @@ impl NaiveSpecIterator {
             spec,
             start: Some(start),
             index: 0,
+            business_hours: None,
+            jitter: Duration::zero(),
+            utc_offset: None,
         })
     }
 
@@ -300,12 +662,30 @@ impl NaiveSpecIterator {
             dtm: start.clone(),
             start: Some(start),
             index: 0,
+            business_hours: None,
+            jitter: Duration::zero(),
+            utc_offset: None,
         })
     }
 
+    /// Only yield occurrences that fall inside `hours`; see
+    /// [`SpecIteratorBuilder::with_business_hours`].
+    pub fn with_business_hours(mut self, hours: BusinessHours) -> Self {
+        self.business_hours = Some(hours);
+        self
+    }
+
+    /// Shift every yielded value by the stable offset of `jitter`; see
+    /// [`SpecIteratorBuilder::with_jitter`].
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter.offset(spec_delta(&self.spec));
+        self
+    }
+
     #[allow(dead_code)]
     pub(crate) fn update_cursor(&mut self, dtm: NaiveDateTime) {
         self.dtm = dtm;
+        self.utc_offset = None;
     }
 }
 
@@ -347,6 +727,30 @@ impl FallibleIterator for NaiveSpecIterator {
     type Error = Error;
 
     fn next(&mut self) -> Result<Option<Self::Item>> {
+        // Without a zone, elapsed and wall-clock time agree.
+        self.next_in(None)
+    }
+}
+
+impl NaiveSpecIterator {
+    /// The next value, with `offset_at` giving the wall clock's UTC offset at
+    /// a UTC datetime for specs that step in elapsed time.
+    fn next_in(&mut self, offset_at: Option<&OffsetAt<'_>>) -> Result<Option<NaiveDateTime>> {
+        let next = match self.business_hours.take() {
+            None => self.next_unfiltered(offset_at)?,
+            Some(hours) => {
+                let next = hours.first_open(|| self.next_unfiltered(offset_at), |dtm| *dtm);
+                self.business_hours = Some(hours);
+                next?
+            }
+        };
+        Ok(next.map(|dtm| dtm + self.jitter))
+    }
+
+    fn next_unfiltered(
+        &mut self,
+        offset_at: Option<&OffsetAt<'_>>,
+    ) -> Result<Option<NaiveDateTime>> {
         if let Some(end) = &self.end {
             if &self.dtm >= end {
                 return Ok(None);
@@ -363,71 +767,312 @@ impl FallibleIterator for NaiveSpecIterator {
             }
         }
 
-        let next = self.dtm.clone();
-
-        // When no explicit Every cadence exists, the finest ForEach component
-        // acts as Every(1) for its own unit (seconds > minutes > hours).
-        // Coarser ForEach components carry the value forward unchanged.
-        // AsIs is a true no-op — it always carries the current value.
-        let has_any_every = matches!(self.spec.seconds, Cycle::Every(_))
-            || matches!(self.spec.minutes, Cycle::Every(_))
-            || matches!(self.spec.hours, Cycle::Every(_));
-        let seconds_is_foreach = matches!(self.spec.seconds, Cycle::ForEach);
-        let minutes_is_foreach = matches!(self.spec.minutes, Cycle::ForEach);
-
-        let next = match &self.spec.seconds {
-            Cycle::At(s) => next.with_second(*s as u32).unwrap(),
-            Cycle::Every(s) => next + Duration::seconds(*s as i64),
-            Cycle::ForEach if !has_any_every => next + Duration::seconds(1),
-            Cycle::ForEach | Cycle::AsIs => next,
-        };
-
-        let next = match &self.spec.minutes {
-            Cycle::At(m) => next.with_minute(*m as u32).unwrap(),
-            Cycle::Every(m) => next + Duration::minutes(*m as i64),
-            Cycle::ForEach if !has_any_every && !seconds_is_foreach => next + Duration::minutes(1),
-            Cycle::ForEach | Cycle::AsIs => next,
-        };
+        let mut next = apply_time_spec(&self.spec, self.dtm);
 
-        let next = match &self.spec.hours {
-            Cycle::At(h) => next.with_hour(*h as u32).unwrap(),
-            Cycle::Every(h) => next + Duration::hours(*h as i64),
-            Cycle::ForEach if !has_any_every && !seconds_is_foreach && !minutes_is_foreach => {
-                next + Duration::hours(1)
+        // An interval longer than its unit runs in elapsed time: the wall-clock
+        // result moves by any change in UTC offset since the cursor.
+        let mut next_offset = None;
+        if let (Some(offset), Some(offset_at)) = (self.utc_offset, offset_at) {
+            if let Some(utc) = next.checked_sub_signed(offset) {
+                let offset = offset_at(utc);
+                next = utc.checked_add_signed(offset).unwrap_or(NaiveDateTime::MAX);
+                next_offset = Some(offset);
             }
-            Cycle::ForEach | Cycle::AsIs => next,
-        };
+        }
 
         // No-progress guard: all-At and all-AsIs specs produce next == self.dtm,
-        // which would loop forever without this check.
-        if next <= self.dtm {
+        // which would loop forever without this check. An interval that runs
+        // past the end of the calendar saturates and ends iteration too.
+        if next <= self.dtm || next == NaiveDateTime::MAX {
             return Ok(None);
         }
 
         if let Some(end) = &self.end {
             if &next > end {
                 self.dtm = end.clone();
+                self.utc_offset = None;
                 self.index += 1;
                 return Ok(Some(end.clone()));
             }
         };
 
         self.dtm = next;
+        self.utc_offset = next_offset.or(self.utc_offset);
         self.index += 1;
         Ok(Some(self.dtm.clone()))
     }
 }
 
+/// Applies `spec` once to `cursor`, producing the next candidate datetime.
+///
+/// Components are applied finest first (milliseconds → seconds → minutes →
+/// hours), each either `At(n)` (set absolute) or `Every(n)` (add delta).
+/// When no explicit `Every` cadence exists, the finest `ForEach` component
+/// acts as `Every(1)` for its own unit and coarser `ForEach` components carry
+/// the value forward unchanged. `AsIs` is a true no-op — it always carries the
+/// current value, including any sub-second part. `Every(n)` may exceed its
+/// unit's clock range: the overflow carries into coarser fields and following
+/// days exactly as duration arithmetic on the wall-clock datetime does.
+/// `Values` and `Range` take
+/// part in the driver election like `ForEach` and are then enforced by
+/// [`align_to_values`].
+pub(crate) fn apply_time_spec(spec: &Spec, cursor: NaiveDateTime) -> NaiveDateTime {
+    let has_any_every = has_any_every(spec);
+    let millis_is_foreach = is_foreach(&spec.millis);
+    let seconds_is_foreach = is_foreach(&spec.seconds);
+    let minutes_is_foreach = is_foreach(&spec.minutes);
+
+    let next = match &spec.millis {
+        Cycle::At(ms) => cursor.with_nanosecond(*ms as u32 * 1_000_000).unwrap(),
+        Cycle::Every(ms) => add_saturating(cursor, Duration::milliseconds(*ms as i64)),
+        Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..) if !has_any_every => {
+            cursor + Duration::milliseconds(1)
+        }
+        Cycle::ForEach | Cycle::AsIs | Cycle::Values(_) | Cycle::Range(..) => cursor,
+    };
+
+    let next = match &spec.seconds {
+        Cycle::At(s) => next.with_second(*s as u32).unwrap(),
+        Cycle::Every(s) => add_saturating(next, Duration::seconds(*s as i64)),
+        Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..)
+            if !has_any_every && !millis_is_foreach =>
+        {
+            next + Duration::seconds(1)
+        }
+        Cycle::ForEach | Cycle::AsIs | Cycle::Values(_) | Cycle::Range(..) => next,
+    };
+
+    let next = match &spec.minutes {
+        Cycle::At(m) => next.with_minute(*m as u32).unwrap(),
+        Cycle::Every(m) => add_saturating(next, Duration::minutes(*m as i64)),
+        Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..)
+            if !has_any_every && !millis_is_foreach && !seconds_is_foreach =>
+        {
+            next + Duration::minutes(1)
+        }
+        Cycle::ForEach | Cycle::AsIs | Cycle::Values(_) | Cycle::Range(..) => next,
+    };
+
+    let next = match &spec.hours {
+        Cycle::At(h) => next.with_hour(*h as u32).unwrap(),
+        Cycle::Every(h) => add_saturating(next, Duration::hours(*h as i64)),
+        Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..)
+            if !has_any_every
+                && !millis_is_foreach
+                && !seconds_is_foreach
+                && !minutes_is_foreach =>
+        {
+            next + Duration::hours(1)
+        }
+        Cycle::ForEach | Cycle::AsIs | Cycle::Values(_) | Cycle::Range(..) => next,
+    };
+
+    if next == NaiveDateTime::MAX {
+        return next;
+    }
+    align_to_values(spec, next)
+}
+
+/// Whether an `Every` interval of `spec` is longer than its unit (`36H`,
+/// `90M`). A zoned iterator steps such a spec in elapsed time.
+pub(crate) fn steps_elapsed(spec: &Spec) -> bool {
+    let over = |cycle: &Cycle, range: u32| matches!(cycle, Cycle::Every(n) if *n > range);
+    over(&spec.hours, 24)
+        || over(&spec.minutes, 60)
+        || over(&spec.seconds, 60)
+        || over(&spec.millis, 1000)
+}
+
+/// The UTC offset of a wall clock at a UTC datetime.
+type OffsetAt<'a> = dyn Fn(NaiveDateTime) -> Duration + 'a;
+
+/// `dtm + delta`, saturating at [`NaiveDateTime::MAX`] so that an interval
+/// running past the end of the calendar ends iteration instead of panicking.
+fn add_saturating(dtm: NaiveDateTime, delta: Duration) -> NaiveDateTime {
+    dtm.checked_add_signed(delta).unwrap_or(NaiveDateTime::MAX)
+}
+
+/// Return the natural step size of the driving component of a time spec.
+///
+/// Rules (finest-to-coarsest, first match wins):
+/// - `Every(n)` on milliseconds → `n` milliseconds
+/// - `ForEach` / `Values` / `Range` on milliseconds (and no `Every` anywhere) → 1 millisecond
+/// - `Every(n)` on seconds  → `n` seconds
+/// - `ForEach` / `Values` / `Range` on seconds (and no `Every` anywhere) → 1 second
+/// - `Every(n)` on minutes  → `n` minutes
+/// - `ForEach` / `Values` / `Range` on minutes (and no `Every` anywhere) → 1 minute
+/// - `Every(n)` on hours    → `n` hours
+/// - `ForEach` / `Values` / `Range` on hours (and no `Every` anywhere) → 1 hour
+/// - All `At` / `AsIs`      → 1 second
+pub(crate) fn spec_delta(spec: &Spec) -> Duration {
+    let has_any_every = has_any_every(spec);
+    let step = |cycle: &Cycle, unit_ms: i64| match cycle {
+        Cycle::Every(n) => Some(Duration::milliseconds(unit_ms * *n as i64)),
+        Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..) if !has_any_every => {
+            Some(Duration::milliseconds(unit_ms))
+        }
+        _ => None,
+    };
+
+    step(&spec.millis, 1)
+        .or_else(|| step(&spec.seconds, 1_000))
+        .or_else(|| step(&spec.minutes, 60_000))
+        .or_else(|| step(&spec.hours, 3_600_000))
+        .unwrap_or(Duration::seconds(1))
+}
+
+/// The length of one backwards scan window for `spec`: its step, or its
+/// [`pinned_period`] when it has one.
+fn rewind_period(spec: &Spec) -> Duration {
+    pinned_period(spec).map_or_else(|| spec_delta(spec), |period| spec_delta(spec).max(period))
+}
+
+/// The period of the coarsest `At` component of `spec` (a day for an hour,
+/// an hour for a minute, …) when nothing coarser drives it on, as in
+/// `05:00:00`, `05:MM:00` or `HH:30:SS`. Going forwards such a spec only
+/// runs within that period: once its driver rolls over, the `At` component
+/// sets it back.
+fn pinned_period(spec: &Spec) -> Option<Duration> {
+    let cycles = [&spec.hours, &spec.minutes, &spec.seconds, &spec.millis];
+    let pinned = cycles
+        .iter()
+        .position(|cycle| matches!(cycle, Cycle::At(_)))?;
+    let drives: fn(&Cycle) -> bool = if has_any_every(spec) {
+        |cycle| matches!(cycle, Cycle::Every(_))
+    } else {
+        is_foreach
+    };
+    let driver = cycles.iter().rposition(|cycle| drives(cycle));
+    if driver.is_some_and(|driver| driver < pinned) {
+        return None;
+    }
+    let periods = [
+        Duration::days(1),
+        Duration::hours(1),
+        Duration::minutes(1),
+        Duration::seconds(1),
+    ];
+    Some(periods[pinned])
+}
+
+/// Whether `spec` pins a single time of day, with no `Every` or `ForEach` /
+/// `Values` / `Range` component.
+pub(crate) fn is_single_time(spec: &Spec) -> bool {
+    let cycles = [&spec.hours, &spec.minutes, &spec.seconds, &spec.millis];
+    !has_any_every(spec) && !cycles.into_iter().any(is_foreach)
+}
+
+fn has_any_every(spec: &Spec) -> bool {
+    [&spec.hours, &spec.minutes, &spec.seconds, &spec.millis]
+        .into_iter()
+        .any(|cycle| matches!(cycle, Cycle::Every(_)))
+}
+
+fn is_foreach(cycle: &Cycle) -> bool {
+    matches!(cycle, Cycle::ForEach | Cycle::Values(_) | Cycle::Range(..))
+}
+
+/// Rolls `dtm` forward to the earliest datetime at or after it whose fields
+/// satisfy every `Values` / `Range` component of `spec`.
+///
+/// `At` components are enforced too, so that rolling over into the next hour
+/// or day lands back on the pinned value. When a field is moved, the finer
+/// fields restart from their first allowed value; `AsIs` fields keep theirs.
+/// Specs without any `Values` or `Range` component are returned unchanged.
+pub(crate) fn align_to_values(spec: &Spec, dtm: NaiveDateTime) -> NaiveDateTime {
+    let constrained = [&spec.hours, &spec.minutes, &spec.seconds, &spec.millis]
+        .into_iter()
+        .any(|cycle| matches!(cycle, Cycle::Values(_) | Cycle::Range(..)));
+    if !constrained {
+        return dtm;
+    }
+
+    let restart = |cycle: &Cycle, current: u32| match cycle {
+        Cycle::AsIs => current,
+        _ => 0,
+    };
+
+    let mut next = dtm;
+    loop {
+        let (hour, minute, second) = (next.hour(), next.minute(), next.second());
+        let nano = next.nanosecond();
+        let minute_restart = restart(&spec.minutes, minute);
+        let second_restart = restart(&spec.seconds, second);
+        let nano_restart = restart(&spec.millis, nano);
+        let at = |date: NaiveDate, h, m, s, ns| date.and_hms_nano_opt(h, m, s, ns).unwrap();
+
+        if !allows(&spec.hours, hour) {
+            next = match next_allowed(&spec.hours, hour) {
+                Some(h) => at(next.date(), h, minute_restart, second_restart, nano_restart),
+                None => at(
+                    next.date() + Duration::days(1),
+                    0,
+                    minute_restart,
+                    second_restart,
+                    nano_restart,
+                ),
+            };
+        } else if !allows(&spec.minutes, minute) {
+            next = match next_allowed(&spec.minutes, minute) {
+                Some(m) => at(next.date(), hour, m, second_restart, nano_restart),
+                None => at(next.date(), hour, 0, second_restart, nano_restart) + Duration::hours(1),
+            };
+        } else if !allows(&spec.seconds, second) {
+            next = match next_allowed(&spec.seconds, second) {
+                Some(s) => at(next.date(), hour, minute, s, nano_restart),
+                None => at(next.date(), hour, minute, 0, nano_restart) + Duration::minutes(1),
+            };
+        } else if !allows(&spec.millis, nano / 1_000_000) {
+            next = match next_allowed(&spec.millis, nano / 1_000_000) {
+                Some(ms) => at(next.date(), hour, minute, second, ms * 1_000_000),
+                None => at(next.date(), hour, minute, second, 0) + Duration::seconds(1),
+            };
+        } else {
+            return next;
+        }
+    }
+}
+
+/// Whether `value` satisfies `cycle` when aligning a spec with value constraints.
+fn allows(cycle: &Cycle, value: u32) -> bool {
+    match cycle {
+        Cycle::At(v) => value == *v as u32,
+        Cycle::Values(vals) => vals.contains(&(value as u16)),
+        Cycle::Range(lo, hi) => (*lo as u32..=*hi as u32).contains(&value),
+        Cycle::AsIs | Cycle::ForEach | Cycle::Every(_) => true,
+    }
+}
+
+/// The smallest value at or after `value` that satisfies `cycle`, if any.
+fn next_allowed(cycle: &Cycle, value: u32) -> Option<u32> {
+    match cycle {
+        Cycle::At(v) => (*v as u32 >= value).then_some(*v as u32),
+        Cycle::Values(vals) => vals.range(value as u16..).next().map(|v| *v as u32),
+        Cycle::Range(lo, hi) => (value <= *hi as u32).then_some(value.max(*lo as u32)),
+        Cycle::AsIs | Cycle::ForEach | Cycle::Every(_) => Some(value),
+    }
+}
+
 impl<Tz: TimeZone> FallibleIterator for SpecIterator<Tz> {
     type Item = DateTime<Tz>;
     type Error = Error;
 
     fn next(&mut self) -> Result<Option<Self::Item>> {
-        let item = self.naive_spec_iter.next()?;
+        let (tz, zone) = (&self.tz, self.zone);
+        let offset_at = |utc: NaiveDateTime| match zone {
+            Some(zone) => zone.from_utc_datetime(&utc).naive_local() - utc,
+            None => tz.from_utc_datetime(&utc).naive_local() - utc,
+        };
+        let item = self.naive_spec_iter.next_in(Some(&offset_at))?;
         let Some(next) = item else {
             return Ok(None);
         };
-        Ok(Some(resolve_local(&self.tz, next, self.dst_policy)?))
+        // A value stepped in elapsed time knows its offset, which also tells
+        // the two passes of a repeated hour apart.
+        if let Some(offset) = self.naive_spec_iter.utc_offset {
+            return Ok(Some(self.tz.from_utc_datetime(&(next - offset))));
+        }
+        Ok(Some(resolve_in_zone(&self.tz, self.zone, next, self.dst_policy)?))
     }
 }
 
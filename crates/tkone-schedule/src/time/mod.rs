@@ -12,21 +12,24 @@
 //! ## Spec Syntax
 //!
 //! ```text
-//! <hours>:<minutes>:<seconds>
+//! <hours>:<minutes>:<seconds>[.<millis>][@<IANA zone>]
 //! ```
 //!
 //! Each component is one of:
 //!
 //! | Token | Meaning |
 //! |-------|---------|
-//! | `HH` / `MM` / `SS` | ForEach — drives if no `Every(n)` is present |
+//! | `HH` / `MM` / `SS` / `MS` | ForEach — drives if no `Every(n)` is present |
 //! | `_` | AsIs — keep the current value unchanged |
-//! | `nH` / `nM` / `nS` | Every *n* hours / minutes / seconds |
-//! | `09` / `30` / `00` | At — pin to that exact value |
+//! | `nH` / `nM` / `nS` / `nMS` | Every *n* hours / minutes / seconds / milliseconds; *n* may exceed the unit (`36H`, `90M`) |
+//! | `09` / `30` / `00` / `250` | At — pin to that exact value (3 digits for milliseconds) |
+//! | `[09,13,17]` | Values — restrict to the listed values |
+//! | `09-17` | Range — restrict to an inclusive range |
 //!
 //! **ForEach driving rule**: when no `Every` component is present the finest
 //! `ForEach` field becomes `Every(1)` for its unit; coarser `ForEach` fields
-//! carry their value unchanged.
+//! carry their value unchanged. `Values` and `Range` are elected like `ForEach`
+//! and step to their next allowed value; next to an `Every` they only filter.
 //!
 //! ## Examples
 //!
@@ -56,6 +59,7 @@ mod spec;
 #[cfg(test)]
 mod tests;
 
-pub use iter::{NaiveSpecIterator, SpecIterator, SpecIteratorBuilder};
+pub use iter::{NaiveSpecIterator, ReverseSpecIterator, SpecIterator, SpecIteratorBuilder};
+pub(crate) use iter::{apply_time_spec, is_single_time, spec_delta, steps_elapsed};
 
 pub use spec::{Cycle, Spec};
@@ -1,4 +1,6 @@
 use crate::prelude::*;
+use crate::utils::split_zone;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::str::FromStr;
 
@@ -7,9 +9,10 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{char, digit1},
-    combinator::{all_consuming, map_res, value, verify},
+    combinator::{all_consuming, map, map_res, opt, value, verify},
     error::Error as NomError,
-    sequence::preceded,
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair},
     IResult,
 };
 
@@ -19,7 +22,15 @@ use nom::{
 /// The `Spec` struct is used to define specification for time to support flexible scheduling options.
 /// Best way to instantiate a `Spec` is to parse it from a string using the format `HH:MM:SS`,
 /// where each component is one of: `HH`/`MM`/`SS` (ForEach), `_` (AsIs), `nH`/`nM`/`nS` (Every),
-/// or a 2-digit number (At).
+/// a 2-digit number (At), a value list such as `[09,13,17]` (Values) or an inclusive range
+/// such as `09-17` (Range).
+///
+/// An optional millisecond component follows the seconds after a `.`: `HH:MM:SS.250MS`
+/// (Every), `HH:MM:SS.000` (At, 3 digits), `.[000,500]` (Values), `.000-499` (Range),
+/// `.MS` (ForEach) or `._` (AsIs). Without it, milliseconds are `AsIs`.
+///
+/// An optional `@<IANA zone>` suffix, such as `09:00:00@Europe/London`, names the timezone
+/// the spec is evaluated in; see [`Spec::tz`].
 ///
 /// ### Examples
 ///
@@ -30,30 +41,58 @@ use nom::{
 /// assert_eq!(spec.hours, Cycle::Every(1));
 /// assert_eq!(spec.minutes, Cycle::At(30));
 /// assert_eq!(spec.seconds, Cycle::ForEach);
+///
+/// let spec = "09-17:30M:00".parse::<Spec>().unwrap();
+/// assert_eq!(spec.hours, Cycle::Range(9, 17));
+///
+/// let spec = "HH:MM:SS.250MS".parse::<Spec>().unwrap();
+/// assert_eq!(spec.millis, Cycle::Every(250));
+///
+/// let spec = "09:00:00@Europe/London".parse::<Spec>().unwrap();
+/// assert_eq!(spec.tz, Some(chrono_tz::Europe::London));
 /// ```
-#[derive(Default, Debug, PartialEq, Clone)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Spec {
     pub hours: Cycle,
     pub minutes: Cycle,
     pub seconds: Cycle,
+    /// Milliseconds within the second; `AsIs` when the spec has no `.` part.
+    pub millis: Cycle,
+    /// The zone named by an `@<IANA zone>` suffix.
+    ///
+    /// Timezone-aware iterators evaluate the spec on this zone's wall clock
+    /// and convert each occurrence to the timezone of the datetime they were
+    /// built from. Without a suffix, that datetime's own timezone is used.
+    pub tz: Option<chrono_tz::Tz>,
 }
 
 /// ## Cycle
-/// Describes how a single time component (hours, minutes, or seconds) advances on each tick.
+/// Describes how a single time component (hours, minutes, seconds or milliseconds) advances on
+/// each tick.
 ///
 /// | Variant | Syntax | Meaning |
 /// |---------|--------|---------|
 /// | `AsIs`     | `_`          | Keep current value unchanged (no-op) |
-/// | `ForEach`  | `HH`/`MM`/`SS` | Advance each occurrence; acts as `Every(1)` for the finest component when no `Every` is present |
+/// | `ForEach`  | `HH`/`MM`/`SS`/`MS` | Advance each occurrence; acts as `Every(1)` for the finest component when no `Every` is present |
 /// | `At(n)`    | `09`, `30`, `45` | Pin the field to exact value *n* |
-/// | `Every(n)` | `1H`, `30M`, `15S` | Add a duration of *n* units on each tick |
+/// | `Every(n)` | `1H`, `36H`, `90M`, `250MS` | Add a duration of *n* units on each tick |
+/// | `Values(set)` | `[09,13,17]` | Restrict the field to the listed values |
+/// | `Range(lo, hi)` | `09-17` | Restrict the field to `lo..=hi` |
+///
+/// `Values` and `Range` drive like `ForEach` when no `Every` is present, stepping
+/// to the next allowed value; alongside an `Every` they only filter its ticks.
 #[derive(Default, Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Cycle {
     #[default]
     AsIs,
     ForEach,
-    At(u8),
-    Every(u8),
+    At(u16),
+    /// Any positive interval; it may exceed its unit's clock range (`36H`, `90M`) and
+    /// carries into the coarser fields and following days. Such a longer interval is
+    /// measured in elapsed time, so it keeps its length across DST changes.
+    Every(u32),
+    Values(BTreeSet<u16>),
+    Range(u16, u16),
 }
 
 // ---------------------------------------------------------------------------
@@ -62,18 +101,41 @@ pub enum Cycle {
 
 type Res<'a, T> = IResult<&'a str, T, NomError<&'a str>>;
 
-fn parse_u8(input: &str) -> Res<'_, u8> {
+fn parse_u16(input: &str) -> Res<'_, u16> {
     map_res(digit1, str::parse).parse(input)
 }
 
+fn parse_u32(input: &str) -> Res<'_, u32> {
+    map_res(digit1, str::parse).parse(input)
+}
+
+/// Parses a bracketed value list (`[09,13,17]`) or an inclusive range (`09-17`)
+/// whose values are all at most `max`.
+fn parse_constraint(max: u16) -> impl FnMut(&str) -> Res<'_, Cycle> {
+    move |input: &str| {
+        let value = move |input| verify(parse_u16, |&n| n <= max).parse(input);
+        alt((
+            map(
+                delimited(char('['), separated_list1(char(','), value), char(']')),
+                |vals| Cycle::Values(vals.into_iter().collect()),
+            ),
+            map(
+                verify(separated_pair(value, char('-'), value), |(lo, hi)| lo <= hi),
+                |(lo, hi)| Cycle::Range(lo, hi),
+            ),
+        ))
+        .parse(input)
+    }
+}
+
 fn parse_hours_every(input: &str) -> Res<'_, Cycle> {
-    let (input, n) = verify(parse_u8, |&n| n > 0).parse(input)?;
+    let (input, n) = verify(parse_u32, |&n| n > 0).parse(input)?;
     let (input, _) = char('H').parse(input)?;
     Ok((input, Cycle::Every(n)))
 }
 
 fn parse_hours_at(input: &str) -> Res<'_, Cycle> {
-    let (input, n) = parse_u8(input)?;
+    let (input, n) = parse_u16(input)?;
     Ok((input, Cycle::At(n)))
 }
 
@@ -81,6 +143,7 @@ fn parse_hours_cycle(input: &str) -> Res<'_, Cycle> {
     alt((
         value(Cycle::ForEach, tag("HH")),
         value(Cycle::AsIs, tag("_")),
+        parse_constraint(23),
         parse_hours_every,
         parse_hours_at,
     ))
@@ -88,13 +151,13 @@ fn parse_hours_cycle(input: &str) -> Res<'_, Cycle> {
 }
 
 fn parse_minutes_every(input: &str) -> Res<'_, Cycle> {
-    let (input, n) = verify(parse_u8, |&n| n > 0).parse(input)?;
+    let (input, n) = verify(parse_u32, |&n| n > 0).parse(input)?;
     let (input, _) = char('M').parse(input)?;
     Ok((input, Cycle::Every(n)))
 }
 
 fn parse_minutes_at(input: &str) -> Res<'_, Cycle> {
-    let (input, n) = parse_u8(input)?;
+    let (input, n) = parse_u16(input)?;
     Ok((input, Cycle::At(n)))
 }
 
@@ -102,6 +165,7 @@ fn parse_minutes_cycle(input: &str) -> Res<'_, Cycle> {
     alt((
         value(Cycle::ForEach, tag("MM")),
         value(Cycle::AsIs, tag("_")),
+        parse_constraint(59),
         parse_minutes_every,
         parse_minutes_at,
     ))
@@ -109,13 +173,13 @@ fn parse_minutes_cycle(input: &str) -> Res<'_, Cycle> {
 }
 
 fn parse_seconds_every(input: &str) -> Res<'_,  Cycle> {
-    let (input, n) = verify(parse_u8, |&n| n > 0).parse(input)?;
+    let (input, n) = verify(parse_u32, |&n| n > 0).parse(input)?;
     let (input, _) = char('S').parse(input)?;
     Ok((input, Cycle::Every(n)))
 }
 
 fn parse_seconds_at(input: &str) -> Res<'_, Cycle> {
-    let (input, n) = parse_u8(input)?;
+    let (input, n) = parse_u16(input)?;
     Ok((input, Cycle::At(n)))
 }
 
@@ -123,23 +187,50 @@ fn parse_seconds_cycle(input: &str) -> Res<'_, Cycle> {
     alt((
         value(Cycle::ForEach, tag("SS")),
         value(Cycle::AsIs, tag("_")),
+        parse_constraint(59),
         parse_seconds_every,
         parse_seconds_at,
     ))
     .parse(input)
 }
 
+fn parse_millis_every(input: &str) -> Res<'_, Cycle> {
+    let (input, n) = verify(parse_u32, |&n| n > 0).parse(input)?;
+    let (input, _) = tag("MS").parse(input)?;
+    Ok((input, Cycle::Every(n)))
+}
+
+fn parse_millis_at(input: &str) -> Res<'_, Cycle> {
+    let (input, n) = verify(parse_u16, |&n| n <= 999).parse(input)?;
+    Ok((input, Cycle::At(n)))
+}
+
+fn parse_millis_cycle(input: &str) -> Res<'_, Cycle> {
+    alt((
+        value(Cycle::ForEach, tag("MS")),
+        value(Cycle::AsIs, tag("_")),
+        parse_constraint(999),
+        parse_millis_every,
+        parse_millis_at,
+    ))
+    .parse(input)
+}
+
 fn parse_spec(input: &str) -> Result<Spec> {
+    let (input, tz) = split_zone(input)?;
     let full_parser = (
         parse_hours_cycle,
         preceded(char(':'), parse_minutes_cycle),
         preceded(char(':'), parse_seconds_cycle),
+        opt(preceded(char('.'), parse_millis_cycle)),
     );
     match all_consuming(full_parser).parse(input) {
-        Ok((_, (hours, minutes, seconds))) => Ok(Spec {
+        Ok((_, (hours, minutes, seconds, millis))) => Ok(Spec {
             hours,
             minutes,
             seconds,
+            millis: millis.unwrap_or_default(),
+            tz,
         }),
         Err(_) => Err(Error::InvalidTimeSpec(format!("failed to parse: {input}"))),
     }
@@ -159,21 +250,33 @@ impl FromStr for Spec {
 
 impl fmt::Display for Spec {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let fmt_cycle = |cycle: &Cycle, letter: char| -> String {
+        let fmt_cycle = |cycle: &Cycle, foreach: &str, unit: &str, width: usize| -> String {
             match cycle {
                 Cycle::AsIs => "_".to_string(),
-                Cycle::ForEach => format!("{}{}", letter, letter),
-                Cycle::At(n) => format!("{:02}", n),
-                Cycle::Every(n) => format!("{}{}", n, letter),
+                Cycle::ForEach => foreach.to_string(),
+                Cycle::At(n) => format!("{:0width$}", n),
+                Cycle::Every(n) => format!("{}{}", n, unit),
+                Cycle::Values(vals) => {
+                    let vals: Vec<String> = vals.iter().map(|v| format!("{:0width$}", v)).collect();
+                    format!("[{}]", vals.join(","))
+                }
+                Cycle::Range(lo, hi) => format!("{:0width$}-{:0width$}", lo, hi),
             }
         };
         write!(
             f,
             "{}:{}:{}",
-            fmt_cycle(&self.hours, 'H'),
-            fmt_cycle(&self.minutes, 'M'),
-            fmt_cycle(&self.seconds, 'S'),
-        )
+            fmt_cycle(&self.hours, "HH", "H", 2),
+            fmt_cycle(&self.minutes, "MM", "M", 2),
+            fmt_cycle(&self.seconds, "SS", "S", 2),
+        )?;
+        if self.millis != Cycle::AsIs {
+            write!(f, ".{}", fmt_cycle(&self.millis, "MS", "MS", 3))?;
+        }
+        match &self.tz {
+            Some(tz) => write!(f, "@{}", tz.name()),
+            None => Ok(()),
+        }
     }
 }
 
@@ -230,6 +333,92 @@ mod tests {
         assert!("HH:MM:0S".parse::<Spec>().is_err(), "0S should be a parse error");
     }
 
+    #[test]
+    fn test_time_spec_every_beyond_unit_range() {
+        let spec = "36H:00:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.hours, Cycle::Every(36));
+        let spec = "HH:90M:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.minutes, Cycle::Every(90));
+        let spec = "HH:MM:SS.86400000MS".parse::<Spec>().unwrap();
+        assert_eq!(spec.millis, Cycle::Every(86_400_000));
+
+        assert!(
+            "4294967296H:00:00".parse::<Spec>().is_err(),
+            "u32 overflow should be a parse error"
+        );
+    }
+
+    #[test]
+    fn test_time_spec_zone_suffix() {
+        let spec = "09:00:00@Europe/London".parse::<Spec>().unwrap();
+        assert_eq!(spec.hours, Cycle::At(9));
+        assert_eq!(spec.tz, Some(chrono_tz::Europe::London));
+        assert_eq!("09:00:00".parse::<Spec>().unwrap().tz, None);
+
+        assert_eq!(
+            "09:00:00@Mars/Olympus".parse::<Spec>(),
+            Err(Error::InvalidTimezone("Mars/Olympus".to_string()))
+        );
+        assert!(
+            "09:00:00@".parse::<Spec>().is_err(),
+            "empty zone should be a parse error"
+        );
+    }
+
+    #[test]
+    fn test_time_spec_values_and_ranges() {
+        let spec = "[13,09,17]:00:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.hours, Cycle::Values(BTreeSet::from([9, 13, 17])));
+        assert_eq!(spec.to_string(), "[09,13,17]:00:00");
+
+        let spec = "09-17:30M:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.hours, Cycle::Range(9, 17));
+        assert_eq!(spec.minutes, Cycle::Every(30));
+
+        assert!(
+            "[09,24]:00:00".parse::<Spec>().is_err(),
+            "hour 24 should be a parse error"
+        );
+        assert!(
+            "HH:[00,60]:00".parse::<Spec>().is_err(),
+            "minute 60 should be a parse error"
+        );
+        assert!(
+            "17-09:00:00".parse::<Spec>().is_err(),
+            "reversed range should be a parse error"
+        );
+        assert!(
+            "[]:00:00".parse::<Spec>().is_err(),
+            "empty list should be a parse error"
+        );
+    }
+
+    #[test]
+    fn test_time_spec_millis() {
+        let spec = "HH:MM:SS.250MS".parse::<Spec>().unwrap();
+        assert_eq!(spec.seconds, Cycle::ForEach);
+        assert_eq!(spec.millis, Cycle::Every(250));
+
+        let spec = "HH:MM:00".parse::<Spec>().unwrap();
+        assert_eq!(spec.millis, Cycle::AsIs);
+        let spec = "HH:MM:00._".parse::<Spec>().unwrap();
+        assert_eq!(spec.millis, Cycle::AsIs);
+        assert_eq!(spec.to_string(), "HH:MM:00");
+
+        assert!(
+            "HH:MM:SS.1000".parse::<Spec>().is_err(),
+            "1000ms should be a parse error"
+        );
+        assert!(
+            "HH:MM:SS.0MS".parse::<Spec>().is_err(),
+            "0MS should be a parse error"
+        );
+        assert!(
+            "HH:MM:SS.".parse::<Spec>().is_err(),
+            "empty millis should be a parse error"
+        );
+    }
+
     #[test]
     fn test_time_spec_roundtrip() {
         for s in &[
@@ -241,6 +430,20 @@ mod tests {
             "09:30:00",
             "HH:MM:30S",
             "2H:15:00",
+            "[09,13,17]:00:00",
+            "09-17:30M:00",
+            "HH:[00,30]:00",
+            "[09,17]:00-29:SS",
+            "HH:MM:SS.250MS",
+            "HH:MM:SS.000",
+            "HH:MM:SS.[000,500]",
+            "1H:00:00.000-499",
+            "HH:MM:SS.MS",
+            "36H:00:00",
+            "HH:90M:00",
+            "HH:MM:3600S",
+            "09:00:00@Europe/London",
+            "HH:30M:00.000@America/New_York",
         ] {
             let parsed = s.parse::<Spec>().unwrap();
             assert_eq!(&parsed.to_string(), s, "roundtrip failed for {}", s);
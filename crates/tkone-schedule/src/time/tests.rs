@@ -649,3 +649,649 @@ fn test_asis_recurrence() {
         },
     ]);
 }
+
+// ---------------------------------------------------------------------------
+// Group 11: Value lists and ranges — [09,13,17] / 09-17
+//
+// With no Every present a list or range takes part in the driver election
+// like ForEach and steps to the next allowed value, rolling over into the
+// next hour or day. Alongside an Every it only filters the Every's ticks;
+// leaving the range restarts at its first value with finer fields reset.
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_value_lists_and_ranges() {
+    let tz = Utc;
+    run_cases(vec![
+        // [09,13,17]:00:00 — three fixed times a day
+        TestCase {
+            spec: "[09,13,17]:00:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            take: 5,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 13, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 17, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap(), // next day
+                tz.with_ymd_and_hms(2025, 1, 2, 13, 0, 0).unwrap(),
+            ],
+        },
+        // 09-17:30M:00 — every 30 minutes while the hour is 09 through 17
+        TestCase {
+            spec: "09-17:30M:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 16, 30, 0).unwrap(),
+            take: 5,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 16, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 17, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 17, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap(), // range restarts
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 30, 0).unwrap(),
+            ],
+        },
+        // HH:[00,30]:00 — minute list drives, hours carry
+        TestCase {
+            spec: "HH:[00,30]:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            take: 4,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 10, 30, 0).unwrap(),
+            ],
+        },
+        // [09,17]:[00,30]:00 — both lists; minutes drive, hours filter
+        TestCase {
+            spec: "[09,17]:[00,30]:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            take: 5,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 17, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 17, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap(),
+            ],
+        },
+        // 09:[00,30]:00 — pinned hour is kept when the minute list rolls over
+        TestCase {
+            spec: "09:[00,30]:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            take: 3,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap(),
+            ],
+        },
+        // [09,13]:_:00 — AsIs minute carries across the jump
+        TestCase {
+            spec: "[09,13]:_:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 22, 0).unwrap(),
+            take: 3,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 22, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 1, 13, 22, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 9, 22, 0).unwrap(),
+            ],
+        },
+    ]);
+}
+
+#[test]
+fn test_value_lists_and_ranges_new_after() {
+    let tz = Utc;
+    // A start before the range lands on its first value, not on start + 30M.
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 8, 10, 0).unwrap();
+    let results: Vec<DateTime<_>> = SpecIteratorBuilder::new_after("09-17:30M:00", start)
+        .build()
+        .unwrap()
+        .take(2)
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 30, 0).unwrap(),
+        ]
+    );
+
+    let spec = "[09,13,17]:00:00".parse::<Spec>().unwrap();
+    assert_eq!(spec.hours, Cycle::Values([9, 13, 17].into_iter().collect()));
+}
+
+// ---------------------------------------------------------------------------
+// Group 12: Business-hours filter
+//
+// Occurrences outside the windows are dropped without re-anchoring the
+// cadence. Windows are inclusive at both ends; a break removes the time
+// strictly between its ends.
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_business_hours_filter() {
+    use crate::biz_hours::BusinessHours;
+    use chrono::Weekday;
+
+    let tz = Utc;
+    let hours = BusinessHours::weekdays("08:30-12:00,13:00-17:30")
+        .unwrap()
+        .with_day(Weekday::Sat, "10:00-11:00")
+        .unwrap();
+
+    // Friday 2025-01-03: every 45 minutes from 11:15 skips lunch and the evening.
+    let start = tz.with_ymd_and_hms(2025, 1, 3, 11, 15, 0).unwrap();
+    let results: Vec<DateTime<_>> = SpecIteratorBuilder::new_with_start("HH:45M:00", start)
+        .with_business_hours(hours.clone())
+        .build()
+        .unwrap()
+        .take(8)
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 3, 11, 15, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 12, 0, 0).unwrap(), // window edge
+            tz.with_ymd_and_hms(2025, 1, 3, 13, 30, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 14, 15, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 15, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 15, 45, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 16, 30, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 3, 17, 15, 0).unwrap(),
+        ]
+    );
+
+    // The filter also applies to the start and carries over the weekend.
+    let start = tz.with_ymd_and_hms(2025, 1, 3, 18, 0, 0).unwrap();
+    let results: Vec<DateTime<_>> = SpecIteratorBuilder::new_with_start("HH:00:00", start)
+        .with_business_hours(hours)
+        .build()
+        .unwrap()
+        .take(3)
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 4, 10, 0, 0).unwrap(), // Saturday
+            tz.with_ymd_and_hms(2025, 1, 4, 11, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap(), // Monday
+        ]
+    );
+}
+
+#[test]
+fn test_business_hours_never_open() {
+    use crate::biz_hours::BusinessHours;
+    use crate::Error;
+
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+
+    // No windows at all: the schedule ends immediately.
+    let mut iter = SpecIteratorBuilder::new_after("1H:00:00", start)
+        .with_business_hours(BusinessHours::new())
+        .build()
+        .unwrap();
+    assert_eq!(iter.next(), Ok(None));
+
+    // Open hours the spec never hits: fails instead of looping forever.
+    let hours = BusinessHours::weekdays("10:15-10:45").unwrap();
+    let mut iter = SpecIteratorBuilder::new_after("1H:00:00", start)
+        .with_business_hours(hours)
+        .build()
+        .unwrap();
+    assert_eq!(iter.next(), Err(Error::IteratorNotConverged));
+
+    assert!(BusinessHours::weekdays("17:30-08:30").is_err());
+    assert!(BusinessHours::weekdays("08:30-25:00").is_err());
+    assert!(BusinessHours::weekdays("08:30").is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Group 13: Millisecond component — HH:MM:SS.250MS / .000 / .[000,500]
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_millisecond_cadences() {
+    use chrono::{Duration, NaiveDate};
+
+    let tz = Utc;
+    let ms = |h, m, s, milli| {
+        tz.from_utc_datetime(
+            &NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_hms_milli_opt(h, m, s, milli)
+                .unwrap(),
+        )
+    };
+    run_cases(vec![
+        // Every 250 ms, carried across the second boundary
+        TestCase {
+            spec: "HH:MM:SS.250MS",
+            start: ms(9, 0, 0, 500),
+            take: 4,
+            expected: vec![
+                ms(9, 0, 0, 500),
+                ms(9, 0, 0, 750),
+                ms(9, 0, 1, 0),
+                ms(9, 0, 1, 250),
+            ],
+        },
+        // ForEach millisecond drives at 1 ms
+        TestCase {
+            spec: "HH:MM:SS.MS",
+            start: ms(9, 0, 0, 998),
+            take: 3,
+            expected: vec![ms(9, 0, 0, 998), ms(9, 0, 0, 999), ms(9, 0, 1, 0)],
+        },
+        // Every second, pinned to .000 — sub-second part of the start dropped
+        TestCase {
+            spec: "HH:MM:1S.000",
+            start: ms(9, 0, 0, 400),
+            take: 3,
+            expected: vec![ms(9, 0, 0, 400), ms(9, 0, 1, 0), ms(9, 0, 2, 0)],
+        },
+        // Twice a second, aligned to the second
+        TestCase {
+            spec: "HH:MM:SS.[000,500]",
+            start: ms(9, 0, 0, 0),
+            take: 4,
+            expected: vec![
+                ms(9, 0, 0, 0),
+                ms(9, 0, 0, 500),
+                ms(9, 0, 1, 0),
+                ms(9, 0, 1, 500),
+            ],
+        },
+    ]);
+
+    // Without a millisecond component, sub-second precision is carried as-is.
+    let start = ms(9, 0, 0, 123) + Duration::microseconds(456);
+    let results: Vec<DateTime<_>> = SpecIteratorBuilder::new_with_start("HH:MM:1S", start)
+        .build()
+        .unwrap()
+        .take(3)
+        .collect()
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            start,
+            start + Duration::seconds(1),
+            start + Duration::seconds(2)
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 14: Intervals beyond the unit's clock range — 36H / 90M, days and DST
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_every_beyond_unit_range() {
+    let tz = Utc;
+    run_cases(vec![
+        // Every 36 hours: carries across days, alternating 09:00 / 21:00
+        TestCase {
+            spec: "36H:00:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            take: 4,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 21, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 4, 9, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 5, 21, 0, 0).unwrap(),
+            ],
+        },
+        // Every 90 minutes: carries into the hour and across midnight
+        TestCase {
+            spec: "HH:90M:00",
+            start: tz.with_ymd_and_hms(2025, 1, 1, 22, 30, 0).unwrap(),
+            take: 4,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 1, 1, 22, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 1, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 1, 2, 3, 0, 0).unwrap(),
+            ],
+        },
+    ]);
+
+    // An interval past the end of the calendar ends iteration.
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+    let mut iter = SpecIteratorBuilder::new_after("4000000000H:00:00", start)
+        .build()
+        .unwrap();
+    assert_eq!(iter.next(), Ok(None));
+}
+
+#[test]
+fn test_every_beyond_unit_range_across_dst() {
+    let tz = chrono_tz::America::New_York;
+    let utc = |d, h, m| {
+        Utc.with_ymd_and_hms(2025, 11, d, h, m, 0)
+            .unwrap()
+            .with_timezone(&tz)
+    };
+    run_cases(vec![
+        // Intervals longer than their unit are elapsed time: 36 hours after
+        // 00:00 EST is 13:00 EDT across the spring-forward change on 2025-03-09.
+        TestCase {
+            spec: "36H:00:00",
+            start: tz.with_ymd_and_hms(2025, 3, 8, 0, 0, 0).unwrap(),
+            take: 3,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 3, 8, 0, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 3, 9, 13, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 3, 11, 1, 0, 0).unwrap(),
+            ],
+        },
+        // 90 minutes after 00:30 EST is 03:00 EDT, then 04:30 EDT.
+        TestCase {
+            spec: "HH:90M:00",
+            start: tz.with_ymd_and_hms(2025, 3, 9, 0, 30, 0).unwrap(),
+            take: 3,
+            expected: vec![
+                tz.with_ymd_and_hms(2025, 3, 9, 0, 30, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 3, 9, 3, 0, 0).unwrap(),
+                tz.with_ymd_and_hms(2025, 3, 9, 4, 30, 0).unwrap(),
+            ],
+        },
+        // Across fall-back on 2025-11-02 the repeated hour is visited twice:
+        // 01:15 EDT, then 01:30 EST.
+        TestCase {
+            spec: "HH:75M:00",
+            start: tz.with_ymd_and_hms(2025, 11, 2, 0, 0, 0).unwrap(),
+            take: 4,
+            expected: vec![utc(2, 4, 0), utc(2, 5, 15), utc(2, 6, 30), utc(2, 7, 45)],
+        },
+    ]);
+    let ticks: Vec<DateTime<_>> = SpecIteratorBuilder::new_with_start(
+        "HH:90M:00",
+        tz.with_ymd_and_hms(2025, 3, 8, 22, 0, 0).unwrap(),
+    )
+    .build()
+    .unwrap()
+    .take(8)
+    .collect()
+    .unwrap();
+    assert!(ticks
+        .windows(2)
+        .all(|w| w[1] - w[0] == chrono::Duration::minutes(90)));
+
+    // Within their unit, intervals stay on the wall clock.
+    run_cases(vec![TestCase {
+        spec: "24H:00:00",
+        start: tz.with_ymd_and_hms(2025, 3, 8, 9, 0, 0).unwrap(),
+        take: 2,
+        expected: vec![
+            tz.with_ymd_and_hms(2025, 3, 8, 9, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 3, 9, 9, 0, 0).unwrap(),
+        ],
+    }]);
+}
+
+// ---------------------------------------------------------------------------
+// Group 15: Jitter — stable per-key offsets
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_jitter() {
+    use crate::jitter::Jitter;
+    use chrono::Duration;
+
+    let tz = Utc;
+    let start = tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+    let run = |jitter: Jitter| -> Vec<DateTime<Utc>> {
+        SpecIteratorBuilder::new_with_start("1H:00:00", start)
+            .with_jitter(jitter)
+            .build()
+            .unwrap()
+            .take(3)
+            .collect()
+            .unwrap()
+    };
+
+    // Fixed offset: every tick, including the start, moves by the same amount.
+    let offset = Duration::seconds(90);
+    assert_eq!(
+        run(Jitter::fixed(offset)),
+        vec![
+            start + offset,
+            start + Duration::hours(1) + offset,
+            start + Duration::hours(2) + offset,
+        ]
+    );
+
+    // Keyed offset: stable per key, bounded, and the cadence is kept.
+    let max = Duration::seconds(30);
+    let pod_a = run(Jitter::keyed("pod-a", max));
+    assert_eq!(pod_a, run(Jitter::keyed("pod-a", max)));
+    assert_ne!(pod_a, run(Jitter::keyed("pod-b", max)));
+    assert!((pod_a[0] - start).abs() <= max);
+    assert_eq!(pod_a[1] - pod_a[0], Duration::hours(1));
+    assert_eq!(pod_a[2] - pod_a[1], Duration::hours(1));
+
+    // Splay: offset within the hour-long period of `1H:00:00`.
+    let splayed = run(Jitter::splay("pod-a"));
+    assert!(splayed[0] >= start && splayed[0] < start + Duration::hours(1));
+    assert_eq!(splayed[1] - splayed[0], Duration::hours(1));
+
+    // The widest bound stays in range instead of overflowing.
+    let offset = Jitter::keyed("pod-a", Duration::max_value()).offset(Duration::hours(1));
+    assert!(offset.abs() <= Duration::max_value());
+}
+
+// ---------------------------------------------------------------------------
+// Group 16: Zone suffix — `@<IANA zone>`
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_zone_suffix() {
+    let tz = Utc;
+    let run = |spec: &str, start: DateTime<Utc>| -> Vec<DateTime<Utc>> {
+        SpecIteratorBuilder::new_after(spec, start)
+            .build()
+            .unwrap()
+            .take(3)
+            .collect()
+            .unwrap()
+    };
+
+    // Hourly on the hour in Kolkata (UTC+05:30) is half past in UTC.
+    assert_eq!(
+        run(
+            "1H:00:00@Asia/Kolkata",
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap()
+        ),
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 30, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 10, 30, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 11, 30, 0).unwrap(),
+        ]
+    );
+
+    // London wall-clock times follow British Summer Time.
+    assert_eq!(
+        run(
+            "[09,17]:00:00@Europe/London",
+            tz.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap()
+        ),
+        vec![
+            tz.with_ymd_and_hms(2025, 6, 30, 8, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 6, 30, 16, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 7, 1, 8, 0, 0).unwrap(),
+        ]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Group 17: Reverse iteration  (new_before)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_reverse_matches_forward() {
+    let tz = Utc;
+    let dtm = tz.with_ymd_and_hms(2025, 1, 1, 10, 20, 0).unwrap();
+    for spec in ["1H:00:00", "HH:15M:00", "1H:MM:SS", "HH:[00,30]:00", "[09,17]:00:00", "HH:MM:SS"] {
+        let mut reverse: Vec<DateTime<_>> = SpecIteratorBuilder::new_before(spec, dtm)
+            .build()
+            .unwrap()
+            .take(6)
+            .collect()
+            .unwrap();
+        assert!(reverse.iter().all(|dtm_| dtm_ < &dtm), "spec: {}", spec);
+        reverse.reverse();
+
+        let forward: Vec<DateTime<_>> = SpecIteratorBuilder::new_with_start(spec, reverse[0])
+            .build()
+            .unwrap()
+            .take(6)
+            .collect()
+            .unwrap();
+        assert_eq!(reverse, forward, "spec: {}", spec);
+    }
+}
+
+#[test]
+fn test_reverse_steps_back_from_cursor() {
+    let tz = Utc;
+    let run = |spec: &str, dtm: DateTime<Utc>| -> Vec<DateTime<Utc>> {
+        SpecIteratorBuilder::new_before(spec, dtm)
+            .build()
+            .unwrap()
+            .take(3)
+            .collect()
+            .unwrap()
+    };
+
+    // Intervals keep the cursor's phase, as `new_after` would going forwards
+    assert_eq!(
+        run("HH:15M:00", tz.with_ymd_and_hms(2025, 1, 1, 10, 20, 0).unwrap()),
+        vec![
+            tz.with_ymd_and_hms(2025, 1, 1, 10, 5, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 50, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 9, 35, 0).unwrap(),
+        ]
+    );
+
+    // An all-At spec has a single occurrence, as it does going forwards
+    assert_eq!(
+        run("09:00:00", tz.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()),
+        vec![tz.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap()]
+    );
+}
+
+#[test]
+fn test_reverse_hours_jitter_and_dst() {
+    use crate::biz_hours::BusinessHours;
+    use crate::jitter::Jitter;
+    use crate::DstPolicy;
+    use chrono::Duration;
+    use chrono_tz::America::New_York;
+
+    // Business hours filter the nominal times; jitter shifts what is yielded.
+    let dtm = Utc.with_ymd_and_hms(2025, 1, 6, 10, 0, 0).unwrap(); // Monday
+    let runs: Vec<DateTime<_>> = SpecIteratorBuilder::new_before("HH:00:00", dtm)
+        .with_business_hours(BusinessHours::weekdays("09:00-17:00").unwrap())
+        .with_jitter(Jitter::fixed(Duration::minutes(-5)))
+        .build()
+        .unwrap()
+        .take(3)
+        .collect()
+        .unwrap();
+    assert_eq!(
+        runs,
+        vec![
+            Utc.with_ymd_and_hms(2025, 1, 6, 8, 55, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 3, 16, 55, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 3, 15, 55, 0).unwrap(),
+        ]
+    );
+
+    // 02:30 does not exist on 2025-03-09 in New York; the DST policy resolves
+    // it exactly as going forwards.
+    let dtm = New_York.with_ymd_and_hms(2025, 3, 9, 4, 0, 0).unwrap();
+    let mut reverse: Vec<DateTime<_>> = SpecIteratorBuilder::new_before("HH:30:00", dtm)
+        .build()
+        .unwrap()
+        .take(4)
+        .collect()
+        .unwrap();
+    reverse.reverse();
+    let start = New_York.with_ymd_and_hms(2025, 3, 8, 23, 0, 0).unwrap();
+    let forward: Vec<DateTime<_>> = SpecIteratorBuilder::new_after("HH:30:00", start)
+        .build()
+        .unwrap()
+        .take(4)
+        .collect()
+        .unwrap();
+    assert_eq!(reverse, forward);
+
+    let strict: Result<Vec<DateTime<_>>, _> = SpecIteratorBuilder::new_before("HH:30:00", dtm)
+        .with_dst_policy(DstPolicy::Strict)
+        .build()
+        .unwrap()
+        .take(4)
+        .collect();
+    assert!(matches!(strict, Err(crate::Error::AmbiguousLocalTime(_))));
+}
+
+/// Specs pinning the hour or minute scan back a day or an hour at a time, so
+/// the latest pinned occurrence is found first whatever the cursor.
+#[test]
+fn test_reverse_fixed_hour_and_minute() {
+    use crate::biz_hours::BusinessHours;
+    let tz = Utc;
+    let dtm = tz.with_ymd_and_hms(2025, 1, 4, 0, 0, 0).unwrap();
+    let run = |spec: &str, take: usize| -> Vec<DateTime<Utc>> {
+        SpecIteratorBuilder::new_before(spec, dtm)
+            .build()
+            .unwrap()
+            .take(take)
+            .collect()
+            .unwrap()
+    };
+    let at = |d, h, m, s| tz.with_ymd_and_hms(2025, 1, d, h, m, s).unwrap();
+
+    assert_eq!(run("05:00:00", 3), vec![at(3, 5, 0, 0)]);
+
+    let minutes = run("05:MM:00", 61);
+    assert_eq!(minutes[..2], [at(3, 5, 59, 0), at(3, 5, 58, 0)]);
+    assert_eq!(minutes[59..], [at(3, 5, 0, 0), at(2, 5, 59, 0)]);
+
+    // Within the pinned hour, intervals run from its start
+    assert_eq!(
+        run("05:30M:00", 3),
+        vec![at(3, 5, 30, 0), at(3, 5, 0, 0), at(2, 5, 30, 0)]
+    );
+    assert_eq!(
+        run("05:[00,30]:00", 3),
+        vec![at(3, 5, 30, 0), at(3, 5, 0, 0), at(2, 5, 30, 0)]
+    );
+
+    let seconds = run("HH:30:SS", 61);
+    assert_eq!(seconds[..2], [at(3, 23, 30, 59), at(3, 23, 30, 58)]);
+    assert_eq!(seconds[59..], [at(3, 23, 30, 0), at(3, 22, 30, 59)]);
+
+    // The cursor itself is excluded
+    let five = SpecIteratorBuilder::new_before("05:00:00", at(3, 5, 0, 0))
+        .build()
+        .unwrap()
+        .next()
+        .unwrap();
+    assert_eq!(five, Some(at(2, 5, 0, 0)));
+
+    // Business hours that never cover the pinned hour fail as going forwards
+    let closed: Result<Vec<DateTime<Utc>>, _> = SpecIteratorBuilder::new_before("05:MM:00", dtm)
+        .with_business_hours(BusinessHours::weekdays("09:00-17:00").unwrap())
+        .build()
+        .unwrap()
+        .take(1)
+        .collect();
+    assert!(matches!(closed, Err(crate::Error::IteratorNotConverged)));
+}
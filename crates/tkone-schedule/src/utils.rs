@@ -1,6 +1,6 @@
 use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Weekday};
 
-use crate::{prelude::*, DstPolicy, Occurrence};
+use crate::{date::EasterCalendar, prelude::*, DstPolicy, Occurrence};
 
 impl<Tz: TimeZone> From<W<(Tz, NaiveDateTime)>> for DateTime<Tz> {
     fn from(W((tz, dtm)): W<(Tz, NaiveDateTime)>) -> Self {
@@ -86,6 +86,65 @@ pub(crate) fn next_result_to_tz<Tz: TimeZone>(
     }
 }
 
+/// Splits an optional `@<IANA zone>` suffix off a spec string.
+pub(crate) fn split_zone(spec: &str) -> Result<(&str, Option<chrono_tz::Tz>)> {
+    let Some((spec, zone)) = spec.rsplit_once('@') else {
+        return Ok((spec, None));
+    };
+    let zone = zone
+        .parse::<chrono_tz::Tz>()
+        .map_err(|_| Error::InvalidTimezone(zone.to_string()))?;
+    Ok((spec, Some(zone)))
+}
+
+/// The wall-clock time of `dtm` in the spec's `zone`, or in its own timezone
+/// when the spec names none.
+pub(crate) fn local_in<Tz: TimeZone>(
+    dtm: &DateTime<Tz>,
+    zone: Option<chrono_tz::Tz>,
+) -> NaiveDateTime {
+    match zone {
+        Some(zone) => dtm.with_timezone(&zone).naive_local(),
+        None => dtm.naive_local(),
+    }
+}
+
+/// Like [`resolve_local`], but resolves `dtm` in the spec's `zone` when it
+/// names one and expresses the result in `tz`.
+pub(crate) fn resolve_in_zone<Tz: TimeZone>(
+    tz: &Tz,
+    zone: Option<chrono_tz::Tz>,
+    dtm: NaiveDateTime,
+    policy: DstPolicy,
+) -> Result<DateTime<Tz>> {
+    match zone {
+        Some(zone) => Ok(resolve_local(&zone, dtm, policy)?.with_timezone(tz)),
+        None => resolve_local(tz, dtm, policy),
+    }
+}
+
+/// Like [`next_result_to_tz`], but resolves in the spec's `zone` when it
+/// names one and expresses the result in `tz`.
+pub(crate) fn next_result_in_zone<Tz: TimeZone>(
+    tz: &Tz,
+    zone: Option<chrono_tz::Tz>,
+    next: Occurrence<NaiveDateTime>,
+    policy: DstPolicy,
+) -> Result<Occurrence<DateTime<Tz>>> {
+    let Some(zone) = zone else {
+        return next_result_to_tz(tz, next, policy);
+    };
+    Ok(match next_result_to_tz(&zone, next, policy)? {
+        Occurrence::Exact(dtm) => Occurrence::Exact(dtm.with_timezone(tz)),
+        Occurrence::AdjustedEarlier(actual, adjusted) => {
+            Occurrence::AdjustedEarlier(actual.with_timezone(tz), adjusted.with_timezone(tz))
+        }
+        Occurrence::AdjustedLater(actual, adjusted) => {
+            Occurrence::AdjustedLater(actual.with_timezone(tz), adjusted.with_timezone(tz))
+        }
+    })
+}
+
 pub trait DateLikeUtils: Datelike {
     fn to_last_day_of_month(&self) -> Self;
     fn to_first_day_of_month(&self) -> Self;
@@ -93,6 +152,42 @@ pub trait DateLikeUtils: Datelike {
     fn to_months_weekday(&self, weekday: &Weekday, occurence: u8) -> Option<Self>;
     fn to_prev_weekday(&self, weekday: &Weekday) -> Self;
     fn to_months_last_weekday(&self, weekday: &Weekday, occurence: u8) -> Option<Self>;
+    /// Easter Sunday of this date's year in `calendar`, keeping the time.
+    fn to_easter(&self, calendar: EasterCalendar) -> Option<Self>;
+}
+
+/// The Gregorian date of Easter Sunday in `year`.
+///
+/// Western Easter uses the anonymous Gregorian algorithm. Orthodox Easter uses
+/// Meeus' Julian algorithm, then shifts the Julian date onto the Gregorian
+/// calendar.
+fn easter_sunday(year: i32, calendar: EasterCalendar) -> Option<NaiveDate> {
+    match calendar {
+        EasterCalendar::Western => {
+            let a = year.rem_euclid(19);
+            let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+            let (d, e) = (b / 4, b % 4);
+            let f = (b + 8) / 25;
+            let g = (b - f + 1) / 3;
+            let h = (19 * a + b - d - g + 15) % 30;
+            let (i, k) = (c / 4, c % 4);
+            let l = (32 + 2 * e + 2 * i - h - k) % 7;
+            let m = (a + 11 * h + 22 * l) / 451;
+            let n = h + l - 7 * m + 114;
+            NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+        }
+        EasterCalendar::Orthodox => {
+            let a = year.rem_euclid(4);
+            let b = year.rem_euclid(7);
+            let c = year.rem_euclid(19);
+            let d = (19 * c + 15) % 30;
+            let e = (2 * a + 4 * b - d + 34) % 7;
+            let n = d + e + 114;
+            let julian_lag = year.div_euclid(100) - year.div_euclid(400) - 2;
+            NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)?
+                .checked_add_signed(Duration::days(julian_lag as i64))
+        }
+    }
 }
 
 impl DateLikeUtils for NaiveDate {
@@ -146,6 +241,10 @@ impl DateLikeUtils for NaiveDate {
             None
         }
     }
+
+    fn to_easter(&self, calendar: EasterCalendar) -> Option<Self> {
+        easter_sunday(self.year(), calendar)
+    }
 }
 
 impl DateLikeUtils for NaiveDateTime {
@@ -176,6 +275,12 @@ impl DateLikeUtils for NaiveDateTime {
             .to_months_last_weekday(weekday, occurence)
             .map(|date| NaiveDateTime::new(date, self.time()))
     }
+
+    fn to_easter(&self, calendar: EasterCalendar) -> Option<Self> {
+        self.date()
+            .to_easter(calendar)
+            .map(|date| NaiveDateTime::new(date, self.time()))
+    }
 }
 
 impl<Tz: TimeZone> DateLikeUtils for DateTime<Tz> {
@@ -215,6 +320,12 @@ impl<Tz: TimeZone> DateLikeUtils for DateTime<Tz> {
             .to_months_last_weekday(weekday, occurence)
             .map(|date| DateTime::<Tz>::from(W((self.timezone(), date))))
     }
+
+    fn to_easter(&self, calendar: EasterCalendar) -> Option<Self> {
+        self.naive_local()
+            .to_easter(calendar)
+            .map(|date| DateTime::<Tz>::from(W((self.timezone(), date))))
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, Hash)]